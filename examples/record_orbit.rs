@@ -0,0 +1,43 @@
+//! Records a precessing orbit around a Schwarzschild black hole and writes it to `orbit.csv`
+//! and `orbit.jsonl`, for plotting with external tools.
+
+use gr_engine::coord_systems::{schwarzschild, Runtime};
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Event, Frame, Particle, Speed, Trajectory};
+use std::f64::consts::PI;
+use std::fs::File;
+use std::io::BufWriter;
+
+type Coords = schwarzschild::EddingtonFinkelstein<Runtime>;
+
+fn main() -> std::io::Result<()> {
+    let bh = schwarzschild::BlackHole::new(1.0);
+    let start = Point::<Coords>::new(arr![f64; 0.0, 12.0, PI / 2.0, 0.0]);
+    let frame = Frame::static_observer_in(&bh, &start).expect("outside the horizon");
+    let mut particle = frame.particle([-0.1, 0.0, 1.0], Speed::Massive(0.33));
+
+    let integrator = DenseDPIntegrator::new(0.1, 1e-4, 2.0, 1e-10);
+    let mut driver = Driver::new(integrator).with_event(Event::horizon(bh.horizon_radius()));
+
+    let mut trajectory = Trajectory::new();
+    trajectory.record(driver.param(), &particle);
+    while driver.param() < 5000.0 {
+        let hits = driver.step(&mut particle, |p: &Particle<Coords>| p.derivative_in(&bh));
+        // a few points of the integrator's dense output per step, for smooth plots
+        trajectory.record_step(&driver, 4);
+        if !hits.is_empty() {
+            println!("The particle fell into the black hole");
+            break;
+        }
+    }
+
+    trajectory.write_csv(BufWriter::new(File::create("orbit.csv")?))?;
+    trajectory.write_json_lines(BufWriter::new(File::create("orbit.jsonl")?))?;
+    println!(
+        "Recorded {} samples to orbit.csv and orbit.jsonl",
+        trajectory.len()
+    );
+    Ok(())
+}
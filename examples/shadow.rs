@@ -0,0 +1,58 @@
+//! Renders the shadow of a Kerr black hole surrounded by a thin disk to `shadow.ppm`.
+//!
+//! Usage: shadow [width] [height] [spin]
+
+use gr_engine::coord_systems::{kerr, Runtime};
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::generic_array::arr;
+use gr_engine::{Camera, Entity, Frame, RayOutcome, Scene};
+use std::env;
+use std::f64::consts::PI;
+use std::fs::File;
+use std::io::BufWriter;
+use std::time::Instant;
+
+type Atlas = kerr::EFAtlas<Runtime>;
+
+fn main() -> std::io::Result<()> {
+    let args: Vec<f64> = env::args()
+        .skip(1)
+        .map(|arg| arg.parse().expect("expected a number"))
+        .collect();
+    let width = args.first().map_or(320, |&w| w as usize);
+    let height = args.get(1).map_or(240, |&h| h as usize);
+    let spin = args.get(2).copied().unwrap_or(0.9);
+
+    let bh = kerr::BlackHole::new(1.0, spin);
+    let position = Point::new(arr![f64; 0.0, 30.0, 85.0 * PI / 180.0, 0.0]);
+    let frame = Frame::static_observer_in(&bh, &position).expect("outside the ergosphere");
+
+    // look at the black hole, with the north pole up
+    let mut observer = Entity::new(
+        position,
+        frame.to_global([1.0, 0.0, 0.0, 0.0]),
+        frame.to_global([0.0, -1.0, 0.0, 0.0]),
+        frame.to_global([0.0, 0.0, 0.0, 1.0]),
+        frame.to_global([0.0, 0.0, -1.0, 0.0]),
+    );
+    observer.orthonormalize_in(&bh);
+
+    let camera = Camera::<Atlas>::new(&observer, width, height, 35.0 * PI / 180.0);
+    // rays are captured halfway between the horizon and the prograde photon orbit, which both
+    // approach r = 1 as the spin goes to 1
+    let photon_orbit = 2.0 * (1.0 + (2.0 / 3.0 * (-spin.abs()).acos()).cos());
+    let scene = Scene::new(bh.horizon_radius(), 60.0)
+        .with_capture_radius(0.5 * (bh.horizon_radius() + photon_orbit))
+        .with_disk(6.0, 20.0);
+
+    let start = Instant::now();
+    let image = camera.render(&scene, &bh, RayOutcome::default_color);
+    println!(
+        "Rendered {}x{} pixels in {:.1} s",
+        width,
+        height,
+        start.elapsed().as_secs_f64()
+    );
+
+    image.write_ppm(BufWriter::new(File::create("shadow.ppm")?))
+}
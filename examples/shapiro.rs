@@ -2,13 +2,12 @@ extern crate diffgeom;
 extern crate gr_engine;
 #[macro_use]
 extern crate generic_array;
-extern crate numeric_algs;
 
 use diffgeom::coordinates::Point;
 use diffgeom::tensors::Vector;
 use gr_engine::coord_systems::schwarzschild::{Mass, Schwarzschild};
-use gr_engine::particle::Particle;
-use numeric_algs::integration::{DPIntegrator, Integrator, StepSize};
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Causality, Constraints, Driver, Event, Particle};
 use std::f64::consts::PI;
 
 struct Sun;
@@ -25,30 +24,25 @@ const D: f64 = 2.33; // radius of the Sun in seconds
 const YE: f64 = 498.67; // Earth "y" coordinate in seconds
 const YV: f64 = 370.7; // Venus "y" coordinate in seconds
 
-fn propagate_single_photon(
-    photon: &mut Particle<Coords>,
-    integrator: &mut DPIntegrator<Particle<Coords>>,
-    r_max: f64,
-) -> f64 {
-    let mut last_pos = photon.get_pos().clone();
+fn propagate_single_photon(photon: &mut Particle<Coords>, r_max: f64) -> f64 {
+    let integrator = DenseDPIntegrator::new(0.01, 0.0001, 0.1, 1e-12);
+    // keep the photon on the null cone
+    let constraints = Constraints::new(Causality::Null);
+    let mut driver = Driver::new(integrator)
+        .with_event(Event::radius(r_max).with_terminal(true))
+        .with_projection(move |p: &mut Particle<Coords>| p.project(&constraints));
 
     let mut i = 1;
-    while photon.get_pos()[1] < r_max {
-        last_pos = photon.get_pos().clone();
-        integrator.propagate_in_place(photon, Particle::derivative, StepSize::UseDefault);
+    loop {
+        let hits = driver.step(photon, Particle::derivative);
+        if let Some(hit) = hits.last() {
+            return hit.state.get_pos()[0];
+        }
         i += 1;
         if i % 100 == 0 {
             println!("Iteration {}... r = {}", i, photon.get_pos()[1]);
         }
     }
-
-    let pos = photon.get_pos();
-    let (t, r) = (pos[0], pos[1]);
-    let (last_t, last_r) = (last_pos[0], last_pos[1]);
-
-    let coeff = (r_max - last_r) / (r - last_r);
-
-    last_t + (t - last_t) * coeff
 }
 
 fn main() {
@@ -59,21 +53,17 @@ fn main() {
     let t_flat = 2.0 * (YE + YV);
 
     let start_point = Point::<Coords>::new(arr![f64; 0.0, D, PI / 2.0, 0.0]);
-    let u_init1 = Vector::<Coords>::new(start_point.clone(), arr![f64; u0, 0.0, 0.0, 1.0]);
-    let u_init2 = Vector::<Coords>::new(start_point.clone(), arr![f64; -u0, 0.0, 0.0, -1.0]);
-
-    let mut photon1 = Particle::new(start_point.clone(), u_init1);
-    let mut photon2 = Particle::new(start_point.clone(), u_init2);
+    let u_init1 = Vector::<Coords>::new(start_point, arr![f64; u0, 0.0, 0.0, 1.0]);
+    let u_init2 = Vector::<Coords>::new(start_point, arr![f64; -u0, 0.0, 0.0, -1.0]);
 
-    let mut integrator = DPIntegrator::<Particle<Coords>>::new(0.01, 0.0001, 0.1, 1e-12);
+    let mut photon1 = Particle::new(start_point, u_init1);
+    let mut photon2 = Particle::new(start_point, u_init2);
 
     println!("Propagating the first photon...");
-    let t1 = propagate_single_photon(&mut photon1, &mut integrator, r_e);
-
-    integrator.reset();
+    let t1 = propagate_single_photon(&mut photon1, r_e);
 
     println!("Propagating the second photon...");
-    let t2 = propagate_single_photon(&mut photon2, &mut integrator, r_v);
+    let t2 = propagate_single_photon(&mut photon2, r_v);
 
     println!("Propagation finished.");
     println!("t1 = {}", t1);
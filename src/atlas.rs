@@ -0,0 +1,633 @@
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::constraints::Constraints;
+use crate::entity::Entity;
+use crate::events::Body;
+use crate::numeric::StateVector;
+use crate::particle::Particle;
+use crate::spacetime::Spacetime;
+use crate::trajectory::Recordable;
+use crate::typenum::consts::{U20, U4, U8};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::Vector;
+use numeric_algs::State;
+use std::f64::consts::PI;
+
+/// Default polar angle below which (or above pi minus which) a particle is moved to a polar chart
+pub const DEFAULT_ENTER_POLE: f64 = 0.25;
+/// Default polar angle above which (or below pi minus which) a particle leaves a polar chart
+pub const DEFAULT_LEAVE_POLE: f64 = 0.5;
+
+/// A set of charts covering the whole spacetime: a main chart using the (theta, phi) angles and
+/// two stereographic charts covering the neighbourhoods of the poles theta=0 and theta=pi
+pub trait Atlas {
+    type Main: CoordinateSystem<Dimension = U4>
+        + ConversionTo<Self::Pole0>
+        + ConversionTo<Self::PolePi>
+        + 'static;
+    type Pole0: CoordinateSystem<Dimension = U4> + ConversionTo<Self::Main> + 'static;
+    type PolePi: CoordinateSystem<Dimension = U4> + ConversionTo<Self::Main> + 'static;
+}
+
+/// Identifies the chart of an atlas an object is currently expressed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chart {
+    Main,
+    Pole0,
+    PolePi,
+}
+
+/// The thresholds controlling the switching between the charts. Having `leave` greater than
+/// `enter` prevents an object moving near the threshold from switching charts at every step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoleThresholds {
+    pub enter: f64,
+    pub leave: f64,
+}
+
+impl Default for PoleThresholds {
+    fn default() -> Self {
+        PoleThresholds {
+            enter: DEFAULT_ENTER_POLE,
+            leave: DEFAULT_LEAVE_POLE,
+        }
+    }
+}
+
+impl PoleThresholds {
+    // returns the chart that should be used for the given value of theta, given the current one
+    fn choose(&self, current: Chart, theta: f64) -> Chart {
+        match current {
+            Chart::Main if theta < self.enter => Chart::Pole0,
+            Chart::Main if theta > PI - self.enter => Chart::PolePi,
+            Chart::Pole0 if theta > self.leave => Chart::Main,
+            Chart::PolePi if theta < PI - self.leave => Chart::Main,
+            _ => current,
+        }
+    }
+}
+
+// theta in the stereographic chart covering the pole theta=0
+fn theta_pole0<C: CoordinateSystem<Dimension = U4>>(p: &Point<C>) -> f64 {
+    (p[2] * p[2] + p[3] * p[3]).sqrt().atan() * 2.0
+}
+
+// theta in the stereographic chart covering the pole theta=pi
+fn theta_pole_pi<C: CoordinateSystem<Dimension = U4>>(p: &Point<C>) -> f64 {
+    PI - (p[2] * p[2] + p[3] * p[3]).sqrt().atan() * 2.0
+}
+
+enum ParticleInChart<A: Atlas> {
+    Main(Particle<A::Main>),
+    Pole0(Particle<A::Pole0>),
+    PolePi(Particle<A::PolePi>),
+}
+
+impl<A: Atlas> Clone for ParticleInChart<A> {
+    fn clone(&self) -> Self {
+        match *self {
+            ParticleInChart::Main(ref p) => ParticleInChart::Main(p.clone()),
+            ParticleInChart::Pole0(ref p) => ParticleInChart::Pole0(p.clone()),
+            ParticleInChart::PolePi(ref p) => ParticleInChart::PolePi(p.clone()),
+        }
+    }
+}
+
+/// A particle that automatically moves between the charts of an atlas, so that it never gets
+/// close to the coordinate singularities at the poles of the main chart.
+///
+/// The particle can be integrated like a regular `Particle`. After every step `update_chart`
+/// should be called; if it returns `true`, the state has been re-expressed in another chart and
+/// any derivative cached by the integrator (like in `DPIntegrator`) has to be discarded.
+pub struct AtlasParticle<A: Atlas> {
+    particle: ParticleInChart<A>,
+    thresholds: PoleThresholds,
+}
+
+impl<A: Atlas> Clone for AtlasParticle<A> {
+    fn clone(&self) -> Self {
+        AtlasParticle {
+            particle: self.particle.clone(),
+            thresholds: self.thresholds,
+        }
+    }
+}
+
+impl<A: Atlas> AtlasParticle<A> {
+    pub fn new(particle: Particle<A::Main>) -> Self {
+        Self::with_thresholds(particle, Default::default())
+    }
+
+    pub fn with_thresholds(particle: Particle<A::Main>, thresholds: PoleThresholds) -> Self {
+        let mut result = AtlasParticle {
+            particle: ParticleInChart::Main(particle),
+            thresholds,
+        };
+        result.update_chart();
+        result
+    }
+
+    pub fn chart(&self) -> Chart {
+        match self.particle {
+            ParticleInChart::Main(_) => Chart::Main,
+            ParticleInChart::Pole0(_) => Chart::Pole0,
+            ParticleInChart::PolePi(_) => Chart::PolePi,
+        }
+    }
+
+    pub fn theta(&self) -> f64 {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.get_pos()[2],
+            ParticleInChart::Pole0(ref p) => theta_pole0(p.get_pos()),
+            ParticleInChart::PolePi(ref p) => theta_pole_pi(p.get_pos()),
+        }
+    }
+
+    /// Moves the particle to another chart if it got too close to or far enough from a pole.
+    /// Returns whether the chart has been changed.
+    pub fn update_chart(&mut self) -> bool {
+        let current = self.chart();
+        let new_chart = self.thresholds.choose(current, self.theta());
+        if new_chart == current {
+            return false;
+        }
+        let main = self.to_main();
+        self.particle = match new_chart {
+            Chart::Main => ParticleInChart::Main(main),
+            Chart::Pole0 => ParticleInChart::Pole0(main.convert()),
+            Chart::PolePi => ParticleInChart::PolePi(main.convert()),
+        };
+        true
+    }
+
+    /// Returns the particle expressed in the main chart of the atlas
+    pub fn to_main(&self) -> Particle<A::Main> {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.clone(),
+            ParticleInChart::Pole0(ref p) => p.convert(),
+            ParticleInChart::PolePi(ref p) => p.convert(),
+        }
+    }
+
+    /// Returns the particle expressed in a display chart `D`, reachable from the main chart
+    pub fn convert<D: CoordinateSystem<Dimension = U4> + 'static>(&self) -> Particle<D>
+    where
+        A::Main: ConversionTo<D>,
+    {
+        self.to_main().convert()
+    }
+
+    /// Returns the position in the main chart of the atlas
+    pub fn get_pos(&self) -> Point<A::Main> {
+        *self.to_main().get_pos()
+    }
+
+    /// Returns the 4-velocity in the main chart of the atlas
+    pub fn get_vel(&self) -> Vector<A::Main> {
+        *self.to_main().get_vel()
+    }
+
+    pub fn derivative(&self) -> StateVector<U8>
+    where
+        A::Main: MetricSystem,
+        A::Pole0: MetricSystem,
+        A::PolePi: MetricSystem,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.derivative(),
+            ParticleInChart::Pole0(ref p) => p.derivative(),
+            ParticleInChart::PolePi(ref p) => p.derivative(),
+        }
+    }
+
+    /// Like `derivative`, but takes the metric from a runtime `Spacetime`
+    pub fn derivative_in<S>(&self, spacetime: &S) -> StateVector<U8>
+    where
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.derivative_in(spacetime),
+            ParticleInChart::Pole0(ref p) => p.derivative_in(spacetime),
+            ParticleInChart::PolePi(ref p) => p.derivative_in(spacetime),
+        }
+    }
+
+    /// Projects the 4-velocity back onto the constraints, in whichever chart the particle
+    /// currently is; see `Particle::project`
+    pub fn project(&mut self, constraints: &Constraints) -> bool
+    where
+        A::Main: MetricSystem + Symmetries,
+        A::Pole0: MetricSystem + Symmetries,
+        A::PolePi: MetricSystem + Symmetries,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref mut p) => p.project(constraints),
+            ParticleInChart::Pole0(ref mut p) => p.project(constraints),
+            ParticleInChart::PolePi(ref mut p) => p.project(constraints),
+        }
+    }
+
+    /// Like `project`, but takes the metric from a runtime `Spacetime`
+    pub fn project_in<S>(&mut self, spacetime: &S, constraints: &Constraints) -> bool
+    where
+        A::Main: Symmetries,
+        A::Pole0: Symmetries,
+        A::PolePi: Symmetries,
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref mut p) => p.project_in(spacetime, constraints),
+            ParticleInChart::Pole0(ref mut p) => p.project_in(spacetime, constraints),
+            ParticleInChart::PolePi(ref mut p) => p.project_in(spacetime, constraints),
+        }
+    }
+
+    /// Calculates the quantities conserved along the geodesic, in whichever chart the particle
+    /// currently is
+    pub fn conserved(&self) -> ConservedQuantities
+    where
+        A::Main: MetricSystem + Symmetries,
+        A::Pole0: MetricSystem + Symmetries,
+        A::PolePi: MetricSystem + Symmetries,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.conserved(),
+            ParticleInChart::Pole0(ref p) => p.conserved(),
+            ParticleInChart::PolePi(ref p) => p.conserved(),
+        }
+    }
+
+    /// Like `conserved`, but takes the metric from a runtime `Spacetime`
+    pub fn conserved_in<S>(&self, spacetime: &S) -> ConservedQuantities
+    where
+        A::Main: Symmetries,
+        A::Pole0: Symmetries,
+        A::PolePi: Symmetries,
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.conserved_in(spacetime),
+            ParticleInChart::Pole0(ref p) => p.conserved_in(spacetime),
+            ParticleInChart::PolePi(ref p) => p.conserved_in(spacetime),
+        }
+    }
+}
+
+impl<A: Atlas> State for AtlasParticle<A> {
+    type Derivative = StateVector<U8>;
+
+    fn shift_in_place(&mut self, dir: &Self::Derivative, amount: f64) {
+        match self.particle {
+            ParticleInChart::Main(ref mut p) => p.shift_in_place(dir, amount),
+            ParticleInChart::Pole0(ref mut p) => p.shift_in_place(dir, amount),
+            ParticleInChart::PolePi(ref mut p) => p.shift_in_place(dir, amount),
+        }
+    }
+}
+
+impl<A: Atlas> Body for AtlasParticle<A>
+where
+    A::Main: Symmetries,
+    A::Pole0: Symmetries,
+    A::PolePi: Symmetries,
+{
+    fn time_coordinate(&self) -> f64 {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.time_coordinate(),
+            ParticleInChart::Pole0(ref p) => p.time_coordinate(),
+            ParticleInChart::PolePi(ref p) => p.time_coordinate(),
+        }
+    }
+
+    fn radius(&self) -> f64 {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.radius(),
+            ParticleInChart::Pole0(ref p) => p.radius(),
+            ParticleInChart::PolePi(ref p) => p.radius(),
+        }
+    }
+
+    fn cos_theta(&self) -> f64 {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.cos_theta(),
+            ParticleInChart::Pole0(ref p) => p.cos_theta(),
+            ParticleInChart::PolePi(ref p) => p.cos_theta(),
+        }
+    }
+}
+
+impl<A: Atlas> Recordable for AtlasParticle<A> {
+    fn chart(&self) -> Chart {
+        self.chart()
+    }
+
+    fn position(&self) -> [f64; 4] {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.position(),
+            ParticleInChart::Pole0(ref p) => p.position(),
+            ParticleInChart::PolePi(ref p) => p.position(),
+        }
+    }
+
+    fn velocity(&self) -> [f64; 4] {
+        match self.particle {
+            ParticleInChart::Main(ref p) => p.velocity(),
+            ParticleInChart::Pole0(ref p) => p.velocity(),
+            ParticleInChart::PolePi(ref p) => p.velocity(),
+        }
+    }
+}
+
+enum EntityInChart<A: Atlas> {
+    Main(Entity<A::Main>),
+    Pole0(Entity<A::Pole0>),
+    PolePi(Entity<A::PolePi>),
+}
+
+impl<A: Atlas> Clone for EntityInChart<A> {
+    fn clone(&self) -> Self {
+        match *self {
+            EntityInChart::Main(ref e) => EntityInChart::Main(e.clone()),
+            EntityInChart::Pole0(ref e) => EntityInChart::Pole0(e.clone()),
+            EntityInChart::PolePi(ref e) => EntityInChart::PolePi(e.clone()),
+        }
+    }
+}
+
+/// An entity that automatically moves between the charts of an atlas; see `AtlasParticle`.
+pub struct AtlasEntity<A: Atlas> {
+    entity: EntityInChart<A>,
+    thresholds: PoleThresholds,
+}
+
+impl<A: Atlas> Clone for AtlasEntity<A> {
+    fn clone(&self) -> Self {
+        AtlasEntity {
+            entity: self.entity.clone(),
+            thresholds: self.thresholds,
+        }
+    }
+}
+
+impl<A: Atlas> AtlasEntity<A> {
+    pub fn new(entity: Entity<A::Main>) -> Self {
+        Self::with_thresholds(entity, Default::default())
+    }
+
+    pub fn with_thresholds(entity: Entity<A::Main>, thresholds: PoleThresholds) -> Self {
+        let mut result = AtlasEntity {
+            entity: EntityInChart::Main(entity),
+            thresholds,
+        };
+        result.update_chart();
+        result
+    }
+
+    pub fn chart(&self) -> Chart {
+        match self.entity {
+            EntityInChart::Main(_) => Chart::Main,
+            EntityInChart::Pole0(_) => Chart::Pole0,
+            EntityInChart::PolePi(_) => Chart::PolePi,
+        }
+    }
+
+    pub fn theta(&self) -> f64 {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.get_pos()[2],
+            EntityInChart::Pole0(ref e) => theta_pole0(e.get_pos()),
+            EntityInChart::PolePi(ref e) => theta_pole_pi(e.get_pos()),
+        }
+    }
+
+    /// Moves the entity to another chart if it got too close to or far enough from a pole.
+    /// Returns whether the chart has been changed.
+    pub fn update_chart(&mut self) -> bool {
+        let current = self.chart();
+        let new_chart = self.thresholds.choose(current, self.theta());
+        if new_chart == current {
+            return false;
+        }
+        let main = self.to_main();
+        self.entity = match new_chart {
+            Chart::Main => EntityInChart::Main(main),
+            Chart::Pole0 => EntityInChart::Pole0(main.convert()),
+            Chart::PolePi => EntityInChart::PolePi(main.convert()),
+        };
+        true
+    }
+
+    /// Returns the entity expressed in the main chart of the atlas
+    pub fn to_main(&self) -> Entity<A::Main> {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.clone(),
+            EntityInChart::Pole0(ref e) => e.convert(),
+            EntityInChart::PolePi(ref e) => e.convert(),
+        }
+    }
+
+    /// Returns the entity expressed in a display chart `D`, reachable from the main chart
+    pub fn convert<D: CoordinateSystem<Dimension = U4> + 'static>(&self) -> Entity<D>
+    where
+        A::Main: ConversionTo<D>,
+    {
+        self.to_main().convert()
+    }
+
+    /// Returns the position in the main chart of the atlas
+    pub fn get_pos(&self) -> Point<A::Main> {
+        *self.to_main().get_pos()
+    }
+
+    /// Returns the 4-velocity in the main chart of the atlas
+    pub fn get_vel(&self) -> Vector<A::Main> {
+        *self.to_main().get_vel()
+    }
+
+    pub fn orthonormalize(&mut self)
+    where
+        A::Main: MetricSystem,
+        A::Pole0: MetricSystem,
+        A::PolePi: MetricSystem,
+    {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.orthonormalize(),
+            EntityInChart::Pole0(ref mut e) => e.orthonormalize(),
+            EntityInChart::PolePi(ref mut e) => e.orthonormalize(),
+        }
+    }
+
+    /// Like `orthonormalize`, but takes the metric from a runtime `Spacetime`
+    pub fn orthonormalize_in<S>(&mut self, spacetime: &S)
+    where
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.orthonormalize_in(spacetime),
+            EntityInChart::Pole0(ref mut e) => e.orthonormalize_in(spacetime),
+            EntityInChart::PolePi(ref mut e) => e.orthonormalize_in(spacetime),
+        }
+    }
+
+    /// Projects the 4-velocity back onto the constraints, in whichever chart the entity currently
+    /// is; see `Entity::project`
+    pub fn project(&mut self, constraints: &Constraints) -> bool
+    where
+        A::Main: MetricSystem + Symmetries,
+        A::Pole0: MetricSystem + Symmetries,
+        A::PolePi: MetricSystem + Symmetries,
+    {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.project(constraints),
+            EntityInChart::Pole0(ref mut e) => e.project(constraints),
+            EntityInChart::PolePi(ref mut e) => e.project(constraints),
+        }
+    }
+
+    /// Like `project`, but takes the metric from a runtime `Spacetime`
+    pub fn project_in<S>(&mut self, spacetime: &S, constraints: &Constraints) -> bool
+    where
+        A::Main: Symmetries,
+        A::Pole0: Symmetries,
+        A::PolePi: Symmetries,
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.project_in(spacetime, constraints),
+            EntityInChart::Pole0(ref mut e) => e.project_in(spacetime, constraints),
+            EntityInChart::PolePi(ref mut e) => e.project_in(spacetime, constraints),
+        }
+    }
+
+    // force and angular velocity are expressed in the local frame, so they don't depend on the
+    // chart
+
+    pub fn add_force(&mut self, x: f64, y: f64, z: f64) {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.add_force(x, y, z),
+            EntityInChart::Pole0(ref mut e) => e.add_force(x, y, z),
+            EntityInChart::PolePi(ref mut e) => e.add_force(x, y, z),
+        }
+    }
+
+    pub fn add_ang_vel(&mut self, x: f64, y: f64, z: f64) {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.add_ang_vel(x, y, z),
+            EntityInChart::Pole0(ref mut e) => e.add_ang_vel(x, y, z),
+            EntityInChart::PolePi(ref mut e) => e.add_ang_vel(x, y, z),
+        }
+    }
+
+    pub fn reset_force(&mut self) {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.reset_force(),
+            EntityInChart::Pole0(ref mut e) => e.reset_force(),
+            EntityInChart::PolePi(ref mut e) => e.reset_force(),
+        }
+    }
+
+    pub fn reset_ang_vel(&mut self) {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.reset_ang_vel(),
+            EntityInChart::Pole0(ref mut e) => e.reset_ang_vel(),
+            EntityInChart::PolePi(ref mut e) => e.reset_ang_vel(),
+        }
+    }
+
+    pub fn derivative(&self) -> StateVector<U20>
+    where
+        A::Main: MetricSystem,
+        A::Pole0: MetricSystem,
+        A::PolePi: MetricSystem,
+    {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.derivative(),
+            EntityInChart::Pole0(ref e) => e.derivative(),
+            EntityInChart::PolePi(ref e) => e.derivative(),
+        }
+    }
+
+    /// Like `derivative`, but takes the metric from a runtime `Spacetime`
+    pub fn derivative_in<S>(&self, spacetime: &S) -> StateVector<U20>
+    where
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.derivative_in(spacetime),
+            EntityInChart::Pole0(ref e) => e.derivative_in(spacetime),
+            EntityInChart::PolePi(ref e) => e.derivative_in(spacetime),
+        }
+    }
+}
+
+impl<A: Atlas> State for AtlasEntity<A> {
+    type Derivative = StateVector<U20>;
+
+    fn shift_in_place(&mut self, dir: &Self::Derivative, amount: f64) {
+        match self.entity {
+            EntityInChart::Main(ref mut e) => e.shift_in_place(dir, amount),
+            EntityInChart::Pole0(ref mut e) => e.shift_in_place(dir, amount),
+            EntityInChart::PolePi(ref mut e) => e.shift_in_place(dir, amount),
+        }
+    }
+}
+
+impl<A: Atlas> Body for AtlasEntity<A>
+where
+    A::Main: Symmetries,
+    A::Pole0: Symmetries,
+    A::PolePi: Symmetries,
+{
+    fn time_coordinate(&self) -> f64 {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.time_coordinate(),
+            EntityInChart::Pole0(ref e) => e.time_coordinate(),
+            EntityInChart::PolePi(ref e) => e.time_coordinate(),
+        }
+    }
+
+    fn radius(&self) -> f64 {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.radius(),
+            EntityInChart::Pole0(ref e) => e.radius(),
+            EntityInChart::PolePi(ref e) => e.radius(),
+        }
+    }
+
+    fn cos_theta(&self) -> f64 {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.cos_theta(),
+            EntityInChart::Pole0(ref e) => e.cos_theta(),
+            EntityInChart::PolePi(ref e) => e.cos_theta(),
+        }
+    }
+}
+
+impl<A: Atlas> Recordable for AtlasEntity<A> {
+    fn chart(&self) -> Chart {
+        self.chart()
+    }
+
+    fn position(&self) -> [f64; 4] {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.position(),
+            EntityInChart::Pole0(ref e) => e.position(),
+            EntityInChart::PolePi(ref e) => e.position(),
+        }
+    }
+
+    fn velocity(&self) -> [f64; 4] {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.velocity(),
+            EntityInChart::Pole0(ref e) => e.velocity(),
+            EntityInChart::PolePi(ref e) => e.velocity(),
+        }
+    }
+
+    fn tetrad(&self) -> Option<[[f64; 4]; 3]> {
+        match self.entity {
+            EntityInChart::Main(ref e) => e.tetrad(),
+            EntityInChart::Pole0(ref e) => e.tetrad(),
+            EntityInChart::PolePi(ref e) => e.tetrad(),
+        }
+    }
+}
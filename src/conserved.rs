@@ -0,0 +1,168 @@
+use crate::spacetime::{contract, lower, Spacetime};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::tensors::{Covector, Vector};
+use std::fmt;
+
+/// A chart of a stationary, axisymmetric spacetime, adapted to its symmetries: the surfaces of
+/// constant t and r are spheres described by the angles theta and phi (or by stereographic
+/// coordinates near the poles).
+///
+/// This is what is needed to calculate the conserved quantities of geodesics and to set up the
+/// frames of the natural observers.
+pub trait Symmetries: CoordinateSystem<Dimension = U4> {
+    /// The Killing vector of time translations
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self>;
+
+    /// The Killing vector of rotations about the axis
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self>;
+
+    /// The cosine of the polar angle theta
+    fn cos_theta(x: &Point<Self>) -> f64;
+
+    /// The radial coordinate r
+    fn radius(x: &Point<Self>) -> f64;
+
+    /// The squared norm of the angular part of a covector measured on the unit sphere, ie.
+    /// p_theta^2 + p_phi^2 / sin^2(theta)
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64;
+
+    /// The gradient of the radial coordinate, dr
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self>;
+
+    /// The orthonormal coframe of the unit sphere, (dtheta, sin(theta) dphi). At the poles, where
+    /// it isn't determined by the angles, it can be any orthonormal coframe.
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2];
+
+    /// The Carter constant of a geodesic with the momentum `p`, given its other conserved
+    /// quantities. The default is the one of spherically symmetric spacetimes, where it reduces
+    /// to L^2 - L_z^2; the charts of rotating spacetimes override it.
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        Self::sphere_norm_sq(p) - conserved.ang_momentum * conserved.ang_momentum
+    }
+}
+
+/// The quantities conserved along a geodesic in a stationary, axisymmetric spacetime, per unit
+/// mass of the particle.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConservedQuantities {
+    /// The Killing energy E
+    pub energy: f64,
+    /// The angular momentum about the axis L_z
+    pub ang_momentum: f64,
+    /// The norm of the 4-velocity g(u, u)
+    pub norm: f64,
+    /// The Carter constant Q
+    pub carter: f64,
+}
+
+impl ConservedQuantities {
+    /// Calculates the conserved quantities of a geodesic at point `x` with 4-velocity `v`; the
+    /// Carter constant is left to `Spacetime::carter_constant`, which knows the parameters of the
+    /// spacetime.
+    pub fn calculate<C, S>(spacetime: &S, x: &Point<C>, v: &Vector<C>) -> Self
+    where
+        C: Symmetries,
+        S: Spacetime<C>,
+    {
+        let p = lower(&spacetime.g(x), v);
+        let mut conserved = ConservedQuantities {
+            energy: contract(&p, &C::time_killing_vector(x)),
+            ang_momentum: -contract(&p, &C::axial_killing_vector(x)),
+            norm: contract(&p, v),
+            carter: 0.0,
+        };
+        conserved.carter = spacetime.carter_constant(&p, &conserved);
+        conserved
+    }
+
+    fn zip_with<F: Fn(f64, f64) -> f64>(&self, other: &Self, f: F) -> Self {
+        ConservedQuantities {
+            energy: f(self.energy, other.energy),
+            ang_momentum: f(self.ang_momentum, other.ang_momentum),
+            norm: f(self.norm, other.norm),
+            carter: f(self.carter, other.carter),
+        }
+    }
+}
+
+/// Tracks how far the conserved quantities drift from their initial values along a trajectory.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DriftReport {
+    /// The values at the start of the trajectory
+    pub initial: ConservedQuantities,
+    /// The largest absolute deviations from the initial values
+    pub max_drift: ConservedQuantities,
+    /// The deviations of the last added sample
+    pub last_drift: ConservedQuantities,
+    /// The number of samples, including the initial one
+    pub samples: usize,
+}
+
+impl DriftReport {
+    pub fn new(initial: ConservedQuantities) -> Self {
+        DriftReport {
+            initial,
+            max_drift: ConservedQuantities::default(),
+            last_drift: ConservedQuantities::default(),
+            samples: 1,
+        }
+    }
+
+    /// Builds a report from all the samples along a trajectory; returns `None` if there are none
+    pub fn from_samples<I: IntoIterator<Item = ConservedQuantities>>(samples: I) -> Option<Self> {
+        let mut samples = samples.into_iter();
+        let mut report = DriftReport::new(samples.next()?);
+        for sample in samples {
+            report.add(sample);
+        }
+        Some(report)
+    }
+
+    pub fn add(&mut self, sample: ConservedQuantities) {
+        self.last_drift = sample.zip_with(&self.initial, |x, x0| x - x0);
+        self.max_drift = self
+            .max_drift
+            .zip_with(&self.last_drift, |max, drift| max.max(drift.abs()));
+        self.samples += 1;
+    }
+
+    /// The largest deviations relative to the initial values; quantities that start at zero (like
+    /// the norm for light) are compared in absolute terms
+    pub fn max_relative_drift(&self) -> ConservedQuantities {
+        self.max_drift.zip_with(&self.initial, |drift, x0| {
+            if x0.abs() < f64::EPSILON {
+                drift
+            } else {
+                drift / x0.abs()
+            }
+        })
+    }
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let initial = &self.initial;
+        let max = &self.max_drift;
+        let rel = self.max_relative_drift();
+        writeln!(f, "Drift over {} samples:", self.samples)?;
+        write_row(f, "E", initial.energy, max.energy, rel.energy)?;
+        write_row(
+            f,
+            "L_z",
+            initial.ang_momentum,
+            max.ang_momentum,
+            rel.ang_momentum,
+        )?;
+        write_row(f, "g(u,u)", initial.norm, max.norm, rel.norm)?;
+        write_row(f, "Q", initial.carter, max.carter, rel.carter)
+    }
+}
+
+fn write_row(f: &mut fmt::Formatter, name: &str, initial: f64, max: f64, rel: f64) -> fmt::Result {
+    writeln!(
+        f,
+        "  {:<7} initial {:>15.8e}  max drift {:>10.3e}  relative {:>10.3e}",
+        name, initial, max, rel
+    )
+}
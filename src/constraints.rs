@@ -0,0 +1,147 @@
+use crate::conserved::Symmetries;
+use crate::spacetime::{combine, dot};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::CoordinateSystem;
+use diffgeom::tensors::{TwoForm, Vector};
+
+/// The causal character of a 4-velocity, which fixes its norm g(u, u)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Causality {
+    /// g(u, u) = 1 - massive particles
+    Timelike,
+    /// g(u, u) = 0 - light
+    Null,
+    /// g(u, u) = -1
+    Spacelike,
+}
+
+impl Causality {
+    /// The norm g(u, u) of a 4-velocity of this character
+    pub fn norm(self) -> f64 {
+        match self {
+            Causality::Timelike => 1.0,
+            Causality::Null => 0.0,
+            Causality::Spacelike => -1.0,
+        }
+    }
+}
+
+/// The constraints a 4-velocity is projected back onto after integration steps: the norm, and
+/// optionally the Killing energy and the axial angular momentum
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+    pub causality: Causality,
+    pub energy: Option<f64>,
+    pub ang_momentum: Option<f64>,
+}
+
+impl Constraints {
+    pub fn new(causality: Causality) -> Self {
+        Constraints {
+            causality,
+            energy: None,
+            ang_momentum: None,
+        }
+    }
+
+    pub fn with_energy(self, energy: f64) -> Self {
+        Constraints {
+            energy: Some(energy),
+            ..self
+        }
+    }
+
+    pub fn with_ang_momentum(self, ang_momentum: f64) -> Self {
+        Constraints {
+            ang_momentum: Some(ang_momentum),
+            ..self
+        }
+    }
+}
+
+// Restores the norm of `v`. Timelike and spacelike vectors are rescaled; null vectors (and
+// vectors that have drifted to the wrong causal character) get their time component adjusted.
+pub(crate) fn normalize<C>(g: &TwoForm<C>, v: &mut Vector<C>, causality: Causality)
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let target = causality.norm();
+    let norm = dot(g, v, v);
+    if target != 0.0 && norm / target > 0.0 {
+        *v /= (norm / target).sqrt();
+        return;
+    }
+
+    // solve g(v + l*e0, v + l*e0) = target for the root l closest to zero
+    let a = g[0];
+    let b = (0..4).map(|j| g[j] * v[j]).sum::<f64>();
+    let c = norm - target;
+    let delta = b * b - a * c;
+    let l = if delta < 0.0 {
+        // no exact solution; get as close as possible
+        -b / a
+    } else {
+        -c / (b + b.signum() * delta.sqrt())
+    };
+    if l.is_finite() {
+        v[0] += l;
+    }
+}
+
+// Projects `v` onto fixed values of the conserved quantities, and then restores its norm without
+// changing them. Returns false if the norm couldn't be restored this way.
+pub(crate) fn project<C: Symmetries>(
+    g: &TwoForm<C>,
+    v: &mut Vector<C>,
+    constraints: &Constraints,
+) -> bool {
+    if constraints.energy.is_none() && constraints.ang_momentum.is_none() {
+        normalize(g, v, constraints.causality);
+        return true;
+    }
+
+    let x = *v.get_point();
+    let xi_t = C::time_killing_vector(&x);
+    let xi_phi = C::axial_killing_vector(&x);
+    let g_tt = dot(g, &xi_t, &xi_t);
+    let g_tp = dot(g, &xi_t, &xi_phi);
+    let g_pp = dot(g, &xi_phi, &xi_phi);
+    let det = g_tt * g_pp - g_tp * g_tp;
+    // on the axis the rotations have no effect, so only the energy can be fixed there
+    let on_axis = det.abs() <= 1e-12 * g_tt.abs() * g_pp.abs() || g_pp == 0.0;
+
+    // shift v along the Killing vectors, so that g(xi_t, v) = E and -g(xi_phi, v) = L
+    let energy = dot(g, &xi_t, v);
+    let ang_momentum = -dot(g, &xi_phi, v);
+    let d_e = constraints.energy.map_or(0.0, |e| e - energy);
+    let d_l = constraints.ang_momentum.map_or(0.0, |l| l - ang_momentum);
+    let shift = if on_axis {
+        combine(d_e / g_tt, &xi_t, 0.0, &xi_phi)
+    } else {
+        let alpha = (g_pp * d_e + g_tp * d_l) / det;
+        let beta = -(g_tp * d_e + g_tt * d_l) / det;
+        combine(alpha, &xi_t, beta, &xi_phi)
+    };
+    *v = combine(1.0, v, 1.0, &shift);
+
+    // split v into the part spanned by the Killing vectors and the part orthogonal to them; only
+    // rescaling the latter keeps E and L fixed
+    let v_t = dot(g, &xi_t, v);
+    let v_p = dot(g, &xi_phi, v);
+    let parallel = if on_axis {
+        combine(v_t / g_tt, &xi_t, 0.0, &xi_phi)
+    } else {
+        let c_t = (g_pp * v_t - g_tp * v_p) / det;
+        let c_p = (g_tt * v_p - g_tp * v_t) / det;
+        combine(c_t, &xi_t, c_p, &xi_phi)
+    };
+    let perpendicular = combine(1.0, v, -1.0, &parallel);
+    let scale_sq = (constraints.causality.norm() - dot(g, &parallel, &parallel))
+        / dot(g, &perpendicular, &perpendicular);
+    if scale_sq >= 0.0 && scale_sq.is_finite() {
+        *v = combine(1.0, &parallel, scale_sq.sqrt(), &perpendicular);
+        true
+    } else {
+        false
+    }
+}
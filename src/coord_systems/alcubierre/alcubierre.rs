@@ -0,0 +1,135 @@
+use super::Properties;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The coordinates (t, x, y, z) of the Alcubierre warp drive moving along the x axis:
+/// ds^2 = dt^2 - (dx - beta dt)^2 - dy^2 - dz^2, with the shift beta = v(t) f(r_s) given by the
+/// velocity of the bubble and its shape f, which is 1 inside and 0 far away from it. r_s is the
+/// distance from the centre of the bubble.
+///
+/// The observers at rest with respect to the slices t = const, with the 4-velocity
+/// (1, beta, 0, 0), are in free fall and their proper time is t.
+pub struct Alcubierre<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Alcubierre<P> {
+    type Dimension = U4;
+}
+
+impl<P> Alcubierre<P> {
+    /// The shift beta and its gradient at the point `x`, for the bubble of the given radius and
+    /// wall thickness, centred at x_s moving with the velocity v and the acceleration dv/dt
+    /// given in `motion`
+    pub(crate) fn shift(
+        radius: f64,
+        thickness: f64,
+        motion: (f64, f64, f64),
+        x: &Point<Self>,
+    ) -> (f64, [f64; 4]) {
+        let (xs, v, a) = motion;
+        let dx = x[1] - xs;
+        let r = (dx * dx + x[2] * x[2] + x[3] * x[3]).sqrt();
+        let sigma = 1.0 / thickness;
+        let norm = 0.5 / (sigma * radius).tanh();
+        let (outer, inner) = ((sigma * (r + radius)).tanh(), (sigma * (r - radius)).tanh());
+        let f = norm * (outer - inner);
+        // df/dr / r; f is flat at the centre of the bubble
+        let df_r = if r > 0.0 {
+            norm * sigma * (inner * inner - outer * outer) / r
+        } else {
+            0.0
+        };
+        let beta = v * f;
+        (
+            beta,
+            [
+                a * f - v * v * df_r * dx,
+                v * df_r * dx,
+                v * df_r * x[2],
+                v * df_r * x[3],
+            ],
+        )
+    }
+
+    pub(crate) fn g_with(beta: f64, x: &Point<Self>) -> TwoForm<Self> {
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - beta*beta, beta, 0.0, 0.0,
+                beta, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(beta: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0, beta, 0.0, 0.0,
+                beta, beta*beta - 1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    /// `beta` and `dbeta` are the shift and its gradient at the point `x`, see `shift`
+    pub(crate) fn christoffel_with(
+        beta: f64,
+        dbeta: [f64; 4],
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let [bt, bx, by, bz] = dbeta;
+        let b2 = beta * beta;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                b2*bx, -beta*bx, -0.5*beta*by, -0.5*beta*bz,
+                -beta*bx, bx, 0.5*by, 0.5*bz,
+                -0.5*beta*by, 0.5*by, 0.0, 0.0,
+                -0.5*beta*bz, 0.5*bz, 0.0, 0.0,
+
+                -bt - beta*bx*(1.0 - b2), -b2*bx, -0.5*by*(1.0 + b2), -0.5*bz*(1.0 + b2),
+                -b2*bx, beta*bx, 0.5*beta*by, 0.5*beta*bz,
+                -0.5*by*(1.0 + b2), 0.5*beta*by, 0.0, 0.0,
+                -0.5*bz*(1.0 + b2), 0.5*beta*bz, 0.0, 0.0,
+
+                -beta*by, 0.5*by, 0.0, 0.0,
+                0.5*by, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                -beta*bz, 0.5*bz, 0.0, 0.0,
+                0.5*bz, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for Alcubierre<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        let (beta, _) = Self::shift(P::radius(), P::thickness(), P::motion(x[0]), x);
+        Self::g_with(beta, x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        let (beta, _) = Self::shift(P::radius(), P::thickness(), P::motion(x[0]), x);
+        Self::inv_g_with(beta, x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (beta, dbeta) = Self::shift(P::radius(), P::thickness(), P::motion(x[0]), x);
+        Self::christoffel_with(beta, dbeta, x)
+    }
+}
@@ -0,0 +1,33 @@
+#[allow(clippy::module_inception)]
+mod alcubierre;
+mod runtime;
+
+/// The parameters of a warp drive: the radius of the bubble, the thickness of its wall and the
+/// trajectory of its centre.
+pub trait Properties {
+    fn radius() -> f64;
+
+    /// The width of the wall, 1/sigma in Alcubierre's notation
+    fn thickness() -> f64;
+
+    /// The position of the centre of the bubble on the x axis at the time `t`, with its velocity
+    /// and acceleration
+    fn motion(t: f64) -> (f64, f64, f64);
+}
+
+/// The trajectory of a warp bubble only known at runtime: the position of its centre on the x
+/// axis at the time `t`, with its velocity and acceleration.
+///
+/// It's implemented for closures returning these three values.
+pub trait Motion {
+    fn motion(&self, t: f64) -> (f64, f64, f64);
+}
+
+impl<F: Fn(f64) -> (f64, f64, f64)> Motion for F {
+    fn motion(&self, t: f64) -> (f64, f64, f64) {
+        self(t)
+    }
+}
+
+pub use self::alcubierre::Alcubierre;
+pub use self::runtime::WarpDrive;
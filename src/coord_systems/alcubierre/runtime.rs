@@ -0,0 +1,48 @@
+use super::{Alcubierre, Motion};
+use crate::coord_systems::Runtime;
+use crate::Spacetime;
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+
+/// An Alcubierre warp drive with the parameters only known at runtime. It provides the metric of
+/// the charts parametrized with `Runtime`.
+#[derive(Clone, Debug)]
+pub struct WarpDrive<T> {
+    pub radius: f64,
+    /// The width of the wall of the bubble, 1/sigma in Alcubierre's notation
+    pub thickness: f64,
+    pub motion: T,
+}
+
+impl<T: Motion> WarpDrive<T> {
+    pub fn new(radius: f64, thickness: f64, motion: T) -> Self {
+        WarpDrive {
+            radius,
+            thickness,
+            motion,
+        }
+    }
+
+    /// The shift beta and its gradient at the point `x`
+    fn shift(&self, x: &Point<Alcubierre<Runtime>>) -> (f64, [f64; 4]) {
+        Alcubierre::shift(self.radius, self.thickness, self.motion.motion(x[0]), x)
+    }
+}
+
+impl<T: Motion> Spacetime<Alcubierre<Runtime>> for WarpDrive<T> {
+    fn g(&self, x: &Point<Alcubierre<Runtime>>) -> TwoForm<Alcubierre<Runtime>> {
+        Alcubierre::g_with(self.shift(x).0, x)
+    }
+
+    fn inv_g(&self, x: &Point<Alcubierre<Runtime>>) -> InvTwoForm<Alcubierre<Runtime>> {
+        Alcubierre::inv_g_with(self.shift(x).0, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Alcubierre<Runtime>>,
+    ) -> Tensor<Alcubierre<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (beta, dbeta) = self.shift(x);
+        Alcubierre::christoffel_with(beta, dbeta, x)
+    }
+}
@@ -0,0 +1,103 @@
+use super::{sphere_radius, Properties};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The comoving coordinates (eta, chi, theta, phi) with the conformal time eta, dt = a deta:
+/// ds^2 = a(eta)^2 (deta^2 - dchi^2 - S_k(chi)^2 dOmega^2). Radial light rays are the lines
+/// eta = ±chi + const.
+pub struct ConformalTime<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for ConformalTime<P> {
+    type Dimension = U4;
+}
+
+impl<P> ConformalTime<P> {
+    pub(crate) fn g_with(k: f64, a: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let (s, _) = sphere_radius(k, x[1]);
+        let th = x[2];
+        let a2 = a * a;
+        TwoForm::new(
+            *x,
+            arr![f64;
+                a2, 0.0, 0.0, 0.0,
+                0.0, -a2, 0.0, 0.0,
+                0.0, 0.0, -a2*s*s, 0.0,
+                0.0, 0.0, 0.0, -a2*s*s*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(k: f64, a: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let (s, _) = sphere_radius(k, x[1]);
+        let th = x[2];
+        let a2 = a * a;
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0/a2, 0.0, 0.0, 0.0,
+                0.0, -1.0/a2, 0.0, 0.0,
+                0.0, 0.0, -1.0/(a2*s*s), 0.0,
+                0.0, 0.0, 0.0, -1.0/(a2*s*s*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    /// `a` and `da` are the scale factor and its derivative over eta at the time of the point `x`
+    pub(crate) fn christoffel_with(
+        k: f64,
+        a: f64,
+        da: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (s, ds) = sphere_radius(k, x[1]);
+        let th = x[2];
+        let h = da / a;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                h, 0.0, 0.0, 0.0,
+                0.0, h, 0.0, 0.0,
+                0.0, 0.0, h*s*s, 0.0,
+                0.0, 0.0, 0.0, h*s*s*th.sin()*th.sin(),
+
+                0.0, h, 0.0, 0.0,
+                h, 0.0, 0.0, 0.0,
+                0.0, 0.0, -s*ds, 0.0,
+                0.0, 0.0, 0.0, -s*ds*th.sin()*th.sin(),
+
+                0.0, 0.0, h, 0.0,
+                0.0, 0.0, ds/s, 0.0,
+                h, ds/s, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, h,
+                0.0, 0.0, 0.0, ds/s,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                h, ds/s, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for ConformalTime<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::curvature(), P::scale_factor(x[0]).0, x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::curvature(), P::scale_factor(x[0]).0, x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (a, da) = P::scale_factor(x[0]);
+        Self::christoffel_with(P::curvature(), a, da, x)
+    }
+}
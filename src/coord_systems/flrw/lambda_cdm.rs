@@ -0,0 +1,290 @@
+use super::{ConformalTime, CosmicTime, ScaleFactor};
+use crate::coord_systems::Runtime;
+use crate::numeric::{hermite, segment};
+
+// the range of the scale factor covered by the table, and the number of its nodes
+const A_MIN: f64 = 1e-10;
+const A_MAX: f64 = 1e6;
+const NODES: usize = 4000;
+// the number of steps of the integration from the big bang to A_MIN
+const INITIAL_STEPS: usize = 4096;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    a: f64,
+    cosmic: f64,
+    conformal: f64,
+}
+
+/// The ΛCDM expansion history: a universe filled with dust, radiation and a cosmological
+/// constant, with the scale factor normalized to a = 1 today. The spatial curvature follows from
+/// the density parameters, see `curvature`.
+///
+/// Both time coordinates are zero at the big bang. The Friedmann equation is integrated once, on
+/// construction, over the expanding phase 1e-10 < a < 1e6; outside of it the scale factor is
+/// continued with a power law in the past and an exponential in the future. A closed universe
+/// that recollapses is only described up to the maximal expansion.
+#[derive(Clone, Debug)]
+pub struct LambdaCdm {
+    hubble: f64,
+    omega_matter: f64,
+    omega_radiation: f64,
+    omega_lambda: f64,
+    nodes: Vec<Node>,
+}
+
+impl LambdaCdm {
+    /// A universe with the Hubble constant `hubble` (in the inverse length units of the engine)
+    /// and the density parameters of matter and the cosmological constant today, without
+    /// radiation
+    pub fn new(hubble: f64, omega_matter: f64, omega_lambda: f64) -> Self {
+        let mut result = LambdaCdm {
+            hubble,
+            omega_matter,
+            omega_radiation: 0.0,
+            omega_lambda,
+            nodes: vec![],
+        };
+        result.tabulate();
+        result
+    }
+
+    pub fn with_radiation(mut self, omega_radiation: f64) -> Self {
+        self.omega_radiation = omega_radiation;
+        self.tabulate();
+        self
+    }
+
+    /// The Hubble constant, the Hubble rate today
+    pub fn hubble(&self) -> f64 {
+        self.hubble
+    }
+
+    /// The density parameter of matter today
+    pub fn omega_matter(&self) -> f64 {
+        self.omega_matter
+    }
+
+    /// The density parameter of radiation today
+    pub fn omega_radiation(&self) -> f64 {
+        self.omega_radiation
+    }
+
+    /// The density parameter of the cosmological constant today
+    pub fn omega_lambda(&self) -> f64 {
+        self.omega_lambda
+    }
+
+    /// The density parameter of the spatial curvature today
+    pub fn omega_curvature(&self) -> f64 {
+        1.0 - self.omega_matter - self.omega_radiation - self.omega_lambda
+    }
+
+    /// The spatial curvature k of the `Flrw` spacetime with this expansion history
+    pub fn curvature(&self) -> f64 {
+        -self.omega_curvature() * self.hubble * self.hubble
+    }
+
+    /// The Hubble rate H = (da/dt) / a at the scale factor `a`
+    pub fn hubble_rate(&self, a: f64) -> f64 {
+        self.hubble * self.friedmann(a).max(0.0).sqrt() / (a * a)
+    }
+
+    /// The cosmic time at which the scale factor reaches 1
+    pub fn age(&self) -> f64 {
+        self.cosmic_from_scale_factor(1.0)
+    }
+
+    /// The conformal time at which the scale factor reaches 1
+    pub fn conformal_age(&self) -> f64 {
+        self.conformal_from_cosmic(self.age())
+    }
+
+    /// The conformal time corresponding to the cosmic time `t`. With a positive cosmological
+    /// constant it approaches a finite limit in the infinite future.
+    pub fn conformal_from_cosmic(&self, t: f64) -> f64 {
+        let first = self.nodes[0];
+        let last = self.nodes[self.nodes.len() - 1];
+        if t < first.cosmic {
+            let p = self.early_exponent(&first);
+            first.conformal
+                - first.cosmic / (first.a * (1.0 - p))
+                    * (1.0 - (t.max(0.0) / first.cosmic).powf(1.0 - p))
+        } else if t > last.cosmic {
+            let h = self.hubble_rate(last.a);
+            let dt = t - last.cosmic;
+            if h > 0.0 {
+                last.conformal + (1.0 - (-h * dt).exp()) / (last.a * h)
+            } else {
+                last.conformal + dt / last.a
+            }
+        } else {
+            let i = segment(&self.nodes, t, |n| n.cosmic);
+            let (n0, n1) = (self.nodes[i], self.nodes[i + 1]);
+            hermite(
+                t,
+                (n0.cosmic, n0.conformal, 1.0 / n0.a),
+                (n1.cosmic, n1.conformal, 1.0 / n1.a),
+            )
+            .0
+        }
+    }
+
+    /// The cosmic time corresponding to the conformal time `eta`
+    pub fn cosmic_from_conformal(&self, eta: f64) -> f64 {
+        let first = self.nodes[0];
+        let last = self.nodes[self.nodes.len() - 1];
+        if eta < first.conformal {
+            let p = self.early_exponent(&first);
+            let base = 1.0 - (first.conformal - eta) * first.a * (1.0 - p) / first.cosmic;
+            first.cosmic * base.max(0.0).powf(1.0 / (1.0 - p))
+        } else if eta > last.conformal {
+            let h = self.hubble_rate(last.a);
+            let deta = eta - last.conformal;
+            if h > 0.0 {
+                last.cosmic - (1.0 - deta * last.a * h).ln() / h
+            } else {
+                last.cosmic + deta * last.a
+            }
+        } else {
+            let i = segment(&self.nodes, eta, |n| n.conformal);
+            let (n0, n1) = (self.nodes[i], self.nodes[i + 1]);
+            hermite(
+                eta,
+                (n0.conformal, n0.cosmic, n0.a),
+                (n1.conformal, n1.cosmic, n1.a),
+            )
+            .0
+        }
+    }
+
+    /// The cosmic time at which the universe has the scale factor `a`
+    pub fn cosmic_from_scale_factor(&self, a: f64) -> f64 {
+        let i = segment(&self.nodes, a, |n| n.a);
+        let (n0, n1) = (self.nodes[i], self.nodes[i + 1]);
+        hermite(
+            a,
+            (n0.a, n0.cosmic, 1.0 / (n0.a * self.hubble_rate(n0.a))),
+            (n1.a, n1.cosmic, 1.0 / (n1.a * self.hubble_rate(n1.a))),
+        )
+        .0
+    }
+
+    // a^4 H^2 / H0^2, which stays finite at the big bang
+    fn friedmann(&self, a: f64) -> f64 {
+        self.omega_radiation
+            + a * (self.omega_matter + a * (self.omega_curvature() + a * a * self.omega_lambda))
+    }
+
+    // the exponent p of the power law a ~ t^p matching the expansion at the given node
+    fn early_exponent(&self, node: &Node) -> f64 {
+        node.cosmic * self.hubble_rate(node.a)
+    }
+
+    fn tabulate(&mut self) {
+        assert!(
+            self.omega_matter + self.omega_radiation > 0.0,
+            "a ΛCDM universe needs matter or radiation to start with a big bang"
+        );
+        // from the big bang to A_MIN, with a = x^2 to remove the singularity of the integrands
+        let h0 = self.hubble;
+        let x_min = A_MIN.sqrt();
+        let dx = x_min / INITIAL_STEPS as f64;
+        let (mut cosmic, mut conformal) = (0.0, 0.0);
+        for i in 0..INITIAL_STEPS {
+            let x = (i as f64 + 0.5) * dx;
+            let e = h0 * self.friedmann(x * x).sqrt();
+            cosmic += 2.0 * x * x * x / e * dx;
+            conformal += 2.0 * x / e * dx;
+        }
+
+        // then with Simpson's rule in ln(a), dt = a^2 / (H0 sqrt(F)) dln(a)
+        let rates = |a: f64| {
+            let f = self.friedmann(a);
+            if f <= 0.0 {
+                None
+            } else {
+                let e = h0 * f.sqrt();
+                Some((a * a / e, a / e))
+            }
+        };
+        let du = (A_MAX / A_MIN).ln() / NODES as f64;
+        let mut nodes = vec![Node {
+            a: A_MIN,
+            cosmic,
+            conformal,
+        }];
+        for i in 0..NODES {
+            let a0 = A_MIN * (i as f64 * du).exp();
+            let (am, a1) = (a0 * (0.5 * du).exp(), a0 * du.exp());
+            let (r0, rm, r1) = match (rates(a0), rates(am), rates(a1)) {
+                (Some(r0), Some(rm), Some(r1)) => (r0, rm, r1),
+                // the universe recollapses before reaching a1
+                _ => break,
+            };
+            cosmic += (r0.0 + 4.0 * rm.0 + r1.0) * du / 6.0;
+            conformal += (r0.1 + 4.0 * rm.1 + r1.1) * du / 6.0;
+            nodes.push(Node {
+                a: a1,
+                cosmic,
+                conformal,
+            });
+        }
+        self.nodes = nodes;
+    }
+
+    // the scale factor and its derivative at the time `time` in the column `column` of the
+    // table; `slope` gives da/dtime at a node
+    fn interpolate<C, S>(&self, time: f64, column: C, slope: S) -> (f64, f64)
+    where
+        C: Fn(&Node) -> f64,
+        S: Fn(f64) -> f64,
+    {
+        let i = segment(&self.nodes, time, &column);
+        let (n0, n1) = (self.nodes[i], self.nodes[i + 1]);
+        let (a, _) = hermite(
+            time,
+            (column(&n0), n0.a, slope(n0.a)),
+            (column(&n1), n1.a, slope(n1.a)),
+        );
+        (a, slope(a))
+    }
+}
+
+impl ScaleFactor<CosmicTime<Runtime>> for LambdaCdm {
+    fn scale_factor(&self, t: f64) -> (f64, f64) {
+        let first = self.nodes[0];
+        let last = self.nodes[self.nodes.len() - 1];
+        if t < first.cosmic {
+            let p = self.early_exponent(&first);
+            let a = first.a * (t.max(0.0) / first.cosmic).powf(p);
+            (a, p * a / t)
+        } else if t > last.cosmic {
+            let h = self.hubble_rate(last.a);
+            let a = last.a * (h * (t - last.cosmic)).exp();
+            (a, h * a)
+        } else {
+            self.interpolate(t, |n| n.cosmic, |a| a * self.hubble_rate(a))
+        }
+    }
+}
+
+impl ScaleFactor<ConformalTime<Runtime>> for LambdaCdm {
+    fn scale_factor(&self, eta: f64) -> (f64, f64) {
+        let first = self.nodes[0];
+        let last = self.nodes[self.nodes.len() - 1];
+        if eta < first.conformal {
+            let (a, da) = ScaleFactor::<CosmicTime<Runtime>>::scale_factor(
+                self,
+                self.cosmic_from_conformal(eta),
+            );
+            (a, a * da)
+        } else if eta > last.conformal {
+            let h = self.hubble_rate(last.a);
+            let a = last.a / (1.0 - last.a * h * (eta - last.conformal));
+            (a, h * a * a)
+        } else {
+            self.interpolate(eta, |n| n.conformal, |a| a * a * self.hubble_rate(a))
+        }
+    }
+}
@@ -0,0 +1,49 @@
+mod conformal;
+mod cosmic;
+mod lambda_cdm;
+mod runtime;
+
+/// The parameters of a Friedmann-Lemaître-Robertson-Walker spacetime: the spatial curvature k
+/// (positive for the closed, zero for the flat and negative for the open universe) and the scale
+/// factor, as a function of the time coordinate of the chart it's used with.
+pub trait Properties {
+    fn curvature() -> f64;
+
+    /// The scale factor a and its derivative over the time coordinate
+    fn scale_factor(time: f64) -> (f64, f64);
+}
+
+/// The expansion history of a FLRW spacetime whose parameters are only known at runtime: the
+/// scale factor as a function of the time coordinate of the chart `C`.
+///
+/// It's implemented for closures returning a and da/dtime, which are then interpreted in the
+/// time coordinate of whichever chart they are used with.
+pub trait ScaleFactor<C> {
+    /// The scale factor a and its derivative over the time coordinate of `C`
+    fn scale_factor(&self, time: f64) -> (f64, f64);
+}
+
+impl<C, F: Fn(f64) -> (f64, f64)> ScaleFactor<C> for F {
+    fn scale_factor(&self, time: f64) -> (f64, f64) {
+        self(time)
+    }
+}
+
+/// The radius S_k(chi) of the sphere at the comoving distance chi from the origin, per unit
+/// scale factor, and its derivative dS_k/dchi
+pub(crate) fn sphere_radius(k: f64, chi: f64) -> (f64, f64) {
+    if k > 0.0 {
+        let sk = k.sqrt();
+        ((sk * chi).sin() / sk, (sk * chi).cos())
+    } else if k < 0.0 {
+        let sk = (-k).sqrt();
+        ((sk * chi).sinh() / sk, (sk * chi).cosh())
+    } else {
+        (chi, 1.0)
+    }
+}
+
+pub use self::conformal::ConformalTime;
+pub use self::cosmic::CosmicTime;
+pub use self::lambda_cdm::LambdaCdm;
+pub use self::runtime::Flrw;
@@ -0,0 +1,117 @@
+use super::{ConformalTime, CosmicTime, LambdaCdm, ScaleFactor};
+use crate::coord_systems::Runtime;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use generic_array::arr;
+
+/// A Friedmann-Lemaître-Robertson-Walker universe with the spatial curvature and the scale
+/// factor only known at runtime. It provides the metric of the charts parametrized with
+/// `Runtime` for which `S` gives the scale factor.
+#[derive(Clone, Debug)]
+pub struct Flrw<S> {
+    /// The spatial curvature k; positive for the closed, zero for the flat and negative for the
+    /// open universe
+    pub curvature: f64,
+    pub scale_factor: S,
+}
+
+impl<S> Flrw<S> {
+    pub fn new(curvature: f64, scale_factor: S) -> Self {
+        Flrw {
+            curvature,
+            scale_factor,
+        }
+    }
+}
+
+impl Flrw<LambdaCdm> {
+    /// The universe with the ΛCDM expansion history, with the curvature matching its density
+    /// parameters
+    pub fn lambda_cdm(model: LambdaCdm) -> Self {
+        Flrw::new(model.curvature(), model)
+    }
+}
+
+impl<S: ScaleFactor<CosmicTime<Runtime>>> Spacetime<CosmicTime<Runtime>> for Flrw<S> {
+    fn g(&self, x: &Point<CosmicTime<Runtime>>) -> TwoForm<CosmicTime<Runtime>> {
+        let (a, _) = self.scale_factor.scale_factor(x[0]);
+        CosmicTime::g_with(self.curvature, a, x)
+    }
+
+    fn inv_g(&self, x: &Point<CosmicTime<Runtime>>) -> InvTwoForm<CosmicTime<Runtime>> {
+        let (a, _) = self.scale_factor.scale_factor(x[0]);
+        CosmicTime::inv_g_with(self.curvature, a, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<CosmicTime<Runtime>>,
+    ) -> Tensor<CosmicTime<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (a, da) = self.scale_factor.scale_factor(x[0]);
+        CosmicTime::christoffel_with(self.curvature, a, da, x)
+    }
+}
+
+impl<S: ScaleFactor<ConformalTime<Runtime>>> Spacetime<ConformalTime<Runtime>> for Flrw<S> {
+    fn g(&self, x: &Point<ConformalTime<Runtime>>) -> TwoForm<ConformalTime<Runtime>> {
+        let (a, _) = self.scale_factor.scale_factor(x[0]);
+        ConformalTime::g_with(self.curvature, a, x)
+    }
+
+    fn inv_g(&self, x: &Point<ConformalTime<Runtime>>) -> InvTwoForm<ConformalTime<Runtime>> {
+        let (a, _) = self.scale_factor.scale_factor(x[0]);
+        ConformalTime::inv_g_with(self.curvature, a, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<ConformalTime<Runtime>>,
+    ) -> Tensor<ConformalTime<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        let (a, da) = self.scale_factor.scale_factor(x[0]);
+        ConformalTime::christoffel_with(self.curvature, a, da, x)
+    }
+}
+
+// Conversions; they need the relation between the two times, which only the ΛCDM model knows
+
+impl SpacetimeConversion<CosmicTime<Runtime>, ConformalTime<Runtime>> for Flrw<LambdaCdm> {
+    fn convert_point(&self, p: &Point<CosmicTime<Runtime>>) -> Point<ConformalTime<Runtime>> {
+        let eta = self.scale_factor.conformal_from_cosmic(p[0]);
+        Point::new(arr![f64; eta, p[1], p[2], p[3]])
+    }
+
+    fn jacobian(&self, p: &Point<CosmicTime<Runtime>>) -> Matrix<ConformalTime<Runtime>> {
+        let (a, _) = ScaleFactor::<CosmicTime<Runtime>>::scale_factor(&self.scale_factor, p[0]);
+        Matrix::new(
+            self.convert_point(p),
+            arr![f64;
+                1.0 / a, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl SpacetimeConversion<ConformalTime<Runtime>, CosmicTime<Runtime>> for Flrw<LambdaCdm> {
+    fn convert_point(&self, p: &Point<ConformalTime<Runtime>>) -> Point<CosmicTime<Runtime>> {
+        let t = self.scale_factor.cosmic_from_conformal(p[0]);
+        Point::new(arr![f64; t, p[1], p[2], p[3]])
+    }
+
+    fn jacobian(&self, p: &Point<ConformalTime<Runtime>>) -> Matrix<CosmicTime<Runtime>> {
+        let (a, _) = ScaleFactor::<ConformalTime<Runtime>>::scale_factor(&self.scale_factor, p[0]);
+        Matrix::new(
+            self.convert_point(p),
+            arr![f64;
+                a, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use super::{EddingtonFinkelstein, NearPole0EF, NearPolePiEF};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the Eddington-Finkelstein chart and its polar patches
+pub struct EFAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for EFAtlas<P> {
+    type Main = EddingtonFinkelstein<P>;
+    type Pole0 = NearPole0EF<P>;
+    type PolePi = NearPolePiEF<P>;
+}
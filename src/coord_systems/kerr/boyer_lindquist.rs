@@ -0,0 +1,266 @@
+use super::{CarterConstant, EddingtonFinkelstein, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Boyer-Lindquist coordinates (t, r, theta, phi). They are singular at the horizons, so
+/// they describe either the exterior region or the region between the horizons - but not both
+/// at once. Requires a^2 + Q^2 < M^2.
+pub struct BoyerLindquist<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for BoyerLindquist<P> {
+    type Dimension = U4;
+}
+
+impl<P> BoyerLindquist<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let rho2 = r * r + a * a * th.cos() * th.cos();
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let z = (2.0 * m * r - q * q) * a * th.sin() * th.sin() / rho2;
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - (2.0*m*r - q*q)/rho2,  0.0,          0.0,   z,
+                0.0,                        -rho2/delta,   0.0,   0.0,
+                0.0,                         0.0,         -rho2,  0.0,
+                z,                           0.0,          0.0,  -(r*r + a*a + a*z)*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let rho2 = r * r + a * a * th.cos() * th.cos();
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let sin2 = th.sin() * th.sin();
+        let big_a = (r * r + a * a) * (r * r + a * a) - a * a * delta * sin2;
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                big_a/(rho2*delta),                0.0,          0.0,       (2.0*m*r - q*q)*a/(rho2*delta),
+                0.0,                              -delta/rho2,   0.0,       0.0,
+                0.0,                               0.0,         -1.0/rho2,  0.0,
+                (2.0*m*r - q*q)*a/(rho2*delta),    0.0,          0.0,      -(delta - a*a*sin2)/(rho2*delta*sin2)
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let rho2 = r * r + a * a * th.cos() * th.cos();
+        let rho2x = r * r - a * a * th.cos() * th.cos();
+        let rho4 = rho2 * rho2;
+        let rho6 = rho2 * rho4;
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        // m * rho2x and 2mr, corrected for the charge
+        let mx = m * rho2x - q * q * r;
+        let mr2 = 2.0 * m * r - q * q;
+
+        let ttr = (r * r + a * a) * mx / (rho4 * delta);
+        let ttth = -mr2 * a * a * th.sin() * th.cos() / rho4;
+        let trp =
+            -a * th.sin() * th.sin() * ((r * r + a * a) * mx + mr2 * r * rho2) / (rho4 * delta);
+        let tthp = mr2 * a * a * a * th.sin() * th.sin() * th.sin() * th.cos() / rho4;
+
+        let rtt = mx * delta / rho6;
+        let rtp = -a * delta * mx * th.sin() * th.sin() / rho6;
+        let rrr = (r * a * a * th.sin() * th.sin() - mx) / (rho2 * delta);
+        let rrt = -a * a * th.sin() * th.cos() / rho2;
+        let rthth = -r * delta / rho2;
+        let rpp =
+            delta * th.sin() * th.sin() * (mx * a * a * th.sin() * th.sin() - r * rho4) / rho6;
+
+        let thtt = -mr2 * a * a * th.sin() * th.cos() / rho6;
+        let thtp = mr2 * a * (r * r + a * a) * th.sin() * th.cos() / rho6;
+        let thrr = a * a * th.sin() * th.cos() / (rho2 * delta);
+        let thrth = r / rho2;
+        let ththth = -a * a * th.sin() * th.cos() / rho2;
+        let thpp = -th.sin()
+            * th.cos()
+            * (rho4 * (r * r + a * a) + mr2 * a * a * th.sin() * th.sin() * (r * r + a * a + rho2))
+            / rho6;
+
+        let ptr = a * mx / (rho4 * delta);
+        let ptth = -mr2 * a * th.cos() / (rho4 * th.sin());
+        let prp = (r * rho4 - mr2 * r * rho2 - a * a * th.sin() * th.sin() * mx) / (rho4 * delta);
+        let pthp = th.cos() / th.sin() * (1.0 + mr2 * a * a * th.sin() * th.sin() / rho4);
+
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, ttr, ttth, 0.0,
+                ttr, 0.0, 0.0, trp,
+                ttth, 0.0, 0.0, tthp,
+                0.0, trp, tthp, 0.0,
+
+                rtt, 0.0, 0.0, rtp,
+                0.0, rrr, rrt, 0.0,
+                0.0, rrt, rthth, 0.0,
+                rtp, 0.0, 0.0, rpp,
+
+                thtt, 0.0, 0.0, thtp,
+                0.0, thrr, thrth, 0.0,
+                0.0, thrth, ththth, 0.0,
+                thtp, 0.0, 0.0, thpp,
+
+                0.0, ptr, ptth, 0.0,
+                ptr, 0.0, 0.0, prp,
+                ptth, 0.0, 0.0, pthp,
+                0.0, prp, pthp, 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for BoyerLindquist<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for BoyerLindquist<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+// Returns the integrals of (r^2 + a^2)/delta and a/delta over r, which are the differences
+// between the Eddington-Finkelstein and Boyer-Lindquist u/t and phi coordinates, respectively
+pub(crate) fn radial_integrals(m: f64, a: f64, q: f64, r: f64) -> (f64, f64) {
+    let sqrt_d = (m * m - a * a - q * q).sqrt();
+    let r_plus = m + sqrt_d;
+    let r_minus = m - sqrt_d;
+    let ln_plus = ((r - r_plus) / (2.0 * m)).abs().ln();
+    let ln_minus = ((r - r_minus) / (2.0 * m)).abs().ln();
+    // r_plus^2 + a^2 = 2 m r_plus - q^2, and likewise for r_minus
+    let dt = r
+        + ((2.0 * m * r_plus - q * q) * ln_plus - (2.0 * m * r_minus - q * q) * ln_minus)
+            / (2.0 * sqrt_d);
+    let dphi = a / (2.0 * sqrt_d) * (ln_plus - ln_minus);
+    (dt, dphi)
+}
+
+impl<P> BoyerLindquist<P> {
+    pub(crate) fn to_ef_point_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Point<EddingtonFinkelstein<P>> {
+        let (dt, dphi) = radial_integrals(m, a, q, p[1]);
+        Point::new(arr![f64; p[0] + dt, p[1], p[2], p[3] + dphi])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<EddingtonFinkelstein<P>> {
+        let r = p[1];
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let dudr = (r * r + a * a) / delta;
+        let dphidr = a / delta;
+        Matrix::new(
+            Self::to_ef_point_with(m, a, q, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for BoyerLindquist<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let m = P::mass();
+        let a = P::ang_momentum();
+        let q = P::charge();
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let dudr = (r * r + a * a) / delta;
+        let dphidr = a / delta;
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
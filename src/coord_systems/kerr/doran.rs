@@ -0,0 +1,273 @@
+use super::{CarterConstant, EddingtonFinkelstein, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Doran coordinates (T, r, theta, phi), the rotating generalization of the
+/// Painlevé-Gullstrand coordinates: T is the proper time of observers falling from rest at
+/// infinity with zero angular momentum. The chart covers the exterior and the interior of the
+/// black hole down to the radius where 2mr = q^2.
+pub struct Doran<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Doran<P> {
+    type Dimension = U4;
+}
+
+impl<P> Doran<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let sin2 = th.sin() * th.sin();
+        let rho2 = r * r + a * a * th.cos() * th.cos();
+        let r2a2 = r * r + a * a;
+        let mu = 2.0 * m * r - q * q;
+        // the velocity of the free-falling observers
+        let b = (mu / r2a2).sqrt();
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - mu/rho2, -b, 0.0, mu*a*sin2/rho2,
+                -b, -rho2/r2a2, 0.0, b*a*sin2,
+                0.0, 0.0, -rho2, 0.0,
+                mu*a*sin2/rho2, b*a*sin2, 0.0, -(r2a2 + mu*a*a*sin2/rho2)*sin2
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let sin2 = th.sin() * th.sin();
+        let rho2 = r * r + a * a * th.cos() * th.cos();
+        let r2a2 = r * r + a * a;
+        let mu = 2.0 * m * r - q * q;
+        let b = (mu / r2a2).sqrt();
+        let delta = r2a2 - mu;
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0, -b*r2a2/rho2, 0.0, 0.0,
+                -b*r2a2/rho2, -delta/rho2, 0.0, -b*a/rho2,
+                0.0, 0.0, -1.0/rho2, 0.0,
+                0.0, -b*a/rho2, 0.0, -(1.0/sin2 - a*a/r2a2)/rho2
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let (sin_th, cos_th) = x[2].sin_cos();
+        let sin2 = sin_th * sin_th;
+        let sc = sin_th * cos_th;
+        let rho2 = r * r + a * a * cos_th * cos_th;
+        let rho4 = rho2 * rho2;
+        let r2a2 = r * r + a * a;
+        let mu = 2.0 * m * r - q * q;
+        let k = mu.sqrt();
+        let sqrt_r2a2 = r2a2.sqrt();
+        let mu_rho2 = mu / rho2;
+        // the derivatives of mu / rho^2 and of the velocity b = sqrt(mu / (r^2 + a^2))
+        let mu_rho2_r = 2.0 * (m * rho2 - mu * r) / rho4;
+        let mu_rho2_th = 2.0 * mu * a * a * sc / rho4;
+        let b_r = (m / k - k * r / r2a2) / sqrt_r2a2;
+
+        // dg[i][j][k] = dg_ij/dx^k
+        let mut dg = [[[0.0; 4]; 4]; 4];
+        dg[0][0][1] = -mu_rho2_r;
+        dg[0][0][2] = -mu_rho2_th;
+        dg[0][1][1] = -b_r;
+        dg[0][3][1] = a * sin2 * mu_rho2_r;
+        dg[0][3][2] = a * (sin2 * mu_rho2_th + 2.0 * sc * mu_rho2);
+        dg[1][1][1] = -2.0 * r * a * a * sin2 / (r2a2 * r2a2);
+        dg[1][1][2] = 2.0 * a * a * sc / r2a2;
+        dg[1][3][1] = a * sin2 * b_r;
+        dg[1][3][2] = 2.0 * k * a * sc / sqrt_r2a2;
+        dg[2][2][1] = -2.0 * r;
+        dg[2][2][2] = 2.0 * a * a * sc;
+        dg[3][3][1] = -2.0 * r * sin2 - a * a * sin2 * sin2 * mu_rho2_r;
+        dg[3][3][2] =
+            -2.0 * r2a2 * sc - a * a * (sin2 * sin2 * mu_rho2_th + 4.0 * sin2 * sc * mu_rho2);
+        for (i, j) in [(0, 1), (0, 3), (1, 3)] {
+            dg[j][i] = dg[i][j];
+        }
+
+        christoffel_from_dg(x, &Self::inv_g_with(m, a, q, x), &dg)
+    }
+}
+
+impl<P: Properties> MetricSystem for Doran<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for Doran<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+// the nodes and weights of the 8-point Gauss-Legendre quadrature on [-1, 1]
+const GAUSS_NODES: [f64; 4] = [
+    0.183_434_642_495_649_8,
+    0.525_532_409_916_329,
+    0.796_666_477_413_626_7,
+    0.960_289_856_497_536_3,
+];
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.362_683_783_378_362,
+    0.313_706_645_877_887_3,
+    0.222_381_034_453_374_5,
+    0.101_228_536_290_376_3,
+];
+
+/// The derivatives of the Eddington-Finkelstein coordinates u and phi with respect to r along
+/// the lines of constant Doran coordinates T and phi
+pub(crate) fn free_fall_derivatives(m: f64, a: f64, q: f64, r: f64) -> (f64, f64) {
+    let sqrt_r2a2 = (r * r + a * a).sqrt();
+    let sigma = sqrt_r2a2 + (2.0 * m * r - q * q).sqrt();
+    (sqrt_r2a2 / sigma, a / (sqrt_r2a2 * sigma))
+}
+
+/// The differences u - T and phi_EF - phi_Doran at radius r: the integrals of
+/// `free_fall_derivatives` from the innermost radius of the chart, where 2mr = q^2
+pub(crate) fn free_fall_integrals(m: f64, a: f64, q: f64, r: f64) -> (f64, f64) {
+    // with r = r0 + s^2 the integrands are smooth functions of s; their singularities closest to
+    // the real axis are at |s| ~ sqrt(a), so the panels grow geometrically from that scale
+    let r0 = 0.5 * q * q / m;
+    let s_max = (r - r0).sqrt();
+    let s_scale = if a == 0.0 {
+        m.sqrt()
+    } else {
+        a.abs().sqrt().min(m.sqrt())
+    };
+    let (mut dt, mut dphi) = (0.0, 0.0);
+    let mut start = 0.0;
+    while start < s_max {
+        let end = (start + 0.25 * (start + s_scale)).min(s_max);
+        let (center, half_width) = (0.5 * (start + end), 0.5 * (end - start));
+        for (&node, &weight) in GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter()) {
+            for &s in &[center - half_width * node, center + half_width * node] {
+                let (du, dphi_ef) = free_fall_derivatives(m, a, q, r0 + s * s);
+                // dr = 2s ds
+                dt += weight * du * 2.0 * s * half_width;
+                dphi += weight * dphi_ef * 2.0 * s * half_width;
+            }
+        }
+        start = end;
+    }
+    (dt, dphi)
+}
+
+impl<P> Doran<P> {
+    pub(crate) fn to_ef_point_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Point<EddingtonFinkelstein<P>> {
+        let (dt, dphi) = free_fall_integrals(m, a, q, p[1]);
+        Point::new(arr![f64; p[0] + dt, p[1], p[2], p[3] + dphi])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<EddingtonFinkelstein<P>> {
+        let (dudr, dphidr) = free_fall_derivatives(m, a, q, p[1]);
+        Matrix::new(
+            Self::to_ef_point_with(m, a, q, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for Doran<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = free_fall_derivatives(P::mass(), P::ang_momentum(), P::charge(), p[1]);
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -1,48 +1,51 @@
-use super::{NearPole0EF, NearPolePiEF, Properties};
+use super::boyer_lindquist::radial_integrals;
+use super::doran::{free_fall_derivatives, free_fall_integrals};
+use super::{
+    BoyerLindquist, CarterConstant, Doran, KerrSchild, NearPole0EF, NearPolePiEF, Properties,
+};
+use crate::conserved::{ConservedQuantities, Symmetries};
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-pub struct EddingtonFinkelstein<P: Properties> {
+pub struct EddingtonFinkelstein<P> {
     _m: PhantomData<P>,
 }
 
-impl<P: Properties> CoordinateSystem for EddingtonFinkelstein<P> {
+impl<P> CoordinateSystem for EddingtonFinkelstein<P> {
     type Dimension = U4;
 }
 
-impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
-    fn g(x: &Point<Self>) -> TwoForm<Self> {
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = P::mass();
-        let a = P::ang_momentum();
         let rho2 = r * r + a * a * th.cos() * th.cos();
-        let z = 2.0 * m * r * a * th.sin() * th.sin() / rho2;
+        let z = (2.0 * m * r - q * q) * a * th.sin() * th.sin() / rho2;
         TwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
-                 1.0 - 2.0*m*r/rho2, -1.0,                  0.0,   z,
-                -1.0,                 0.0,                  0.0,   a*th.sin()*th.sin(),
-                 0.0,                 0.0,                 -rho2,  0.0,
-                 z,                   a*th.sin()*th.sin(),  0.0,  -(r*r + a*a + z)*th.sin()*th.sin()
+                 1.0 - (2.0*m*r - q*q)/rho2, -1.0,                  0.0,   z,
+                -1.0,                          0.0,                  0.0,   a*th.sin()*th.sin(),
+                 0.0,                          0.0,                 -rho2,  0.0,
+                 z,                            a*th.sin()*th.sin(),  0.0,  -(r*r + a*a + a*z)*th.sin()*th.sin()
             ],
         )
     }
 
-    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = P::mass();
-        let a = P::ang_momentum();
         let rho2 = r * r + a * a * th.cos() * th.cos();
-        let delta = r * r - 2.0 * m * r + a * a;
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
         InvTwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
                 -a*a*th.sin()*th.sin()/rho2, -(r*r + a*a)/rho2,  0.0,      -a/rho2,
                 -(r*r + a*a)/rho2,           -delta/rho2,        0.0,      -a/rho2,
@@ -52,63 +55,64 @@ impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
         )
     }
 
-    fn christoffel(
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
         x: &Point<Self>,
     ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
         let r = x[1];
         let th = x[2];
-        let m = P::mass();
-        let a = P::ang_momentum();
         let rho2 = r * r + a * a * th.cos() * th.cos();
         let rho2x = r * r - a * a * th.cos() * th.cos();
         let rho4 = rho2 * rho2;
         let rho6 = rho2 * rho4;
-        let delta = r * r - 2.0 * m * r + a * a;
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        // m * rho2x and 2mr, corrected for the charge
+        let mx = m * rho2x - q * q * r;
+        let mr2 = 2.0 * m * r - q * q;
 
-        let uuu = m * (r * r + a * a) * rho2x / rho6;
-        let uut = -m * r * a * a * (2.0 * th).sin() / rho4;
-        let uup = -m * a * (r * r + a * a) * rho2x * th.sin() * th.sin() / rho6;
+        let uuu = (r * r + a * a) * mx / rho6;
+        let uut = -mr2 * a * a * th.sin() * th.cos() / rho4;
+        let uup = -a * (r * r + a * a) * mx * th.sin() * th.sin() / rho6;
         let urt = -a * a * th.sin() * th.cos() / rho2;
         let urp = a * r * th.sin() * th.sin() / rho2;
         let utt = -r * (r * r + a * a) / rho2;
-        let utp = 2.0 * m * r * a * a * a * th.sin() * th.sin() * th.sin() * th.cos() / rho4;
+        let utp = mr2 * a * a * a * th.sin() * th.sin() * th.sin() * th.cos() / rho4;
         let upp = (r * r + a * a) / rho2
-            * (m * rho2x * a * a * th.sin() * th.sin() * th.sin() * th.sin() / rho4
+            * (mx * a * a * th.sin() * th.sin() * th.sin() * th.sin() / rho4
                 - r * th.sin() * th.sin());
 
-        let ruu = m * rho2x * delta / rho6;
-        let rur = -m * rho2x / rho4;
-        let rup = -m * rho2x * delta * a * th.sin() * th.sin() / rho6;
+        let ruu = mx * delta / rho6;
+        let rur = -mx / rho4;
+        let rup = -mx * delta * a * th.sin() * th.sin() / rho6;
         let rrt = -a * a * th.sin() * th.cos() / rho2;
-        let rrp = (r * rho2 + m * rho2x) * a * th.sin() * th.sin() / rho4;
+        let rrp = (r * rho2 + mx) * a * th.sin() * th.sin() / rho4;
         let rtt = -r * delta / rho2;
         let rpp =
-            delta * th.sin() * th.sin() * (m * a * a * rho2x * th.sin() * th.sin() - r * rho4)
-                / rho6;
+            delta * th.sin() * th.sin() * (mx * a * a * th.sin() * th.sin() - r * rho4) / rho6;
 
-        let tuu = -2.0 * m * r * a * a * th.sin() * th.cos() / rho6;
-        let tup = 2.0 * m * r * a * (r * r + a * a) * th.sin() * th.cos() / rho6;
+        let tuu = -mr2 * a * a * th.sin() * th.cos() / rho6;
+        let tup = mr2 * a * (r * r + a * a) * th.sin() * th.cos() / rho6;
         let trt = r / rho2;
         let trp = a * th.sin() * th.cos() / rho2;
         let ttt = -a * a * th.sin() * th.cos() / rho2;
         let tpp = -th.sin()
             * th.cos()
-            * (rho4 * (r * r + a * a)
-                + 2.0 * m * r * a * a * th.sin() * th.sin() * (r * r + a * a + rho2))
+            * (rho4 * (r * r + a * a) + mr2 * a * a * th.sin() * th.sin() * (r * r + a * a + rho2))
             / rho6;
 
-        let puu = m * a * rho2x / rho6;
-        let put = -2.0 * m * r * a * th.cos() / (rho4 * th.sin());
-        let pup = -m * a * a * rho2x * th.sin() * th.sin() / rho6;
+        let puu = mx * a / rho6;
+        let put = -mr2 * a * th.cos() / (rho4 * th.sin());
+        let pup = -mx * a * a * th.sin() * th.sin() / rho6;
         let prt = -a / rho2 * th.cos() / th.sin();
         let prp = r / rho2;
         let ptt = -a * r / rho2;
-        let ptp = th.cos() / th.sin() * (1.0 + 2.0 * m * r * a * a * th.sin() * th.sin() / rho4);
-        let ppp =
-            a * th.sin() * th.sin() * (m * a * a * rho2x * th.sin() * th.sin() - r * rho4) / rho6;
+        let ptp = th.cos() / th.sin() * (1.0 + mr2 * a * a * th.sin() * th.sin() / rho4);
+        let ppp = a * th.sin() * th.sin() * (mx * a * a * th.sin() * th.sin() - r * rho4) / rho6;
 
         Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
-            x.clone(),
+            *x,
             arr![f64;
                 uuu, 0.0, uut, uup,
                 0.0, 0.0, urt, urp,
@@ -132,13 +136,221 @@ impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
             ],
         )
     }
+}
+
+impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
 
     // TODO
     //fn dg(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
     //fn covariant_christoffel(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
 }
 
-impl<P: Properties + 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelstein<P> {
+impl<P: CarterConstant> Symmetries for EddingtonFinkelstein<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn to_boyer_lindquist_point_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Point<BoyerLindquist<P>> {
+        let (dt, dphi) = radial_integrals(m, a, q, p[1]);
+        Point::new(arr![f64; p[0] - dt, p[1], p[2], p[3] - dphi])
+    }
+
+    pub(crate) fn to_boyer_lindquist_jacobian_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<BoyerLindquist<P>> {
+        let r = p[1];
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let dtdr = -(r * r + a * a) / delta;
+        let dphidr = -a / delta;
+        Matrix::new(
+            Self::to_boyer_lindquist_point_with(m, a, q, p),
+            arr![f64;
+                1.0, dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+    pub(crate) fn to_doran_point_with(m: f64, a: f64, q: f64, p: &Point<Self>) -> Point<Doran<P>> {
+        let (dt, dphi) = free_fall_integrals(m, a, q, p[1]);
+        Point::new(arr![f64; p[0] - dt, p[1], p[2], p[3] - dphi])
+    }
+
+    pub(crate) fn to_doran_jacobian_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Doran<P>> {
+        let (dudr, dphidr) = free_fall_derivatives(m, a, q, p[1]);
+        Matrix::new(
+            Self::to_doran_point_with(m, a, q, p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+    pub(crate) fn to_kerr_schild_point_with(a: f64, p: &Point<Self>) -> Point<KerrSchild<P>> {
+        let (r, th, ph) = (p[1], p[2], p[3]);
+        let x = (r * ph.cos() - a * ph.sin()) * th.sin();
+        let y = (r * ph.sin() + a * ph.cos()) * th.sin();
+        Point::new(arr![f64; p[0] - r, x, y, r * th.cos()])
+    }
+
+    pub(crate) fn to_kerr_schild_jacobian_with(a: f64, p: &Point<Self>) -> Matrix<KerrSchild<P>> {
+        let (r, th, ph) = (p[1], p[2], p[3]);
+        let (sin_th, cos_th) = th.sin_cos();
+        let (sin_ph, cos_ph) = ph.sin_cos();
+        let xs = r * cos_ph - a * sin_ph;
+        let ys = r * sin_ph + a * cos_ph;
+        Matrix::new(
+            Self::to_kerr_schild_point_with(a, p),
+            arr![f64;
+                1.0, -1.0, 0.0, 0.0,
+                0.0, cos_ph * sin_th, xs * cos_th, -ys * sin_th,
+                0.0, sin_ph * sin_th, ys * cos_th, xs * sin_th,
+                0.0, cos_th, -r * sin_th, 0.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<BoyerLindquist<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_point_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_jacobian_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let m = P::mass();
+        let a = P::ang_momentum();
+        let q = P::charge();
+        let delta = r * r - 2.0 * m * r + a * a + q * q;
+        let dtdr = -(r * r + a * a) / delta;
+        let dphidr = -a / delta;
+        Tensor::<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<Doran<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Doran<P>> {
+        Self::to_doran_point_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Doran<P>> {
+        Self::to_doran_jacobian_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Doran<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = free_fall_derivatives(P::mass(), P::ang_momentum(), P::charge(), p[1]);
+        Tensor::<Doran<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<KerrSchild<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<KerrSchild<P>> {
+        Self::to_kerr_schild_point_with(P::ang_momentum(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<KerrSchild<P>> {
+        Self::to_kerr_schild_jacobian_with(P::ang_momentum(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<KerrSchild<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        Tensor::<KerrSchild<P>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            x,
+            KerrSchild::to_ef_jacobian_with(P::ang_momentum(), &x).coords_array(),
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelstein<P> {
     fn convert_point(p: &Point<Self>) -> Point<NearPole0EF<P>> {
         let th = p[2];
         let ph = p[3];
@@ -150,14 +362,14 @@ impl<P: Properties + 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelst
     fn jacobian(p: &Point<Self>) -> Matrix<NearPole0EF<P>> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -167,20 +379,20 @@ impl<P: Properties + 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelst
     ) -> Tensor<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
 }
 
-impl<P: Properties + 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkelstein<P> {
+impl<P: 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkelstein<P> {
     fn convert_point(p: &Point<Self>) -> Point<NearPolePiEF<P>> {
         let th = p[2];
         let ph = p[3];
@@ -190,16 +402,16 @@ impl<P: Properties + 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkels
     }
 
     fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiEF<P>> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -207,16 +419,16 @@ impl<P: Properties + 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkels
     fn inv_jacobian(
         p: &Point<Self>,
     ) -> Tensor<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
@@ -0,0 +1,275 @@
+use super::{CarterConstant, EddingtonFinkelstein, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Cartesian Kerr-Schild coordinates (t, x, y, z), with t = u - r,
+/// x + iy = (r + ia) sin(theta) exp(i phi) and z = r cos(theta) in terms of the
+/// Eddington-Finkelstein coordinates. The metric is regular on the axis and across the horizons;
+/// the chart covers r > 0, bounded by the disk spanned by the ring singularity.
+pub struct KerrSchild<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for KerrSchild<P> {
+    type Dimension = U4;
+}
+
+/// The Boyer-Lindquist radius of a point with Cartesian Kerr-Schild coordinates x, y, z: the
+/// positive root of r^4 - (x^2 + y^2 + z^2 - a^2) r^2 - a^2 z^2 = 0
+pub(crate) fn radius(a: f64, x: f64, y: f64, z: f64) -> f64 {
+    let b = x * x + y * y + z * z - a * a;
+    let d = (b * b + 4.0 * a * a * z * z).sqrt();
+    // avoids the cancellation inside the ellipsoid r = a
+    let r2 = if b >= 0.0 {
+        0.5 * (b + d)
+    } else {
+        2.0 * a * a * z * z / (d - b)
+    };
+    r2.sqrt()
+}
+
+// the gradient of the radius with respect to (t, x, y, z)
+fn radius_gradient<P>(a: f64, r: f64, x: &Point<KerrSchild<P>>) -> [f64; 4] {
+    let e = r * r * r * r + a * a * x[3] * x[3];
+    [
+        0.0,
+        r * r * r * x[1] / e,
+        r * r * r * x[2] / e,
+        r * x[3] * (r * r + a * a) / e,
+    ]
+}
+
+// the scalar f and the null covector l of the metric g = eta - f l l
+fn kerr_schild_form<P>(m: f64, a: f64, q: f64, x: &Point<KerrSchild<P>>) -> (f64, [f64; 4]) {
+    let r = radius(a, x[1], x[2], x[3]);
+    let r2a2 = r * r + a * a;
+    let f = (2.0 * m * r - q * q) * r * r / (r * r * r * r + a * a * x[3] * x[3]);
+    let l = [
+        1.0,
+        (r * x[1] + a * x[2]) / r2a2,
+        (r * x[2] - a * x[1]) / r2a2,
+        x[3] / r,
+    ];
+    (f, l)
+}
+
+impl<P> KerrSchild<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let (f, l) = kerr_schild_form(m, a, q, x);
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - f*l[0]*l[0], -f*l[0]*l[1], -f*l[0]*l[2], -f*l[0]*l[3],
+                -f*l[1]*l[0], -1.0 - f*l[1]*l[1], -f*l[1]*l[2], -f*l[1]*l[3],
+                -f*l[2]*l[0], -f*l[2]*l[1], -1.0 - f*l[2]*l[2], -f*l[2]*l[3],
+                -f*l[3]*l[0], -f*l[3]*l[1], -f*l[3]*l[2], -1.0 - f*l[3]*l[3]
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let (f, l) = kerr_schild_form(m, a, q, x);
+        // l is null, so the inverse is eta + f l^ l^ with the index raised by eta
+        let u = [l[0], -l[1], -l[2], -l[3]];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0 + f*u[0]*u[0], f*u[0]*u[1], f*u[0]*u[2], f*u[0]*u[3],
+                f*u[1]*u[0], -1.0 + f*u[1]*u[1], f*u[1]*u[2], f*u[1]*u[3],
+                f*u[2]*u[0], f*u[2]*u[1], -1.0 + f*u[2]*u[2], f*u[2]*u[3],
+                f*u[3]*u[0], f*u[3]*u[1], f*u[3]*u[2], -1.0 + f*u[3]*u[3]
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (f, l) = kerr_schild_form(m, a, q, x);
+        let r = radius(a, x[1], x[2], x[3]);
+        let dr = radius_gradient(a, r, x);
+        let r2a2 = r * r + a * a;
+        let e = r * r * r * r + a * a * x[3] * x[3];
+        let mu = 2.0 * m * r - q * q;
+
+        // dl[i][k] = dl_i/dx^k, df[k] = df/dx^k
+        let mut dl = [[0.0; 4]; 4];
+        let mut df = [0.0; 4];
+        for k in 1..4 {
+            let delta = |i: usize| if i == k { 1.0 } else { 0.0 };
+            dl[1][k] = (dr[k] * x[1] + r * delta(1) + a * delta(2) - 2.0 * r * dr[k] * l[1]) / r2a2;
+            dl[2][k] = (dr[k] * x[2] + r * delta(2) - a * delta(1) - 2.0 * r * dr[k] * l[2]) / r2a2;
+            dl[3][k] = (delta(3) - l[3] * dr[k]) / r;
+            df[k] = ((2.0 * m * r + 2.0 * mu) * r * dr[k]
+                - f * (4.0 * r * r * r * dr[k] + 2.0 * a * a * x[3] * delta(3)))
+                / e;
+        }
+
+        // dg[i][j][k] = dg_ij/dx^k
+        let mut dg = [[[0.0; 4]; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                for k in 1..4 {
+                    dg[i][j][k] =
+                        -(df[k] * l[i] * l[j] + f * dl[i][k] * l[j] + f * l[i] * dl[j][k]);
+                }
+            }
+        }
+
+        christoffel_from_dg(x, &Self::inv_g_with(m, a, q, x), &dg)
+    }
+}
+
+impl<P: Properties> MetricSystem for KerrSchild<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), x)
+    }
+}
+
+// The radius and the angles are the Cartesian ones, which only match the Boyer-Lindquist ones far
+// from the black hole or when it doesn't rotate; the Carter constant is calculated in the
+// Eddington-Finkelstein coordinates, which need the angular momentum.
+impl<P: CarterConstant> Symmetries for KerrSchild<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, -x[2], x[1], 0.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[3] / Self::radius(x)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        (x[1] * x[1] + x[2] * x[2] + x[3] * x[3]).sqrt()
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let r = Self::radius(x);
+        let p2 = p[1] * p[1] + p[2] * p[2] + p[3] * p[3];
+        let xp = x[1] * p[1] + x[2] * p[2] + x[3] * p[3];
+        r * r * p2 - xp * xp
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        let r = Self::radius(x);
+        Covector::new(*x, arr![f64; 0.0, x[1] / r, x[2] / r, x[3] / r])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let r = Self::radius(x);
+        let (sin_th, cos_th) = ((x[1] * x[1] + x[2] * x[2]).sqrt() / r, x[3] / r);
+        let ph = x[2].atan2(x[1]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, cos_th * ph.cos() / r, cos_th * ph.sin() / r, -sin_th / r],
+            ),
+            Covector::new(*x, arr![f64; 0.0, -ph.sin() / r, ph.cos() / r, 0.0]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        Self::carter_constant_with(P::spin(), p, conserved)
+    }
+}
+
+impl<P: CarterConstant> KerrSchild<P> {
+    // the momentum is pulled back to the Eddington-Finkelstein coordinates, where the Carter
+    // constant takes its usual form
+    pub(crate) fn carter_constant_with(
+        a: f64,
+        p: &Covector<Self>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        let x = Self::to_ef_point_with(a, p.get_point());
+        let jacobian = EddingtonFinkelstein::to_kerr_schild_jacobian_with(a, &x);
+        let mut p_ef = Covector::<EddingtonFinkelstein<P>>::zero(x);
+        for j in 0..4 {
+            for i in 0..4 {
+                p_ef[j] += p[i] * jacobian[i * 4 + j];
+            }
+        }
+        super::carter_constant_with(a, &p_ef, conserved)
+    }
+}
+
+// Conversions
+
+impl<P> KerrSchild<P> {
+    pub(crate) fn to_ef_point_with(a: f64, p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        let r = radius(a, p[1], p[2], p[3]);
+        let th = (p[3] / r).acos();
+        let ph = p[2].atan2(p[1]) - a.atan2(r);
+        Point::new(arr![f64; p[0] + r, r, th, ph])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(a: f64, p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        let r = radius(a, p[1], p[2], p[3]);
+        let dr = radius_gradient(a, r, p);
+        let sin_th = (1.0 - p[3] * p[3] / (r * r)).sqrt();
+        // d(z/r)
+        let dc = [
+            0.0,
+            -p[3] * dr[1] / (r * r),
+            -p[3] * dr[2] / (r * r),
+            1.0 / r - p[3] * dr[3] / (r * r),
+        ];
+        let rho2 = p[1] * p[1] + p[2] * p[2];
+        let ar = a / (r * r + a * a);
+        Matrix::new(
+            Self::to_ef_point_with(a, p),
+            arr![f64;
+                1.0, dr[1], dr[2], dr[3],
+                0.0, dr[1], dr[2], dr[3],
+                0.0, -dc[1] / sin_th, -dc[2] / sin_th, -dc[3] / sin_th,
+                0.0, -p[2] / rho2 + ar * dr[1], p[1] / rho2 + ar * dr[2], ar * dr[3],
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for KerrSchild<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::ang_momentum(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::ang_momentum(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            x,
+            EddingtonFinkelstein::to_kerr_schild_jacobian_with(P::ang_momentum(), &x)
+                .coords_array(),
+        )
+    }
+}
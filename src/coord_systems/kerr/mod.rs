@@ -1,10 +1,66 @@
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::coord_systems::Runtime;
+use diffgeom::tensors::Covector;
+
+mod atlas;
+mod boyer_lindquist;
+mod doran;
 mod eddington;
+mod kerr_schild;
 mod polar_eddington;
+mod runtime;
 
+/// The parameters of a Kerr-Newman black hole; the charge defaults to zero, which gives the Kerr
+/// metric
 pub trait Properties {
     fn mass() -> f64;
     fn ang_momentum() -> f64;
+
+    fn charge() -> f64 {
+        0.0
+    }
+}
+
+/// Provides the Carter constant to the charts, which depends on the angular momentum: the charts
+/// parametrized with `Properties` know it statically, while the ones parametrized with `Runtime`
+/// don't and give NaN; their Carter constant comes from the `BlackHole` instead.
+pub trait CarterConstant {
+    /// The angular momentum per unit mass a, or NaN if it is only known at runtime
+    fn spin() -> f64;
+
+    fn carter_constant<C: Symmetries>(p: &Covector<C>, conserved: &ConservedQuantities) -> f64 {
+        carter_constant_with(Self::spin(), p, conserved)
+    }
+}
+
+impl<P: Properties> CarterConstant for P {
+    fn spin() -> f64 {
+        P::ang_momentum()
+    }
+}
+
+impl CarterConstant for Runtime {
+    fn spin() -> f64 {
+        f64::NAN
+    }
+}
+
+// Q = p_theta^2 + cos^2(theta) (a^2 (g(u, u) - E^2) + L_z^2 / sin^2(theta)), which doesn't depend
+// on the charge
+pub(crate) fn carter_constant_with<C: Symmetries>(
+    a: f64,
+    p: &Covector<C>,
+    conserved: &ConservedQuantities,
+) -> f64 {
+    let cos_th = C::cos_theta(p.get_point());
+    let (energy, l) = (conserved.energy, conserved.ang_momentum);
+    C::sphere_norm_sq(p) - l * l + a * a * cos_th * cos_th * (conserved.norm - energy * energy)
 }
 
+pub use self::atlas::EFAtlas;
+pub use self::boyer_lindquist::BoyerLindquist;
+pub use self::doran::Doran;
 pub use self::eddington::EddingtonFinkelstein;
+pub use self::kerr_schild::KerrSchild;
 pub use self::polar_eddington::{NearPole0EF, NearPolePiEF};
+pub use self::runtime::BlackHole;
@@ -1,44 +1,100 @@
-use super::{EddingtonFinkelstein, Properties};
+use super::{CarterConstant, EddingtonFinkelstein, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-/// The coordinate system near the pole theta=pi
-pub struct NearPole0EF<P: Properties> {
+// The derivatives of the metric in the polar charts, dg[i][j][k] = dg_ij/dx^k. Both polar charts
+// share the same metric:
+// g = (1 - f) du^2 - 2 du dr + 2 a f du w + 2 a dr w - rho^2 alpha^2 (dx^2 + dy^2) - a^2 (1 + f) w^2,
+// where f = (2mr - q^2)/rho^2 and w = sin^2(theta) dphi = alpha^2 (x dy - y dx).
+fn polar_dg(m: f64, a: f64, q: f64, r: f64, x: f64, y: f64) -> [[[f64; 4]; 4]; 4] {
+    let xy1 = 1.0 + x * x + y * y;
+    let cos_th = (1.0 - x * x - y * y) / xy1;
+    let alpha2 = 4.0 / xy1 / xy1;
+    let rho2 = r * r + a * a * cos_th * cos_th;
+    let f = (2.0 * m * r - q * q) / rho2;
+
+    // the components of w and the derivatives of all the functions above
+    let w = [0.0, 0.0, -alpha2 * y, alpha2 * x];
+    let d_alpha2 = [0.0, 0.0, -4.0 * x * alpha2 / xy1, -4.0 * y * alpha2 / xy1];
+    let d_rho2 = [
+        0.0,
+        2.0 * r,
+        -2.0 * a * a * cos_th * x * alpha2,
+        -2.0 * a * a * cos_th * y * alpha2,
+    ];
+    let d_f = [
+        0.0,
+        (2.0 * m - f * d_rho2[1]) / rho2,
+        -f * d_rho2[2] / rho2,
+        -f * d_rho2[3] / rho2,
+    ];
+    let d_w = [
+        [0.0; 4],
+        [0.0; 4],
+        [0.0, 0.0, -y * d_alpha2[2], -alpha2 - y * d_alpha2[3]],
+        [0.0, 0.0, alpha2 + x * d_alpha2[2], x * d_alpha2[3]],
+    ];
+
+    let mut dg = [[[0.0; 4]; 4]; 4];
+    for k in 0..4 {
+        dg[0][0][k] = -d_f[k];
+        for i in 2..4 {
+            dg[0][i][k] = a * (d_f[k] * w[i] + f * d_w[i][k]);
+            dg[i][0][k] = dg[0][i][k];
+            dg[1][i][k] = a * d_w[i][k];
+            dg[i][1][k] = dg[1][i][k];
+            for j in 2..4 {
+                dg[i][j][k] = -a
+                    * a
+                    * (d_f[k] * w[i] * w[j] + (1.0 + f) * (d_w[i][k] * w[j] + w[i] * d_w[j][k]));
+            }
+            dg[i][i][k] -= d_rho2[k] * alpha2 + rho2 * d_alpha2[k];
+        }
+    }
+    dg
+}
+
+/// The coordinate system near the pole theta=0
+pub struct NearPole0EF<P> {
     _m: PhantomData<P>,
 }
 
-impl<P: Properties> CoordinateSystem for NearPole0EF<P> {
+impl<P> CoordinateSystem for NearPole0EF<P> {
     type Dimension = U4;
 }
 
-impl<P: Properties> MetricSystem for NearPole0EF<P> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<P> NearPole0EF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = P::mass();
-        let a = P::ang_momentum();
 
         let xy1 = 1.0 + x * x + y * y;
         let negxy1 = 1.0 - x * x - y * y;
         let rho2 = r * r + a * a * negxy1 * negxy1 / xy1 / xy1;
         let alpha2 = 4.0 / xy1 / xy1;
 
-        let guu = 1.0 - 2.0 * m * r / rho2;
-        let gxx = -alpha2 * (r * r + a * a - alpha2 * a * a * (x * x - 2.0 * m * r * y * y / rho2));
-        let gyy = -alpha2 * (r * r + a * a - alpha2 * a * a * (y * y - 2.0 * m * r * x * x / rho2));
-        let gux = -2.0 * m * r * a * y * alpha2 / rho2;
-        let guy = 2.0 * m * r * a * x * alpha2 / rho2;
+        let guu = 1.0 - (2.0 * m * r - q * q) / rho2;
+        let gxx = -alpha2
+            * (r * r + a * a - alpha2 * a * a * (x * x - (2.0 * m * r - q * q) * y * y / rho2));
+        let gyy = -alpha2
+            * (r * r + a * a - alpha2 * a * a * (y * y - (2.0 * m * r - q * q) * x * x / rho2));
+        let gux = -(2.0 * m * r - q * q) * a * y * alpha2 / rho2;
+        let guy = (2.0 * m * r - q * q) * a * x * alpha2 / rho2;
         let grx = -alpha2 * a * y;
         let gry = alpha2 * a * x;
-        let gxy = x * y * a * a * alpha2 * alpha2 * (1.0 + 2.0 * m * r / rho2);
+        let gxy = x * y * a * a * alpha2 * alpha2 * (1.0 + (2.0 * m * r - q * q) / rho2);
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 guu, -1.0, gux, guy,
                 -1.0, 0.0, grx, gry,
@@ -48,12 +104,10 @@ impl<P: Properties> MetricSystem for NearPole0EF<P> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = P::mass();
-        let a = P::ang_momentum();
 
         let xy1 = 1.0 + x * x + y * y;
         let negxy1 = 1.0 - x * x - y * y;
@@ -61,7 +115,7 @@ impl<P: Properties> MetricSystem for NearPole0EF<P> {
         let alpha2 = 4.0 / xy1 / xy1;
 
         let guu = -alpha2 * a * a * (x * x + y * y) / rho2;
-        let grr = -(r * r + a * a - 2.0 * m * r) / rho2;
+        let grr = -(r * r + a * a + q * q - 2.0 * m * r) / rho2;
         let gxx = -1.0 / alpha2 / rho2;
         let gyy = gxx;
         let gur = -(r * r + a * a) / rho2;
@@ -70,7 +124,7 @@ impl<P: Properties> MetricSystem for NearPole0EF<P> {
         let grx = a * y / rho2;
         let gry = -a * x / rho2;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 guu, gur, gux, guy,
                 gur, grr, grx, gry,
@@ -79,11 +133,79 @@ impl<P: Properties> MetricSystem for NearPole0EF<P> {
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let dg = polar_dg(m, a, q, p[1], p[2], p[3]);
+        christoffel_from_dg(p, &Self::inv_g_with(m, a, q, p), &dg)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPole0EF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPole0EF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
 }
 
 // Conversions
 
-impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole0EF<P> {
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole0EF<P> {
     fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
         let x = p[2];
         let y = p[3];
@@ -95,14 +217,14 @@ impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole
     fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, -y,
-                0.0, 0.0, y * coeff, x,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -112,51 +234,51 @@ impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole
     ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
             ],
         )
     }
 }
 
 /// The coordinate system near the pole theta=pi
-pub struct NearPolePiEF<P: Properties> {
+pub struct NearPolePiEF<P> {
     _m: PhantomData<P>,
 }
 
-impl<P: Properties> CoordinateSystem for NearPolePiEF<P> {
+impl<P> CoordinateSystem for NearPolePiEF<P> {
     type Dimension = U4;
 }
 
-impl<P: Properties> MetricSystem for NearPolePiEF<P> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<P> NearPolePiEF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, q: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = P::mass();
-        let a = P::ang_momentum();
 
         let xy1 = 1.0 + x * x + y * y;
         let negxy1 = 1.0 - x * x - y * y;
         let rho2 = r * r + a * a * negxy1 * negxy1 / xy1 / xy1;
         let alpha2 = 4.0 / xy1 / xy1;
 
-        let guu = 1.0 - 2.0 * m * r / rho2;
-        let gxx = -alpha2 * (r * r + a * a - alpha2 * a * a * (x * x - 2.0 * m * r * y * y / rho2));
-        let gyy = -alpha2 * (r * r + a * a - alpha2 * a * a * (y * y - 2.0 * m * r * x * x / rho2));
-        let gux = -2.0 * m * r * a * y * alpha2 / rho2;
-        let guy = 2.0 * m * r * a * x * alpha2 / rho2;
+        let guu = 1.0 - (2.0 * m * r - q * q) / rho2;
+        let gxx = -alpha2
+            * (r * r + a * a - alpha2 * a * a * (x * x - (2.0 * m * r - q * q) * y * y / rho2));
+        let gyy = -alpha2
+            * (r * r + a * a - alpha2 * a * a * (y * y - (2.0 * m * r - q * q) * x * x / rho2));
+        let gux = -(2.0 * m * r - q * q) * a * y * alpha2 / rho2;
+        let guy = (2.0 * m * r - q * q) * a * x * alpha2 / rho2;
         let grx = -alpha2 * a * y;
         let gry = alpha2 * a * x;
-        let gxy = x * y * a * a * alpha2 * alpha2 * (1.0 + 2.0 * m * r / rho2);
+        let gxy = x * y * a * a * alpha2 * alpha2 * (1.0 + (2.0 * m * r - q * q) / rho2);
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 guu, -1.0, gux, guy,
                 -1.0, 0.0, grx, gry,
@@ -166,12 +288,10 @@ impl<P: Properties> MetricSystem for NearPolePiEF<P> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, a: f64, q: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = P::mass();
-        let a = P::ang_momentum();
 
         let xy1 = 1.0 + x * x + y * y;
         let negxy1 = 1.0 - x * x - y * y;
@@ -179,7 +299,7 @@ impl<P: Properties> MetricSystem for NearPolePiEF<P> {
         let alpha2 = 4.0 / xy1 / xy1;
 
         let guu = -alpha2 * a * a * (x * x + y * y) / rho2;
-        let grr = -(r * r + a * a - 2.0 * m * r) / rho2;
+        let grr = -(r * r + a * a + q * q - 2.0 * m * r) / rho2;
         let gxx = -1.0 / alpha2 / rho2;
         let gyy = gxx;
         let gur = -(r * r + a * a) / rho2;
@@ -188,7 +308,7 @@ impl<P: Properties> MetricSystem for NearPolePiEF<P> {
         let grx = a * y / rho2;
         let gry = -a * x / rho2;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 guu, gur, gux, guy,
                 gur, grr, grx, gry,
@@ -197,11 +317,82 @@ impl<P: Properties> MetricSystem for NearPolePiEF<P> {
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let dg = polar_dg(m, a, q, p[1], p[2], p[3]);
+        christoffel_from_dg(p, &Self::inv_g_with(m, a, q, p), &dg)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPolePiEF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::charge(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPolePiEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
 }
 
 // Conversions
 
-impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPolePiEF<P> {
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPolePiEF<P> {
     fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
         let x = p[2];
         let y = p[3];
@@ -213,14 +404,14 @@ impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole
     fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y,
-                0.0, 0.0, -y * coeff, x,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -230,14 +421,14 @@ impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole
     ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
             ],
         )
     }
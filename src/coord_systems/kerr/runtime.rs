@@ -0,0 +1,305 @@
+use super::{
+    carter_constant_with, BoyerLindquist, Doran, EddingtonFinkelstein, KerrSchild, NearPole0EF,
+    NearPolePiEF,
+};
+use crate::conserved::ConservedQuantities;
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm,
+};
+
+/// A Kerr-Newman black hole with the mass, angular momentum and charge only known at runtime. It
+/// provides the metric of the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackHole {
+    pub mass: f64,
+    pub ang_momentum: f64,
+    pub charge: f64,
+}
+
+impl BlackHole {
+    /// Creates an uncharged (Kerr) black hole
+    pub fn new(mass: f64, ang_momentum: f64) -> Self {
+        BlackHole {
+            mass,
+            ang_momentum,
+            charge: 0.0,
+        }
+    }
+
+    pub fn with_charge(self, charge: f64) -> Self {
+        BlackHole { charge, ..self }
+    }
+
+    /// The radius of the outer horizon
+    pub fn horizon_radius(&self) -> f64 {
+        let (m, a, q) = (self.mass, self.ang_momentum, self.charge);
+        m + (m * m - a * a - q * q).sqrt()
+    }
+}
+
+impl Spacetime<EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> TwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> InvTwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::inv_g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Tensor<EddingtonFinkelstein<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        EddingtonFinkelstein::christoffel_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<EddingtonFinkelstein<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+impl Spacetime<BoyerLindquist<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<BoyerLindquist<Runtime>>) -> TwoForm<BoyerLindquist<Runtime>> {
+        BoyerLindquist::g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn inv_g(&self, x: &Point<BoyerLindquist<Runtime>>) -> InvTwoForm<BoyerLindquist<Runtime>> {
+        BoyerLindquist::inv_g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<BoyerLindquist<Runtime>>,
+    ) -> Tensor<BoyerLindquist<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        BoyerLindquist::christoffel_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<BoyerLindquist<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+impl Spacetime<Doran<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<Doran<Runtime>>) -> TwoForm<Doran<Runtime>> {
+        Doran::g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn inv_g(&self, x: &Point<Doran<Runtime>>) -> InvTwoForm<Doran<Runtime>> {
+        Doran::inv_g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Doran<Runtime>>,
+    ) -> Tensor<Doran<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Doran::christoffel_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<Doran<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+impl Spacetime<KerrSchild<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<KerrSchild<Runtime>>) -> TwoForm<KerrSchild<Runtime>> {
+        KerrSchild::g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn inv_g(&self, x: &Point<KerrSchild<Runtime>>) -> InvTwoForm<KerrSchild<Runtime>> {
+        KerrSchild::inv_g_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<KerrSchild<Runtime>>,
+    ) -> Tensor<KerrSchild<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        KerrSchild::christoffel_with(self.mass, self.ang_momentum, self.charge, x)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<KerrSchild<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        KerrSchild::carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+impl Spacetime<NearPole0EF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0EF<Runtime>>) -> TwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::g_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0EF<Runtime>>) -> InvTwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::inv_g_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0EF<Runtime>>,
+    ) -> Tensor<NearPole0EF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0EF::christoffel_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPole0EF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+impl Spacetime<NearPolePiEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiEF<Runtime>>) -> TwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::g_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiEF<Runtime>>) -> InvTwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::inv_g_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiEF<Runtime>>,
+    ) -> Tensor<NearPolePiEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiEF::christoffel_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPolePiEF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, p, conserved)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<BoyerLindquist<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<BoyerLindquist<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        BoyerLindquist::to_ef_point_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<BoyerLindquist<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        BoyerLindquist::to_ef_jacobian_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, BoyerLindquist<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<BoyerLindquist<Runtime>> {
+        EddingtonFinkelstein::to_boyer_lindquist_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.charge,
+            p,
+        )
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<BoyerLindquist<Runtime>> {
+        EddingtonFinkelstein::to_boyer_lindquist_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.charge,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<Doran<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Doran<Runtime>>) -> Point<EddingtonFinkelstein<Runtime>> {
+        Doran::to_ef_point_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn jacobian(&self, p: &Point<Doran<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        Doran::to_ef_jacobian_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, Doran<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Point<Doran<Runtime>> {
+        EddingtonFinkelstein::to_doran_point_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<Doran<Runtime>> {
+        EddingtonFinkelstein::to_doran_jacobian_with(self.mass, self.ang_momentum, self.charge, p)
+    }
+}
+
+impl SpacetimeConversion<KerrSchild<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<KerrSchild<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        KerrSchild::to_ef_point_with(self.ang_momentum, p)
+    }
+
+    fn jacobian(&self, p: &Point<KerrSchild<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        KerrSchild::to_ef_jacobian_with(self.ang_momentum, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, KerrSchild<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<KerrSchild<Runtime>> {
+        EddingtonFinkelstein::to_kerr_schild_point_with(self.ang_momentum, p)
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<KerrSchild<Runtime>> {
+        EddingtonFinkelstein::to_kerr_schild_jacobian_with(self.ang_momentum, p)
+    }
+}
+
+// the conversions to and from the polar charts don't depend on the parameters
+impl<C1, C2> SpacetimeConversion<C1, C2> for BlackHole
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
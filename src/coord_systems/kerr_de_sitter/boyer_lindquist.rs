@@ -0,0 +1,434 @@
+use super::{CarterConstant, EddingtonFinkelstein, OutgoingEF, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+// The functions of r and c = cos(theta) that the metric is built from. With
+// Delta_r = (r^2 + a^2)(1 - l r^2/3) - 2mr, Delta_theta = 1 + l a^2 c^2/3, Xi = 1 + l a^2/3,
+// A = Delta_r/(rho^2 Xi^2) and B = Delta_theta/(rho^2 Xi^2), the metric in the Boyer-Lindquist
+// coordinates is
+// g = A (dt - a s^2 dphi)^2 - rho^2/Delta_r dr^2 - rho^2/Delta_theta dtheta^2
+//     - B s^2 (a dt - (r^2 + a^2) dphi)^2.
+// The arrays hold the value and the derivatives with respect to r and c (Delta_r doesn't
+// depend on c).
+pub(crate) struct MetricFunctions {
+    pub xi: f64,
+    pub rho2: f64,
+    pub delta_theta: f64,
+    pub delta_r: [f64; 2],
+    // g_tt
+    pub tt: [f64; 3],
+    // g_tphi / sin^2(theta)
+    pub tw: [f64; 3],
+    // (g_phiphi / sin^2(theta) - g_thth) / sin^2(theta), which is regular at the poles
+    pub ww: [f64; 3],
+    // -g_thth
+    pub h: [f64; 3],
+}
+
+impl MetricFunctions {
+    pub(crate) fn new(m: f64, a: f64, l: f64, r: f64, c: f64) -> Self {
+        let l3 = l / 3.0;
+        let s2 = 1.0 - c * c;
+        let big_r = r * r + a * a;
+        let xi = 1.0 + l3 * a * a;
+        let xi2 = xi * xi;
+        let rho2 = r * r + a * a * c * c;
+        let d_rho2 = [2.0 * r, 2.0 * a * a * c];
+        let delta_theta = 1.0 + l3 * a * a * c * c;
+        let d_delta_theta = 2.0 * l3 * a * a * c;
+        let delta_r = big_r * (1.0 - l3 * r * r) - 2.0 * m * r;
+        let d_delta_r = 2.0 * r * (1.0 - l3 * r * r) - 2.0 * l3 * r * big_r - 2.0 * m;
+
+        let fa = delta_r / (rho2 * xi2);
+        let fa_r = (d_delta_r - fa * xi2 * d_rho2[0]) / (rho2 * xi2);
+        let fa_c = -fa * d_rho2[1] / rho2;
+        let fb = delta_theta / (rho2 * xi2);
+        let fb_r = -fb * d_rho2[0] / rho2;
+        let fb_c = (d_delta_theta - fb * xi2 * d_rho2[1]) / (rho2 * xi2);
+        let h = rho2 / delta_theta;
+        let h_r = d_rho2[0] / delta_theta;
+        let h_c = (d_rho2[1] - h * d_delta_theta) / delta_theta;
+        // ww = a^2 (A - K) with K = (1 - l r^2/3) (1/(Delta_theta Xi) + (r^2 + a^2)/(rho^2 Xi^2))
+        let u = 1.0 / (delta_theta * xi) + big_r / (rho2 * xi2);
+        let u_r = (2.0 * r - big_r * d_rho2[0] / rho2) / (rho2 * xi2);
+        let u_c = -d_delta_theta / (delta_theta * delta_theta * xi)
+            - big_r * d_rho2[1] / (rho2 * rho2 * xi2);
+        let k = (1.0 - l3 * r * r) * u;
+        let k_r = -2.0 * l3 * r * u + (1.0 - l3 * r * r) * u_r;
+        let k_c = (1.0 - l3 * r * r) * u_c;
+
+        MetricFunctions {
+            xi,
+            rho2,
+            delta_theta,
+            delta_r: [delta_r, d_delta_r],
+            tt: [
+                fa - a * a * fb * s2,
+                fa_r - a * a * fb_r * s2,
+                fa_c - a * a * (fb_c * s2 - 2.0 * c * fb),
+            ],
+            tw: [
+                a * (fb * big_r - fa),
+                a * (fb_r * big_r + 2.0 * r * fb - fa_r),
+                a * (fb_c * big_r - fa_c),
+            ],
+            ww: [a * a * (fa - k), a * a * (fa_r - k_r), a * a * (fa_c - k_c)],
+            h: [h, h_r, h_c],
+        }
+    }
+}
+
+// The derivatives of the metric in the charts using theta and phi, dg[i][j][k] = dg_ij/dx^k,
+// given the (t, r), (r, r) and (r, phi) components with their derivatives with respect to r
+// and c. The other components are the same in all these charts.
+pub(crate) fn spherical_dg(
+    f: &MetricFunctions,
+    tr: [f64; 3],
+    rr: [f64; 3],
+    rp: [f64; 3],
+    th: f64,
+) -> [[[f64; 4]; 4]; 4] {
+    let (s, c) = th.sin_cos();
+    let s2 = s * s;
+    let tp = [
+        s2 * f.tw[0],
+        s2 * f.tw[1],
+        -2.0 * c * f.tw[0] + s2 * f.tw[2],
+    ];
+    let pp = [
+        s2 * s2 * f.ww[0] - s2 * f.h[0],
+        s2 * s2 * f.ww[1] - s2 * f.h[1],
+        -4.0 * c * s2 * f.ww[0] + s2 * s2 * f.ww[2] + 2.0 * c * f.h[0] - s2 * f.h[2],
+    ];
+    let th_th = [-f.h[0], -f.h[1], -f.h[2]];
+    let components = [
+        (0, 0, f.tt),
+        (0, 1, tr),
+        (0, 3, tp),
+        (1, 1, rr),
+        (1, 3, rp),
+        (2, 2, th_th),
+        (3, 3, pp),
+    ];
+
+    let mut dg = [[[0.0; 4]; 4]; 4];
+    for &(i, j, comp) in &components {
+        // d/dtheta = -sin(theta) d/dc
+        dg[i][j][1] = comp[1];
+        dg[i][j][2] = -s * comp[2];
+        dg[j][i] = dg[i][j];
+    }
+    dg
+}
+
+/// The Boyer-Lindquist coordinates (t, r, theta, phi) of a rotating black hole in a universe with
+/// a positive cosmological constant. They are singular at the horizons, so they describe only
+/// one of the regions between them - e.g. the one between the black hole and cosmological
+/// horizons.
+pub struct BoyerLindquist<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for BoyerLindquist<P> {
+    type Dimension = U4;
+}
+
+impl<P> BoyerLindquist<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let f = MetricFunctions::new(m, a, l, r, th.cos());
+        let s2 = th.sin() * th.sin();
+        let gtp = s2 * f.tw[0];
+        let gpp = s2 * s2 * f.ww[0] - s2 * f.h[0];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                f.tt[0], 0.0,                  0.0,     gtp,
+                0.0,    -f.rho2/f.delta_r[0],  0.0,     0.0,
+                0.0,     0.0,                 -f.h[0],  0.0,
+                gtp,     0.0,                  0.0,     gpp
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let f = MetricFunctions::new(m, a, l, r, th.cos());
+        let s2 = th.sin() * th.sin();
+        let big_r = r * r + a * a;
+        let xi2r = f.xi * f.xi / f.rho2;
+        let delta_r = f.delta_r[0];
+        let dth = f.delta_theta;
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                xi2r*(big_r*big_r/delta_r - a*a*s2/dth), 0.0,             0.0,        xi2r*a*(big_r/delta_r - 1.0/dth),
+                0.0,                                     -delta_r/f.rho2,  0.0,        0.0,
+                0.0,                                      0.0,            -dth/f.rho2, 0.0,
+                xi2r*a*(big_r/delta_r - 1.0/dth),         0.0,             0.0,        xi2r*(a*a/delta_r - 1.0/(dth*s2))
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let f = MetricFunctions::new(m, a, l, r, th.cos());
+        let delta_r = f.delta_r[0];
+        let rr = [
+            -f.rho2 / delta_r,
+            -(2.0 * r - f.rho2 * f.delta_r[1] / delta_r) / delta_r,
+            -2.0 * a * a * th.cos() / delta_r,
+        ];
+        let dg = spherical_dg(&f, [0.0; 3], rr, [0.0; 3], th);
+        christoffel_from_dg(x, &Self::inv_g_with(m, a, l, x), &dg)
+    }
+}
+
+impl<P: Properties> MetricSystem for BoyerLindquist<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for BoyerLindquist<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+// Finds a root of Delta_r with Newton's method. Delta_r is concave outside of its outermost
+// roots, so starting beyond one of them converges monotonically to it.
+fn outer_root(m: f64, a: f64, l: f64, mut r: f64) -> f64 {
+    let l3 = l / 3.0;
+    for _ in 0..100 {
+        let big_r = r * r + a * a;
+        let delta_r = big_r * (1.0 - l3 * r * r) - 2.0 * m * r;
+        let d_delta_r = 2.0 * r * (1.0 - l3 * r * r) - 2.0 * l3 * r * big_r - 2.0 * m;
+        let step = delta_r / d_delta_r;
+        r -= step;
+        if step.abs() <= 1e-15 * r.abs() {
+            break;
+        }
+    }
+    r
+}
+
+// The four roots of Delta_r in increasing order: a negative one, the inner (Cauchy), black hole
+// and cosmological horizons. Requires a positive cosmological constant small enough for all of
+// them to exist.
+pub(crate) fn horizons(m: f64, a: f64, l: f64) -> [f64; 4] {
+    let l3 = l / 3.0;
+    let r_c = outer_root(m, a, l, 1.0 / l3.sqrt());
+    let r_n = outer_root(m, a, l, -2.0 / l3.sqrt());
+    // the remaining roots solve r^2 + (r_c + r_n) r + q = 0, since all four roots add up to
+    // zero and their product is -3a^2/l
+    let half_p = (r_c + r_n) / 2.0;
+    let q = -a * a / (l3 * r_c * r_n);
+    let sqrt_d = (half_p * half_p - q).sqrt();
+    [r_n, -half_p - sqrt_d, -half_p + sqrt_d, r_c]
+}
+
+// Returns the integrals of Xi (r^2 + a^2)/Delta_r and Xi a/Delta_r over r, which are the
+// differences between the Eddington-Finkelstein and Boyer-Lindquist t and phi coordinates,
+// respectively. 1/Delta_r decomposes into a sum of 1/(Delta_r'(r_i) (r - r_i)) over its roots.
+pub(crate) fn radial_integrals(m: f64, a: f64, l: f64, r: f64) -> (f64, f64) {
+    let l3 = l / 3.0;
+    let xi = 1.0 + l3 * a * a;
+    let roots = horizons(m, a, l);
+    let r_c = roots[3];
+    let mut dt = 0.0;
+    let mut dphi = 0.0;
+    for &r_i in &roots {
+        let d_delta_r = 2.0 * r_i * (1.0 - l3 * a * a) - 4.0 * l3 * r_i * r_i * r_i - 2.0 * m;
+        let ln = ((r - r_i) / r_c).abs().ln() / d_delta_r;
+        dt += xi * (r_i * r_i + a * a) * ln;
+        dphi += xi * a * ln;
+    }
+    (dt, dphi)
+}
+
+// The integrands of `radial_integrals`
+pub(crate) fn radial_derivatives(m: f64, a: f64, l: f64, r: f64) -> (f64, f64) {
+    let l3 = l / 3.0;
+    let xi = 1.0 + l3 * a * a;
+    let delta_r = (r * r + a * a) * (1.0 - l3 * r * r) - 2.0 * m * r;
+    (xi * (r * r + a * a) / delta_r, xi * a / delta_r)
+}
+
+impl<P> BoyerLindquist<P> {
+    pub(crate) fn to_ef_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<EddingtonFinkelstein<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] + dt, p[1], p[2], p[3] + dphi])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<EddingtonFinkelstein<P>> {
+        let (dvdr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_ef_point_with(m, a, l, p),
+            arr![f64;
+                1.0, dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_outgoing_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<OutgoingEF<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] - dt, p[1], p[2], p[3] - dphi])
+    }
+
+    pub(crate) fn to_outgoing_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<OutgoingEF<P>> {
+        let (dvdr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_outgoing_point_with(m, a, l, p),
+            arr![f64;
+                1.0, -dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for BoyerLindquist<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dvdr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<OutgoingEF<P>> for BoyerLindquist<P> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<P>> {
+        Self::to_outgoing_point_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<P>> {
+        Self::to_outgoing_jacobian_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dvdr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
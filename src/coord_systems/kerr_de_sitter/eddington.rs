@@ -0,0 +1,380 @@
+use super::boyer_lindquist::{radial_derivatives, radial_integrals, spherical_dg, MetricFunctions};
+use super::{BoyerLindquist, CarterConstant, NearPole0EF, NearPolePiEF, OutgoingEF, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+// The metric of the ingoing (eps = 1) and outgoing (eps = -1) Eddington-Finkelstein charts, with
+// dv = dt + eps Xi (r^2 + a^2)/Delta_r dr and dpsi = dphi + eps Xi a/Delta_r dr
+pub(crate) fn ef_g<C>(eps: f64, m: f64, a: f64, l: f64, x: &Point<C>) -> TwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let r = x[1];
+    let th = x[2];
+    let f = MetricFunctions::new(m, a, l, r, th.cos());
+    let s2 = th.sin() * th.sin();
+    let gur = -eps / f.xi;
+    let gup = s2 * f.tw[0];
+    let grp = eps * a * s2 / f.xi;
+    let gpp = s2 * s2 * f.ww[0] - s2 * f.h[0];
+    TwoForm::new(
+        *x,
+        arr![f64;
+            f.tt[0], gur, 0.0,     gup,
+            gur,     0.0, 0.0,     grp,
+            0.0,     0.0, -f.h[0], 0.0,
+            gup,     grp, 0.0,     gpp
+        ],
+    )
+}
+
+pub(crate) fn ef_inv_g<C>(eps: f64, m: f64, a: f64, l: f64, x: &Point<C>) -> InvTwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let r = x[1];
+    let th = x[2];
+    let f = MetricFunctions::new(m, a, l, r, th.cos());
+    let s2 = th.sin() * th.sin();
+    let rho2 = f.rho2;
+    let xi = f.xi;
+    let dth = f.delta_theta;
+    InvTwoForm::new(
+        *x,
+        arr![f64;
+            -xi*xi*a*a*s2/(rho2*dth), -eps*xi*(r*r + a*a)/rho2, 0.0,        -xi*xi*a/(rho2*dth),
+            -eps*xi*(r*r + a*a)/rho2, -f.delta_r[0]/rho2,        0.0,        -eps*xi*a/rho2,
+             0.0,                      0.0,                     -dth/rho2,   0.0,
+            -xi*xi*a/(rho2*dth),      -eps*xi*a/rho2,            0.0,        -xi*xi/(rho2*dth*s2)
+        ],
+    )
+}
+
+pub(crate) fn ef_christoffel<C>(
+    eps: f64,
+    m: f64,
+    a: f64,
+    l: f64,
+    x: &Point<C>,
+) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let th = x[2];
+    let f = MetricFunctions::new(m, a, l, x[1], th.cos());
+    let ur = [-eps / f.xi, 0.0, 0.0];
+    let rp = [
+        eps * a * th.sin() * th.sin() / f.xi,
+        0.0,
+        -2.0 * eps * a * th.cos() / f.xi,
+    ];
+    let dg = spherical_dg(&f, ur, [0.0; 3], rp, th);
+    christoffel_from_dg(x, &ef_inv_g(eps, m, a, l, x), &dg)
+}
+
+/// The ingoing Eddington-Finkelstein coordinates (u, r, theta, phi). They are regular at the
+/// future black hole horizons, both the outer and the inner one, and at the past cosmological
+/// horizon.
+pub struct EddingtonFinkelstein<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for EddingtonFinkelstein<P> {
+    type Dimension = U4;
+}
+
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> TwoForm<Self> {
+        ef_g(1.0, m, a, l, x)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        ef_inv_g(1.0, m, a, l, x)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        ef_christoffel(1.0, m, a, l, x)
+    }
+}
+
+impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for EddingtonFinkelstein<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn to_boyer_lindquist_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<BoyerLindquist<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] - dt, p[1], p[2], p[3] - dphi])
+    }
+
+    pub(crate) fn to_boyer_lindquist_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<BoyerLindquist<P>> {
+        let (dudr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_boyer_lindquist_point_with(m, a, l, p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_outgoing_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<OutgoingEF<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] - 2.0 * dt, p[1], p[2], p[3] - 2.0 * dphi])
+    }
+
+    pub(crate) fn to_outgoing_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<OutgoingEF<P>> {
+        let (dudr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_outgoing_point_with(m, a, l, p),
+            arr![f64;
+                1.0, -2.0 * dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -2.0 * dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<BoyerLindquist<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_point_with(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p,
+        )
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_jacobian_with(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p,
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<OutgoingEF<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<P>> {
+        Self::to_outgoing_point_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<P>> {
+        Self::to_outgoing_jacobian_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 2.0 * dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 2.0 * dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0EF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0EF<P>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiEF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiEF<P>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
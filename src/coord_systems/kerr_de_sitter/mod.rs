@@ -0,0 +1,73 @@
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::coord_systems::Runtime;
+use diffgeom::tensors::Covector;
+
+mod atlas;
+mod boyer_lindquist;
+mod eddington;
+mod outgoing;
+mod polar_eddington;
+mod polar_outgoing;
+mod runtime;
+
+/// The parameters of a Kerr-de Sitter black hole; the cosmological constant has to be positive
+/// and small enough for the black hole and cosmological horizons to exist
+pub trait Properties {
+    fn mass() -> f64;
+    fn ang_momentum() -> f64;
+    fn cosmological_constant() -> f64;
+}
+
+/// Provides the Carter constant to the charts, which depends on the angular momentum and the
+/// cosmological constant: the charts parametrized with `Properties` know them statically, while
+/// the ones parametrized with `Runtime` don't and give NaN; their Carter constant comes from the
+/// `BlackHole` instead.
+pub trait CarterConstant {
+    fn carter_constant<C: Symmetries>(p: &Covector<C>, conserved: &ConservedQuantities) -> f64;
+}
+
+impl<P: Properties> CarterConstant for P {
+    fn carter_constant<C: Symmetries>(p: &Covector<C>, conserved: &ConservedQuantities) -> f64 {
+        carter_constant_with(P::ang_momentum(), P::cosmological_constant(), p, conserved)
+    }
+}
+
+impl CarterConstant for Runtime {
+    fn carter_constant<C: Symmetries>(_: &Covector<C>, _: &ConservedQuantities) -> f64 {
+        f64::NAN
+    }
+}
+
+// With delta_theta = 1 + lambda/3 a^2 cos^2(theta) and xi = 1 + lambda/3 a^2, the separated
+// angular equation gives the constant
+//   K = delta_theta p_theta^2 + xi^2 (a E sin^2(theta) - L_z)^2 / (delta_theta sin^2(theta))
+//       + g(u, u) a^2 cos^2(theta),
+// of which Q = K - xi^2 (L_z - a E)^2 reduces to the Kerr Carter constant for lambda = 0. The
+// terms with L_z^2 are rearranged so that the only division by sin^2(theta) is the one in the
+// norm of p on the sphere, which the polar charts evaluate regularly.
+pub(crate) fn carter_constant_with<C: Symmetries>(
+    a: f64,
+    lambda: f64,
+    p: &Covector<C>,
+    conserved: &ConservedQuantities,
+) -> f64 {
+    let cos_th = C::cos_theta(p.get_point());
+    let (cos2, sin2) = (cos_th * cos_th, 1.0 - cos_th * cos_th);
+    let (energy, l) = (conserved.energy, conserved.ang_momentum);
+    let l3a2 = lambda / 3.0 * a * a;
+    let delta_th = 1.0 + l3a2 * cos2;
+    let xi = 1.0 + l3a2;
+    delta_th * C::sphere_norm_sq(p)
+        + l * l * l3a2 * (xi + delta_th) / delta_th
+        + xi * xi * (a * a * energy * energy * sin2 - 2.0 * a * energy * l) / delta_th
+        + conserved.norm * a * a * cos2
+        - xi * xi * (l - a * energy) * (l - a * energy)
+}
+
+pub use self::atlas::{EFAtlas, OutgoingEFAtlas};
+pub use self::boyer_lindquist::BoyerLindquist;
+pub use self::eddington::EddingtonFinkelstein;
+pub use self::outgoing::OutgoingEF;
+pub use self::polar_eddington::{NearPole0EF, NearPolePiEF};
+pub use self::polar_outgoing::{NearPole0OutgoingEF, NearPolePiOutgoingEF};
+pub use self::runtime::BlackHole;
@@ -0,0 +1,314 @@
+use super::boyer_lindquist::{radial_derivatives, radial_integrals};
+use super::eddington::{ef_christoffel, ef_g, ef_inv_g};
+use super::{
+    BoyerLindquist, CarterConstant, EddingtonFinkelstein, NearPole0OutgoingEF,
+    NearPolePiOutgoingEF, Properties,
+};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The outgoing Eddington-Finkelstein coordinates (u, r, theta, phi), with the retarded time u.
+/// They are regular at the future cosmological horizon, so photons can be followed out of the
+/// universe visible to the black hole, and at the past (white hole) horizons.
+pub struct OutgoingEF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for OutgoingEF<P> {
+    type Dimension = U4;
+}
+
+impl<P> OutgoingEF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> TwoForm<Self> {
+        ef_g(-1.0, m, a, l, x)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        ef_inv_g(-1.0, m, a, l, x)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        ef_christoffel(-1.0, m, a, l, x)
+    }
+}
+
+impl<P: Properties> MetricSystem for OutgoingEF<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), x)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for OutgoingEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P> OutgoingEF<P> {
+    pub(crate) fn to_boyer_lindquist_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<BoyerLindquist<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] + dt, p[1], p[2], p[3] + dphi])
+    }
+
+    pub(crate) fn to_boyer_lindquist_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<BoyerLindquist<P>> {
+        let (dudr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_boyer_lindquist_point_with(m, a, l, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_ef_point_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Point<EddingtonFinkelstein<P>> {
+        let (dt, dphi) = radial_integrals(m, a, l, p[1]);
+        Point::new(arr![f64; p[0] + 2.0 * dt, p[1], p[2], p[3] + 2.0 * dphi])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Matrix<EddingtonFinkelstein<P>> {
+        let (dudr, dphidr) = radial_derivatives(m, a, l, p[1]);
+        Matrix::new(
+            Self::to_ef_point_with(m, a, l, p),
+            arr![f64;
+                1.0, 2.0 * dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 2.0 * dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<BoyerLindquist<P>> for OutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_point_with(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p,
+        )
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<BoyerLindquist<P>> {
+        Self::to_boyer_lindquist_jacobian_with(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p,
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<BoyerLindquist<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for OutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let (dudr, dphidr) = radial_derivatives(
+            P::mass(),
+            P::ang_momentum(),
+            P::cosmological_constant(),
+            p[1],
+        );
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -2.0 * dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, -2.0 * dphidr, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0OutgoingEF<P>> for OutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0OutgoingEF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0OutgoingEF<P>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0OutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0OutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPolePiOutgoingEF<P>> for OutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiOutgoingEF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiOutgoingEF<P>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiOutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiOutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,399 @@
+use super::boyer_lindquist::MetricFunctions;
+use super::{CarterConstant, EddingtonFinkelstein, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::spacetime::christoffel_from_dg;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+// The metric of the polar patches of the ingoing (eps = 1) and outgoing (eps = -1)
+// Eddington-Finkelstein charts. All the polar charts share the same metric:
+// g = g_uu du^2 - 2 eps/Xi du dr + 2 g_uphi/s^2 du w + 2 eps a/Xi dr w - h alpha^2 (dx^2 + dy^2)
+//     + (g_phiphi/s^2 + h)/s^2 w^2,
+// where h = rho^2/Delta_theta and w = sin^2(theta) dphi = alpha^2 (x dy - y dx).
+pub(crate) fn polar_g<C>(eps: f64, m: f64, a: f64, l: f64, p: &Point<C>) -> TwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let x = p[2];
+    let y = p[3];
+    let xy1 = 1.0 + x * x + y * y;
+    let alpha2 = 4.0 / xy1 / xy1;
+    let f = MetricFunctions::new(m, a, l, p[1], (1.0 - x * x - y * y) / xy1);
+
+    let w = [-alpha2 * y, alpha2 * x];
+    let gur = -eps / f.xi;
+    let gux = f.tw[0] * w[0];
+    let guy = f.tw[0] * w[1];
+    let grx = eps * a / f.xi * w[0];
+    let gry = eps * a / f.xi * w[1];
+    let gxx = f.ww[0] * w[0] * w[0] - f.h[0] * alpha2;
+    let gxy = f.ww[0] * w[0] * w[1];
+    let gyy = f.ww[0] * w[1] * w[1] - f.h[0] * alpha2;
+    TwoForm::new(
+        *p,
+        arr![f64;
+            f.tt[0], gur, gux, guy,
+            gur, 0.0, grx, gry,
+            gux, grx, gxx, gxy,
+            guy, gry, gxy, gyy
+        ],
+    )
+}
+
+pub(crate) fn polar_inv_g<C>(eps: f64, m: f64, a: f64, l: f64, p: &Point<C>) -> InvTwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let r = p[1];
+    let x = p[2];
+    let y = p[3];
+    let xy1 = 1.0 + x * x + y * y;
+    let alpha2 = 4.0 / xy1 / xy1;
+    let f = MetricFunctions::new(m, a, l, r, (1.0 - x * x - y * y) / xy1);
+    let rho2 = f.rho2;
+    let xi = f.xi;
+    let dth = f.delta_theta;
+    // Xi^2 - Delta_theta^2 = l a^2 alpha^2 (x^2 + y^2)/3 (Xi + Delta_theta)
+    let z = l / 3.0 * a * a * (xi + dth) / (dth * rho2);
+
+    let guu = -xi * xi * a * a * alpha2 * (x * x + y * y) / (rho2 * dth);
+    let gur = -eps * xi * (r * r + a * a) / rho2;
+    let grr = -f.delta_r[0] / rho2;
+    let gux = xi * xi * a * y / (rho2 * dth);
+    let guy = -xi * xi * a * x / (rho2 * dth);
+    let grx = eps * xi * a * y / rho2;
+    let gry = -eps * xi * a * x / rho2;
+    let gxx = -dth / (alpha2 * rho2) - z * y * y;
+    let gyy = -dth / (alpha2 * rho2) - z * x * x;
+    let gxy = z * x * y;
+    InvTwoForm::new(
+        *p,
+        arr![f64;
+            guu, gur, gux, guy,
+            gur, grr, grx, gry,
+            gux, grx, gxx, gxy,
+            guy, gry, gxy, gyy
+        ],
+    )
+}
+
+pub(crate) fn polar_christoffel<C>(
+    eps: f64,
+    m: f64,
+    a: f64,
+    l: f64,
+    p: &Point<C>,
+) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let x = p[2];
+    let y = p[3];
+    let xy1 = 1.0 + x * x + y * y;
+    let alpha2 = 4.0 / xy1 / xy1;
+    let f = MetricFunctions::new(m, a, l, p[1], (1.0 - x * x - y * y) / xy1);
+
+    // the derivatives of a function of r and c, given its derivatives with respect to r and c;
+    // dc/dx = -x alpha^2 and dc/dy = -y alpha^2
+    let d = |v: [f64; 3]| [0.0, v[1], -x * alpha2 * v[2], -y * alpha2 * v[2]];
+    let d_tt = d(f.tt);
+    let d_tw = d(f.tw);
+    let d_ww = d(f.ww);
+    let d_h = d(f.h);
+    let w = [0.0, 0.0, -alpha2 * y, alpha2 * x];
+    let d_alpha2 = [0.0, 0.0, -4.0 * x * alpha2 / xy1, -4.0 * y * alpha2 / xy1];
+    let d_w = [
+        [0.0; 4],
+        [0.0; 4],
+        [0.0, 0.0, -y * d_alpha2[2], -alpha2 - y * d_alpha2[3]],
+        [0.0, 0.0, alpha2 + x * d_alpha2[2], x * d_alpha2[3]],
+    ];
+
+    // dg[i][j][k] = dg_ij/dx^k
+    let mut dg = [[[0.0; 4]; 4]; 4];
+    for k in 0..4 {
+        dg[0][0][k] = d_tt[k];
+        for i in 2..4 {
+            dg[0][i][k] = d_tw[k] * w[i] + f.tw[0] * d_w[i][k];
+            dg[i][0][k] = dg[0][i][k];
+            dg[1][i][k] = eps * a / f.xi * d_w[i][k];
+            dg[i][1][k] = dg[1][i][k];
+            for j in 2..4 {
+                dg[i][j][k] =
+                    d_ww[k] * w[i] * w[j] + f.ww[0] * (d_w[i][k] * w[j] + w[i] * d_w[j][k]);
+            }
+            dg[i][i][k] -= d_h[k] * alpha2 + f.h[0] * d_alpha2[k];
+        }
+    }
+    christoffel_from_dg(p, &polar_inv_g(eps, m, a, l, p), &dg)
+}
+
+/// The coordinate system near the pole theta=0
+pub struct NearPole0EF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPole0EF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPole0EF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> TwoForm<Self> {
+        polar_g(1.0, m, a, l, p)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        polar_inv_g(1.0, m, a, l, p)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        polar_christoffel(1.0, m, a, l, p)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPole0EF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPole0EF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole0EF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
+            ],
+        )
+    }
+}
+
+/// The coordinate system near the pole theta=pi
+pub struct NearPolePiEF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPolePiEF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPolePiEF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> TwoForm<Self> {
+        polar_g(1.0, m, a, l, p)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        polar_inv_g(1.0, m, a, l, p)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        polar_christoffel(1.0, m, a, l, p)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPolePiEF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPolePiEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPolePiEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = PI - (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
+            ],
+        )
+    }
+}
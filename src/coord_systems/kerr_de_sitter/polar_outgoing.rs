@@ -0,0 +1,275 @@
+use super::polar_eddington::{polar_christoffel, polar_g, polar_inv_g};
+use super::{CarterConstant, OutgoingEF, Properties};
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The coordinate system near the pole theta=0
+pub struct NearPole0OutgoingEF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPole0OutgoingEF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPole0OutgoingEF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> TwoForm<Self> {
+        polar_g(-1.0, m, a, l, p)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        polar_inv_g(-1.0, m, a, l, p)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        polar_christoffel(-1.0, m, a, l, p)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPole0OutgoingEF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPole0OutgoingEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<OutgoingEF<P>> for NearPole0OutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
+            ],
+        )
+    }
+}
+
+/// The coordinate system near the pole theta=pi
+pub struct NearPolePiOutgoingEF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPolePiOutgoingEF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPolePiOutgoingEF<P> {
+    pub(crate) fn g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> TwoForm<Self> {
+        polar_g(-1.0, m, a, l, p)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, a: f64, l: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        polar_inv_g(-1.0, m, a, l, p)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        a: f64,
+        l: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        polar_christoffel(-1.0, m, a, l, p)
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPolePiOutgoingEF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::ang_momentum(), P::cosmological_constant(), p)
+    }
+}
+
+impl<P: CarterConstant> Symmetries for NearPolePiOutgoingEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+
+    fn carter_constant(p: &Covector<Self>, conserved: &ConservedQuantities) -> f64 {
+        P::carter_constant(p, conserved)
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<OutgoingEF<P>> for NearPolePiOutgoingEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = PI - (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<OutgoingEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
+            ],
+        )
+    }
+}
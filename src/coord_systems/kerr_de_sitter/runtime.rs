@@ -0,0 +1,420 @@
+use super::boyer_lindquist::horizons;
+use super::{
+    carter_constant_with, BoyerLindquist, EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF,
+    NearPolePiEF, NearPolePiOutgoingEF, OutgoingEF,
+};
+use crate::conserved::ConservedQuantities;
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm,
+};
+
+/// A Kerr-de Sitter black hole with the mass, angular momentum and cosmological constant only
+/// known at runtime. It provides the metric of the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackHole {
+    pub mass: f64,
+    pub ang_momentum: f64,
+    pub cosmological_constant: f64,
+}
+
+impl BlackHole {
+    pub fn new(mass: f64, ang_momentum: f64, cosmological_constant: f64) -> Self {
+        BlackHole {
+            mass,
+            ang_momentum,
+            cosmological_constant,
+        }
+    }
+
+    fn horizons(&self) -> [f64; 4] {
+        horizons(self.mass, self.ang_momentum, self.cosmological_constant)
+    }
+
+    /// The radius of the outer black hole horizon
+    pub fn horizon_radius(&self) -> f64 {
+        self.horizons()[2]
+    }
+
+    /// The radius of the inner (Cauchy) horizon
+    pub fn inner_horizon_radius(&self) -> f64 {
+        self.horizons()[1]
+    }
+
+    /// The radius of the cosmological horizon
+    pub fn cosmological_horizon_radius(&self) -> f64 {
+        self.horizons()[3]
+    }
+}
+
+impl Spacetime<BoyerLindquist<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<BoyerLindquist<Runtime>>) -> TwoForm<BoyerLindquist<Runtime>> {
+        BoyerLindquist::g_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn inv_g(&self, x: &Point<BoyerLindquist<Runtime>>) -> InvTwoForm<BoyerLindquist<Runtime>> {
+        BoyerLindquist::inv_g_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<BoyerLindquist<Runtime>>,
+    ) -> Tensor<BoyerLindquist<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        BoyerLindquist::christoffel_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            x,
+        )
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<BoyerLindquist<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> TwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::g_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> InvTwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::inv_g_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            x,
+        )
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Tensor<EddingtonFinkelstein<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        EddingtonFinkelstein::christoffel_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            x,
+        )
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<EddingtonFinkelstein<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<OutgoingEF<Runtime>>) -> TwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::g_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn inv_g(&self, x: &Point<OutgoingEF<Runtime>>) -> InvTwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::inv_g_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<OutgoingEF<Runtime>>,
+    ) -> Tensor<OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        OutgoingEF::christoffel_with(self.mass, self.ang_momentum, self.cosmological_constant, x)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<OutgoingEF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<NearPole0EF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0EF<Runtime>>) -> TwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0EF<Runtime>>) -> InvTwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::inv_g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0EF<Runtime>>,
+    ) -> Tensor<NearPole0EF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0EF::christoffel_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPole0EF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<NearPolePiEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiEF<Runtime>>) -> TwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiEF<Runtime>>) -> InvTwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::inv_g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiEF<Runtime>>,
+    ) -> Tensor<NearPolePiEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiEF::christoffel_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPolePiEF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<NearPole0OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0OutgoingEF<Runtime>>) -> TwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::inv_g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> Tensor<NearPole0OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPole0OutgoingEF::christoffel_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPole0OutgoingEF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+impl Spacetime<NearPolePiOutgoingEF<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> TwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::g_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::inv_g_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> Tensor<NearPolePiOutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiOutgoingEF::christoffel_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn carter_constant(
+        &self,
+        p: &Covector<NearPolePiOutgoingEF<Runtime>>,
+        conserved: &ConservedQuantities,
+    ) -> f64 {
+        carter_constant_with(self.ang_momentum, self.cosmological_constant, p, conserved)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<BoyerLindquist<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<BoyerLindquist<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        BoyerLindquist::to_ef_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<BoyerLindquist<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        BoyerLindquist::to_ef_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, BoyerLindquist<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<BoyerLindquist<Runtime>> {
+        EddingtonFinkelstein::to_boyer_lindquist_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<BoyerLindquist<Runtime>> {
+        EddingtonFinkelstein::to_boyer_lindquist_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<BoyerLindquist<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<BoyerLindquist<Runtime>>) -> Point<OutgoingEF<Runtime>> {
+        BoyerLindquist::to_outgoing_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(&self, p: &Point<BoyerLindquist<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        BoyerLindquist::to_outgoing_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, BoyerLindquist<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<OutgoingEF<Runtime>>) -> Point<BoyerLindquist<Runtime>> {
+        OutgoingEF::to_boyer_lindquist_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<BoyerLindquist<Runtime>> {
+        OutgoingEF::to_boyer_lindquist_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_point_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_jacobian_with(
+            self.mass,
+            self.ang_momentum,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<OutgoingEF<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_point_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_jacobian_with(self.mass, self.ang_momentum, self.cosmological_constant, p)
+    }
+}
+
+// the conversions to and from the polar charts don't depend on the parameters
+impl<C1, C2> SpacetimeConversion<C1, C2> for BlackHole
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
@@ -0,0 +1,14 @@
+use super::{NearPole0Spherical, NearPolePiSpherical, Spherical};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the spherical chart and its polar patches
+pub struct SphericalAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for SphericalAtlas<P> {
+    type Main = Spherical<P>;
+    type Pole0 = NearPole0Spherical<P>;
+    type PolePi = NearPolePiSpherical<P>;
+}
@@ -0,0 +1,233 @@
+use super::{Properties, Rindler, Rotating, Spherical};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The inertial coordinates (t, x, y, z)
+pub struct Cartesian<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Cartesian<P> {
+    type Dimension = U4;
+}
+
+impl<P> MetricSystem for Cartesian<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::zero(*x)
+    }
+}
+
+impl<P> Symmetries for Cartesian<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, -x[2], x[1], 0.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[3] / Self::radius(x)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        (x[1] * x[1] + x[2] * x[2] + x[3] * x[3]).sqrt()
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let r = Self::radius(x);
+        let p2 = p[1] * p[1] + p[2] * p[2] + p[3] * p[3];
+        let xp = x[1] * p[1] + x[2] * p[2] + x[3] * p[3];
+        r * r * p2 - xp * xp
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        let r = Self::radius(x);
+        Covector::new(*x, arr![f64; 0.0, x[1] / r, x[2] / r, x[3] / r])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let r = Self::radius(x);
+        let (sin_th, cos_th) = ((x[1] * x[1] + x[2] * x[2]).sqrt() / r, x[3] / r);
+        let ph = x[2].atan2(x[1]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, cos_th * ph.cos() / r, cos_th * ph.sin() / r, -sin_th / r],
+            ),
+            Covector::new(*x, arr![f64; 0.0, -ph.sin() / r, ph.cos() / r, 0.0]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<Spherical<P>> for Cartesian<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Spherical<P>> {
+        let r = (p[1] * p[1] + p[2] * p[2] + p[3] * p[3]).sqrt();
+        let th = (p[3] / r).acos();
+        let ph = p[2].atan2(p[1]);
+        Point::new(arr![f64; p[0], r, th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Spherical<P>> {
+        let (x, y, z) = (p[1], p[2], p[3]);
+        let rho2 = x * x + y * y;
+        let rho = rho2.sqrt();
+        let r2 = rho2 + z * z;
+        let r = r2.sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, x / r, y / r, z / r,
+                0.0, x * z / (r2 * rho), y * z / (r2 * rho), -rho / r2,
+                0.0, -y / rho2, x / rho2, 0.0,
+            ],
+        )
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Spherical<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        let r = x[1];
+        let (sin_th, cos_th) = x[2].sin_cos();
+        let (sin_ph, cos_ph) = x[3].sin_cos();
+        Tensor::<Spherical<P>, (CovariantIndex, ContravariantIndex)>::new(
+            x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, sin_th * cos_ph, r * cos_th * cos_ph, -r * sin_th * sin_ph,
+                0.0, sin_th * sin_ph, r * cos_th * sin_ph, r * sin_th * cos_ph,
+                0.0, cos_th, -r * sin_th, 0.0,
+            ],
+        )
+    }
+}
+
+impl<P> Cartesian<P> {
+    pub(crate) fn to_rindler_point_with(a: f64, p: &Point<Self>) -> Point<Rindler<P>> {
+        let (t, x) = (p[0], p[1]);
+        let tau = (t / x).atanh() / a;
+        let xi = (x * x - t * t).sqrt();
+        Point::new(arr![f64; tau, xi, p[2], p[3]])
+    }
+
+    pub(crate) fn to_rindler_jacobian_with(a: f64, p: &Point<Self>) -> Matrix<Rindler<P>> {
+        let (t, x) = (p[0], p[1]);
+        let xi2 = x * x - t * t;
+        let xi = xi2.sqrt();
+        Matrix::new(
+            Self::to_rindler_point_with(a, p),
+            arr![f64;
+                x / (a * xi2), -t / (a * xi2), 0.0, 0.0,
+                -t / xi, x / xi, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_rotating_point_with(omega: f64, p: &Point<Self>) -> Point<Rotating<P>> {
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        let x = p[1] * cos_wt + p[2] * sin_wt;
+        let y = -p[1] * sin_wt + p[2] * cos_wt;
+        Point::new(arr![f64; p[0], x, y, p[3]])
+    }
+
+    pub(crate) fn to_rotating_jacobian_with(omega: f64, p: &Point<Self>) -> Matrix<Rotating<P>> {
+        let x = Self::to_rotating_point_with(omega, p);
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        Matrix::new(
+            x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                omega * x[2], cos_wt, sin_wt, 0.0,
+                -omega * x[1], -sin_wt, cos_wt, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<Rindler<P>> for Cartesian<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Rindler<P>> {
+        Self::to_rindler_point_with(P::acceleration(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Rindler<P>> {
+        Self::to_rindler_jacobian_with(P::acceleration(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Rindler<P>, (CovariantIndex, ContravariantIndex)> {
+        let a = P::acceleration();
+        let x = Self::convert_point(p);
+        let (sinh, cosh) = ((a * x[0]).sinh(), (a * x[0]).cosh());
+        Tensor::<Rindler<P>, (CovariantIndex, ContravariantIndex)>::new(
+            x,
+            arr![f64;
+                a * x[1] * cosh, sinh, 0.0, 0.0,
+                a * x[1] * sinh, cosh, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<Rotating<P>> for Cartesian<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Rotating<P>> {
+        Self::to_rotating_point_with(P::angular_velocity(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Rotating<P>> {
+        Self::to_rotating_jacobian_with(P::angular_velocity(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Rotating<P>, (CovariantIndex, ContravariantIndex)> {
+        let omega = P::angular_velocity();
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        Tensor::<Rotating<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                -omega * p[2], cos_wt, -sin_wt, 0.0,
+                omega * p[1], sin_wt, cos_wt, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,22 @@
+mod atlas;
+mod cartesian;
+mod polar_spherical;
+mod rindler;
+mod rotating;
+mod runtime;
+mod spherical;
+
+/// The parameters of the non-inertial charts: the proper acceleration of the reference Rindler
+/// observer and the angular velocity of the rotating frame. The inertial charts don't need them.
+pub trait Properties {
+    fn acceleration() -> f64;
+    fn angular_velocity() -> f64;
+}
+
+pub use self::atlas::SphericalAtlas;
+pub use self::cartesian::Cartesian;
+pub use self::polar_spherical::{NearPole0Spherical, NearPolePiSpherical};
+pub use self::rindler::Rindler;
+pub use self::rotating::Rotating;
+pub use self::runtime::Minkowski;
+pub use self::spherical::Spherical;
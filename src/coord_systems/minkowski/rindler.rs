@@ -0,0 +1,144 @@
+use super::{Cartesian, Properties};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Rindler coordinates (tau, xi, y, z) of observers uniformly accelerating along the x axis,
+/// with t = xi sinh(a tau) and x = xi cosh(a tau). The observer at xi = 1/a has the proper
+/// acceleration a and measures the proper time tau. They only cover the wedge x > |t|, bounded
+/// by the Rindler horizon at xi = 0.
+pub struct Rindler<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Rindler<P> {
+    type Dimension = U4;
+}
+
+impl<P> Rindler<P> {
+    pub(crate) fn g_with(a: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let axi = a * x[1];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                axi*axi, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(a: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let axi = a * x[1];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0/(axi*axi), 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        a: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let xi = x[1];
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, 1.0/xi, 0.0, 0.0,
+                1.0/xi, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                a*a*xi, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for Rindler<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::acceleration(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::acceleration(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::acceleration(), x)
+    }
+}
+
+// Conversions
+
+impl<P> Rindler<P> {
+    pub(crate) fn to_cartesian_point_with(a: f64, p: &Point<Self>) -> Point<Cartesian<P>> {
+        let (tau, xi) = (p[0], p[1]);
+        let t = xi * (a * tau).sinh();
+        let x = xi * (a * tau).cosh();
+        Point::new(arr![f64; t, x, p[2], p[3]])
+    }
+
+    pub(crate) fn to_cartesian_jacobian_with(a: f64, p: &Point<Self>) -> Matrix<Cartesian<P>> {
+        let (tau, xi) = (p[0], p[1]);
+        let (sinh, cosh) = ((a * tau).sinh(), (a * tau).cosh());
+        Matrix::new(
+            Self::to_cartesian_point_with(a, p),
+            arr![f64;
+                a * xi * cosh, sinh, 0.0, 0.0,
+                a * xi * sinh, cosh, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<Cartesian<P>> for Rindler<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian<P>> {
+        Self::to_cartesian_point_with(P::acceleration(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Cartesian<P>> {
+        Self::to_cartesian_jacobian_with(P::acceleration(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Cartesian<P>, (CovariantIndex, ContravariantIndex)> {
+        let a = P::acceleration();
+        let (tau, xi) = (p[0], p[1]);
+        let (sinh, cosh) = ((a * tau).sinh(), (a * tau).cosh());
+        Tensor::<Cartesian<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                cosh / (a * xi), -sinh / (a * xi), 0.0, 0.0,
+                -sinh, cosh, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,193 @@
+use super::{Cartesian, Properties};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The coordinates (t, x, y, z) of a frame rotating about the z axis with a constant angular
+/// velocity omega. Observers at rest in them only exist inside the light cylinder,
+/// omega^2 (x^2 + y^2) < 1.
+pub struct Rotating<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Rotating<P> {
+    type Dimension = U4;
+}
+
+impl<P> Rotating<P> {
+    pub(crate) fn g_with(omega: f64, p: &Point<Self>) -> TwoForm<Self> {
+        let x = p[1];
+        let y = p[2];
+        TwoForm::new(
+            *p,
+            arr![f64;
+                1.0 - omega*omega*(x*x + y*y), omega*y, -omega*x, 0.0,
+                omega*y, -1.0, 0.0, 0.0,
+                -omega*x, 0.0, -1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(omega: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        let x = p[1];
+        let y = p[2];
+        InvTwoForm::new(
+            *p,
+            arr![f64;
+                1.0, omega*y, -omega*x, 0.0,
+                omega*y, omega*omega*y*y - 1.0, -omega*omega*x*y, 0.0,
+                -omega*x, -omega*omega*x*y, omega*omega*x*x - 1.0, 0.0,
+                0.0, 0.0, 0.0, -1.0
+            ],
+        )
+    }
+
+    // the centrifugal and Coriolis terms
+    pub(crate) fn christoffel_with(
+        omega: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let x = p[1];
+        let y = p[2];
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                -omega*omega*x, 0.0, -omega, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                -omega, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                -omega*omega*y, omega, 0.0, 0.0,
+                omega, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for Rotating<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::angular_velocity(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::angular_velocity(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::angular_velocity(), x)
+    }
+}
+
+impl<P> Symmetries for Rotating<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, -x[2], x[1], 0.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[3] / Self::radius(x)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        (x[1] * x[1] + x[2] * x[2] + x[3] * x[3]).sqrt()
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let r = Self::radius(x);
+        let p2 = p[1] * p[1] + p[2] * p[2] + p[3] * p[3];
+        let xp = x[1] * p[1] + x[2] * p[2] + x[3] * p[3];
+        r * r * p2 - xp * xp
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        let r = Self::radius(x);
+        Covector::new(*x, arr![f64; 0.0, x[1] / r, x[2] / r, x[3] / r])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let r = Self::radius(x);
+        let (sin_th, cos_th) = ((x[1] * x[1] + x[2] * x[2]).sqrt() / r, x[3] / r);
+        let ph = x[2].atan2(x[1]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, cos_th * ph.cos() / r, cos_th * ph.sin() / r, -sin_th / r],
+            ),
+            Covector::new(*x, arr![f64; 0.0, -ph.sin() / r, ph.cos() / r, 0.0]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P> Rotating<P> {
+    pub(crate) fn to_cartesian_point_with(omega: f64, p: &Point<Self>) -> Point<Cartesian<P>> {
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        let x = p[1] * cos_wt - p[2] * sin_wt;
+        let y = p[1] * sin_wt + p[2] * cos_wt;
+        Point::new(arr![f64; p[0], x, y, p[3]])
+    }
+
+    pub(crate) fn to_cartesian_jacobian_with(omega: f64, p: &Point<Self>) -> Matrix<Cartesian<P>> {
+        let x = Self::to_cartesian_point_with(omega, p);
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        Matrix::new(
+            x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                -omega * x[2], cos_wt, -sin_wt, 0.0,
+                omega * x[1], sin_wt, cos_wt, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<Cartesian<P>> for Rotating<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian<P>> {
+        Self::to_cartesian_point_with(P::angular_velocity(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Cartesian<P>> {
+        Self::to_cartesian_jacobian_with(P::angular_velocity(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Cartesian<P>, (CovariantIndex, ContravariantIndex)> {
+        let omega = P::angular_velocity();
+        let (sin_wt, cos_wt) = (omega * p[0]).sin_cos();
+        Tensor::<Cartesian<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                omega * p[2], cos_wt, sin_wt, 0.0,
+                -omega * p[1], -sin_wt, cos_wt, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,191 @@
+use super::{Cartesian, NearPole0Spherical, NearPolePiSpherical, Rindler, Rotating, Spherical};
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+
+/// The flat spacetime, with the parameters of the non-inertial charts only known at runtime. It
+/// provides the metric of the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Minkowski {
+    pub acceleration: f64,
+    pub angular_velocity: f64,
+}
+
+impl Minkowski {
+    pub fn new(acceleration: f64, angular_velocity: f64) -> Self {
+        Minkowski {
+            acceleration,
+            angular_velocity,
+        }
+    }
+}
+
+impl Spacetime<Cartesian<Runtime>> for Minkowski {
+    fn g(&self, x: &Point<Cartesian<Runtime>>) -> TwoForm<Cartesian<Runtime>> {
+        Cartesian::g(x)
+    }
+
+    fn inv_g(&self, x: &Point<Cartesian<Runtime>>) -> InvTwoForm<Cartesian<Runtime>> {
+        Cartesian::inv_g(x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Cartesian<Runtime>>,
+    ) -> Tensor<Cartesian<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Cartesian::christoffel(x)
+    }
+}
+
+impl Spacetime<Spherical<Runtime>> for Minkowski {
+    fn g(&self, x: &Point<Spherical<Runtime>>) -> TwoForm<Spherical<Runtime>> {
+        Spherical::g(x)
+    }
+
+    fn inv_g(&self, x: &Point<Spherical<Runtime>>) -> InvTwoForm<Spherical<Runtime>> {
+        Spherical::inv_g(x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Spherical<Runtime>>,
+    ) -> Tensor<Spherical<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Spherical::christoffel(x)
+    }
+}
+
+impl Spacetime<NearPole0Spherical<Runtime>> for Minkowski {
+    fn g(&self, p: &Point<NearPole0Spherical<Runtime>>) -> TwoForm<NearPole0Spherical<Runtime>> {
+        NearPole0Spherical::g(p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPole0Spherical<Runtime>>,
+    ) -> InvTwoForm<NearPole0Spherical<Runtime>> {
+        NearPole0Spherical::inv_g(p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0Spherical<Runtime>>,
+    ) -> Tensor<NearPole0Spherical<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPole0Spherical::christoffel(p)
+    }
+}
+
+impl Spacetime<NearPolePiSpherical<Runtime>> for Minkowski {
+    fn g(&self, p: &Point<NearPolePiSpherical<Runtime>>) -> TwoForm<NearPolePiSpherical<Runtime>> {
+        NearPolePiSpherical::g(p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPolePiSpherical<Runtime>>,
+    ) -> InvTwoForm<NearPolePiSpherical<Runtime>> {
+        NearPolePiSpherical::inv_g(p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiSpherical<Runtime>>,
+    ) -> Tensor<NearPolePiSpherical<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiSpherical::christoffel(p)
+    }
+}
+
+impl Spacetime<Rindler<Runtime>> for Minkowski {
+    fn g(&self, x: &Point<Rindler<Runtime>>) -> TwoForm<Rindler<Runtime>> {
+        Rindler::g_with(self.acceleration, x)
+    }
+
+    fn inv_g(&self, x: &Point<Rindler<Runtime>>) -> InvTwoForm<Rindler<Runtime>> {
+        Rindler::inv_g_with(self.acceleration, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Rindler<Runtime>>,
+    ) -> Tensor<Rindler<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Rindler::christoffel_with(self.acceleration, x)
+    }
+}
+
+impl Spacetime<Rotating<Runtime>> for Minkowski {
+    fn g(&self, x: &Point<Rotating<Runtime>>) -> TwoForm<Rotating<Runtime>> {
+        Rotating::g_with(self.angular_velocity, x)
+    }
+
+    fn inv_g(&self, x: &Point<Rotating<Runtime>>) -> InvTwoForm<Rotating<Runtime>> {
+        Rotating::inv_g_with(self.angular_velocity, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Rotating<Runtime>>,
+    ) -> Tensor<Rotating<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Rotating::christoffel_with(self.angular_velocity, x)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<Cartesian<Runtime>, Rindler<Runtime>> for Minkowski {
+    fn convert_point(&self, p: &Point<Cartesian<Runtime>>) -> Point<Rindler<Runtime>> {
+        Cartesian::to_rindler_point_with(self.acceleration, p)
+    }
+
+    fn jacobian(&self, p: &Point<Cartesian<Runtime>>) -> Matrix<Rindler<Runtime>> {
+        Cartesian::to_rindler_jacobian_with(self.acceleration, p)
+    }
+}
+
+impl SpacetimeConversion<Rindler<Runtime>, Cartesian<Runtime>> for Minkowski {
+    fn convert_point(&self, p: &Point<Rindler<Runtime>>) -> Point<Cartesian<Runtime>> {
+        Rindler::to_cartesian_point_with(self.acceleration, p)
+    }
+
+    fn jacobian(&self, p: &Point<Rindler<Runtime>>) -> Matrix<Cartesian<Runtime>> {
+        Rindler::to_cartesian_jacobian_with(self.acceleration, p)
+    }
+}
+
+impl SpacetimeConversion<Cartesian<Runtime>, Rotating<Runtime>> for Minkowski {
+    fn convert_point(&self, p: &Point<Cartesian<Runtime>>) -> Point<Rotating<Runtime>> {
+        Cartesian::to_rotating_point_with(self.angular_velocity, p)
+    }
+
+    fn jacobian(&self, p: &Point<Cartesian<Runtime>>) -> Matrix<Rotating<Runtime>> {
+        Cartesian::to_rotating_jacobian_with(self.angular_velocity, p)
+    }
+}
+
+impl SpacetimeConversion<Rotating<Runtime>, Cartesian<Runtime>> for Minkowski {
+    fn convert_point(&self, p: &Point<Rotating<Runtime>>) -> Point<Cartesian<Runtime>> {
+        Rotating::to_cartesian_point_with(self.angular_velocity, p)
+    }
+
+    fn jacobian(&self, p: &Point<Rotating<Runtime>>) -> Matrix<Cartesian<Runtime>> {
+        Rotating::to_cartesian_jacobian_with(self.angular_velocity, p)
+    }
+}
+
+// the conversions between the inertial charts don't depend on the parameters
+impl<C1, C2> SpacetimeConversion<C1, C2> for Minkowski
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
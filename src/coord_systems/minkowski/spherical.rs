@@ -0,0 +1,240 @@
+use super::{Cartesian, NearPole0Spherical, NearPolePiSpherical};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The inertial spherical coordinates (t, r, theta, phi)
+pub struct Spherical<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Spherical<P> {
+    type Dimension = U4;
+}
+
+impl<P> MetricSystem for Spherical<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r, 0.0,
+                0.0, 0.0, 0.0, -r*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<P> Symmetries for Spherical<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<Cartesian<P>> for Spherical<P> {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian<P>> {
+        let r = p[1];
+        let (sin_th, cos_th) = p[2].sin_cos();
+        let (sin_ph, cos_ph) = p[3].sin_cos();
+        Point::new(arr![f64; p[0], r * sin_th * cos_ph, r * sin_th * sin_ph, r * cos_th])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Cartesian<P>> {
+        let r = p[1];
+        let (sin_th, cos_th) = p[2].sin_cos();
+        let (sin_ph, cos_ph) = p[3].sin_cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, sin_th * cos_ph, r * cos_th * cos_ph, -r * sin_th * sin_ph,
+                0.0, sin_th * sin_ph, r * cos_th * sin_ph, r * sin_th * cos_ph,
+                0.0, cos_th, -r * sin_th, 0.0,
+            ],
+        )
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Cartesian<P>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let (sin_th, cos_th) = p[2].sin_cos();
+        let (sin_ph, cos_ph) = p[3].sin_cos();
+        Tensor::<Cartesian<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, sin_th * cos_ph, sin_th * sin_ph, cos_th,
+                0.0, cos_th * cos_ph / r, cos_th * sin_ph / r, -sin_th / r,
+                0.0, -sin_ph / (r * sin_th), cos_ph / (r * sin_th), 0.0,
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0Spherical<P>> for Spherical<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0Spherical<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0Spherical<P>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0Spherical<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0Spherical<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPolePiSpherical<P>> for Spherical<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiSpherical<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiSpherical<P>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiSpherical<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiSpherical<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
@@ -1,2 +1,17 @@
+pub mod alcubierre;
+pub mod flrw;
 pub mod kerr;
+pub mod kerr_de_sitter;
+pub mod minkowski;
+pub mod oppenheimer_snyder;
+pub mod reissner_nordstrom;
 pub mod schwarzschild;
+pub mod schwarzschild_de_sitter;
+pub mod static_star;
+pub mod vaidya;
+pub mod wormhole;
+
+/// Marks a chart whose parameters are only known at runtime; the metric of such a chart is
+/// provided by a `Spacetime` instead of `MetricSystem`
+#[derive(Clone, Copy, Debug)]
+pub struct Runtime;
@@ -0,0 +1,14 @@
+#[allow(clippy::module_inception)]
+mod oppenheimer_snyder;
+mod runtime;
+mod star;
+
+/// The parameters of a collapsing star: its mass and the radius at which it starts to collapse
+/// from rest.
+pub trait Properties {
+    fn mass() -> f64;
+    fn initial_radius() -> f64;
+}
+
+pub use self::oppenheimer_snyder::OppenheimerSnyder;
+pub use self::runtime::CollapsingStar;
@@ -0,0 +1,142 @@
+use super::star::{Fields, Star};
+use super::Properties;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+use generic_array::arr;
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+/// The ingoing coordinates (v, r, theta, phi) of the Oppenheimer-Snyder collapse of a
+/// homogeneous dust star: ds^2 = L^2 (1 - 2m/r) dv^2 - 2 L dv dr - r^2 dOmega^2, with the advanced
+/// time v constant along the ingoing light rays and the areal radius r.
+///
+/// Outside the star L = 1 and m is its mass, so the chart is exactly the ingoing
+/// Eddington-Finkelstein chart of the exterior Schwarzschild spacetime. Inside, the star is a
+/// part of a closed dust FLRW universe, m is the mass enclosed by the sphere of radius r and L is
+/// fixed by the advanced time, which stays continuous across the surface. The metric is
+/// continuous there, so particles cross the moving surface without noticing it.
+///
+/// The surface starts to collapse from rest at v = 0. Earlier, it follows the time reverse of
+/// the collapse.
+pub struct OppenheimerSnyder<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for OppenheimerSnyder<P> {
+    type Dimension = U4;
+}
+
+impl<P> OppenheimerSnyder<P> {
+    pub(crate) fn g_with(fields: Fields, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let (l, _, _) = fields.lapse;
+        let (m, _, _) = fields.mass;
+        TwoForm::new(
+            *x,
+            arr![f64;
+                l*l*(1.0 - 2.0*m/r), -l, 0.0, 0.0,
+                -l, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(fields: Fields, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let (l, _, _) = fields.lapse;
+        let (m, _, _) = fields.mass;
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                0.0, -1.0/l, 0.0, 0.0,
+                -1.0/l, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        fields: Fields,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let (l, l_v, l_r) = fields.lapse;
+        let (m, m_v, m_r) = fields.mass;
+        // g_vv and its derivatives
+        let f = 1.0 - 2.0 * m / r;
+        let g = l * l * f;
+        let g_v = 2.0 * l * l_v * f - 2.0 * l * l * m_v / r;
+        let g_r = 2.0 * l * l_r * f + 2.0 * l * l * (m / r - m_r) / r;
+        let gamma_vvv = (l_v + 0.5 * g_r) / l;
+        let gamma_rvv = -0.5 * g_v / l + g / l * gamma_vvv;
+        let gamma_rvr = -0.5 * g_r / l;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                gamma_vvv, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r/l, 0.0,
+                0.0, 0.0, 0.0, -r*th.sin()*th.sin()/l,
+
+                gamma_rvv, gamma_rvr, 0.0, 0.0,
+                gamma_rvr, l_r/l, 0.0, 0.0,
+                0.0, 0.0, -r*f, 0.0,
+                0.0, 0.0, 0.0, -r*f*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+thread_local! {
+    // the parameters of the last star used by the static charts and its geometry, so that it
+    // isn't rebuilt at every point
+    static LAST_STAR: Cell<Option<(f64, f64, Star)>> = const { Cell::new(None) };
+}
+
+impl<P: Properties> OppenheimerSnyder<P> {
+    fn fields(x: &Point<Self>) -> Fields {
+        let (mass, initial_radius) = (P::mass(), P::initial_radius());
+        let star = LAST_STAR.with(|last| match last.get() {
+            Some((m, r, star)) if m == mass && r == initial_radius => star,
+            _ => {
+                let star = Star::new(mass, initial_radius);
+                last.set(Some((mass, initial_radius, star)));
+                star
+            }
+        });
+        star.fields(x[0], x[1])
+    }
+}
+
+impl<P: Properties> MetricSystem for OppenheimerSnyder<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(Self::fields(x), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(Self::fields(x), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(Self::fields(x), x)
+    }
+}
@@ -0,0 +1,67 @@
+use super::star::{Fields, Star};
+use super::OppenheimerSnyder;
+use crate::coord_systems::Runtime;
+use crate::Spacetime;
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+
+/// A collapsing dust star with the parameters only known at runtime. It provides the metric of
+/// the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollapsingStar {
+    mass: f64,
+    initial_radius: f64,
+    star: Star,
+}
+
+impl CollapsingStar {
+    /// A star of the mass `mass` starting to collapse from rest at the areal radius
+    /// `initial_radius`, which must be larger than the Schwarzschild radius 2M
+    pub fn new(mass: f64, initial_radius: f64) -> Self {
+        CollapsingStar {
+            mass,
+            initial_radius,
+            star: Star::new(mass, initial_radius),
+        }
+    }
+
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+
+    /// The areal radius at which the star starts to collapse from rest, at v = 0
+    pub fn initial_radius(&self) -> f64 {
+        self.initial_radius
+    }
+
+    /// The areal radius of the surface of the star at the advanced time `v`; 0 once the star has
+    /// collapsed into the singularity
+    pub fn surface_radius(&self, v: f64) -> f64 {
+        self.star.surface_radius(v)
+    }
+
+    fn fields(&self, x: &Point<OppenheimerSnyder<Runtime>>) -> Fields {
+        self.star.fields(x[0], x[1])
+    }
+}
+
+impl Spacetime<OppenheimerSnyder<Runtime>> for CollapsingStar {
+    fn g(&self, x: &Point<OppenheimerSnyder<Runtime>>) -> TwoForm<OppenheimerSnyder<Runtime>> {
+        OppenheimerSnyder::g_with(self.fields(x), x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<OppenheimerSnyder<Runtime>>,
+    ) -> InvTwoForm<OppenheimerSnyder<Runtime>> {
+        OppenheimerSnyder::inv_g_with(self.fields(x), x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<OppenheimerSnyder<Runtime>>,
+    ) -> Tensor<OppenheimerSnyder<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        OppenheimerSnyder::christoffel_with(self.fields(x), x)
+    }
+}
@@ -0,0 +1,175 @@
+use std::f64::consts::PI;
+
+/// The metric functions of the Oppenheimer-Snyder chart at a point, each with its derivatives
+/// over v and r: the lapse Lambda in front of dv and the mass m enclosed by the sphere through
+/// the point
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fields {
+    pub lapse: (f64, f64, f64),
+    pub mass: (f64, f64, f64),
+}
+
+/// The geometry of a closed dust FLRW ball of conformal radius chi0 with the scale factor
+/// a(eta) = a_max (1 + cos eta) / 2, matched to the Schwarzschild exterior of mass
+/// m = a_max sin^3 chi0 / 2. The surface has the areal radius a(eta) sin chi0 and starts to
+/// collapse from rest at eta = 0, which is put at the advanced time v = 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Star {
+    mass: f64,
+    a_max: f64,
+    chi0: f64,
+    sin0: f64,
+    cos0: f64,
+    v_offset: f64,
+}
+
+impl Star {
+    pub fn new(mass: f64, initial_radius: f64) -> Self {
+        assert!(
+            initial_radius > 2.0 * mass,
+            "the star must start to collapse outside of its Schwarzschild radius 2M"
+        );
+        let sin0 = (2.0 * mass / initial_radius).sqrt();
+        let chi0 = sin0.asin();
+        let mut star = Star {
+            mass,
+            a_max: initial_radius / sin0,
+            chi0,
+            sin0,
+            cos0: chi0.cos(),
+            v_offset: 0.0,
+        };
+        star.v_offset = star.advanced_time(0.0);
+        star
+    }
+
+    fn scale_factor(&self, eta: f64) -> f64 {
+        let c = (0.5 * eta).cos();
+        self.a_max * c * c
+    }
+
+    /// The advanced time at which the surface is at the conformal time `eta`. It diverges at
+    /// eta = 2 chi0 - pi, where the time reversed surface emerges from the past horizon.
+    fn advanced_time(&self, eta: f64) -> f64 {
+        let (s0, c0) = (self.sin0, self.cos0);
+        let y = 0.5 * eta - self.chi0;
+        let (sin_y, cos_y) = y.sin_cos();
+        let sin_2y = (2.0 * y).sin();
+        2.0 * self.a_max
+            * (c0 * c0 * c0 * (0.5 * y + 0.25 * sin_2y) - 1.5 * c0 * c0 * s0 * sin_y * sin_y
+                + 3.0 * c0 * s0 * s0 * (0.5 * y - 0.25 * sin_2y)
+                + s0 * s0 * s0 * (cos_y.ln() - 0.5 * cos_y * cos_y))
+            - self.v_offset
+    }
+
+    /// dv/deta along the surface
+    fn advanced_time_rate(&self, eta: f64) -> f64 {
+        let c = (0.5 * eta).cos();
+        self.a_max * c * c * c / (0.5 * eta - self.chi0).cos()
+    }
+
+    /// The conformal time of the surface at the advanced time `v`, or `None` if the star has
+    /// already collapsed into the singularity
+    fn surface_time(&self, v: f64) -> Option<f64> {
+        if v >= self.advanced_time(PI) {
+            return None;
+        }
+        Some(solve_increasing(
+            |eta| (self.advanced_time(eta), self.advanced_time_rate(eta)),
+            v,
+            2.0 * self.chi0 - PI,
+            PI,
+        ))
+    }
+
+    /// The areal radius of the surface at the advanced time `v`
+    pub fn surface_radius(&self, v: f64) -> f64 {
+        self.surface_time(v)
+            .map_or(0.0, |eta| self.scale_factor(eta) * self.sin0)
+    }
+
+    pub fn fields(&self, v: f64, r: f64) -> Fields {
+        match self.surface_time(v) {
+            Some(eta_s) if r < self.scale_factor(eta_s) * self.sin0 => self.interior(eta_s, r),
+            _ => Fields {
+                lapse: (1.0, 0.0, 0.0),
+                mass: (self.mass, 0.0, 0.0),
+            },
+        }
+    }
+
+    /// The fields inside the star, on the ingoing light ray through the surface at the
+    /// conformal time `eta_s`, where eta + chi = eta_s + chi0
+    fn interior(&self, eta_s: f64, r: f64) -> Fields {
+        let a_max = self.a_max;
+        let w = eta_s + self.chi0;
+        // the areal radius a(w - chi) sin chi grows with chi along the ray, at the rate D
+        let d = |chi: f64| {
+            let eta = w - chi;
+            a_max * (0.5 * eta).cos() * (0.5 * eta - chi).cos()
+        };
+        let chi = solve_increasing(
+            |chi| (self.scale_factor(w - chi) * chi.sin(), d(chi)),
+            r,
+            (w - PI).max(0.0),
+            self.chi0,
+        );
+        let eta = w - chi;
+        let (sin_chi, cos_chi) = chi.sin_cos();
+        let (half_sin, half_cos) = (0.5 * eta).sin_cos();
+        let a = self.scale_factor(eta);
+        let da = -a_max * half_sin * half_cos;
+        let d = d(chi);
+        let dv = self.advanced_time_rate(eta_s);
+        let lapse = a * a / (d * dv);
+
+        // the inverse of the Jacobian of (v, r) over (eta, chi)
+        let (eta_v, eta_r) = (a * cos_chi / (dv * d), -1.0 / d);
+        let (chi_v, chi_r) = (-da * sin_chi / (dv * d), 1.0 / d);
+
+        let tan_s = (0.5 * eta_s).tan();
+        let tan_s0 = (0.5 * eta_s - self.chi0).tan();
+        let tan_chi = (0.5 * eta - chi).tan();
+        let ln_lapse_eta = -1.5 * half_sin / half_cos - 0.5 * tan_s0 + 0.5 * tan_chi + 1.5 * tan_s;
+        let ln_lapse_chi = -0.5 * tan_s0 - tan_chi + 1.5 * tan_s;
+
+        let mass = 0.5 * a_max * sin_chi * sin_chi * sin_chi;
+        let mass_chi = 1.5 * a_max * sin_chi * sin_chi * cos_chi;
+
+        Fields {
+            lapse: (
+                lapse,
+                lapse * (ln_lapse_eta * eta_v + ln_lapse_chi * chi_v),
+                lapse * (ln_lapse_eta * eta_r + ln_lapse_chi * chi_r),
+            ),
+            mass: (mass, mass_chi * chi_v, mass_chi * chi_r),
+        }
+    }
+}
+
+/// Finds x in [lo, hi] with f(x) = target for an increasing f, given together with its
+/// derivative; Newton's method falling back to bisection
+fn solve_increasing<F: Fn(f64) -> (f64, f64)>(f: F, target: f64, lo: f64, hi: f64) -> f64 {
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x = 0.5 * (lo + hi);
+    for _ in 0..200 {
+        let (value, derivative) = f(x);
+        let diff = value - target;
+        if diff > 0.0 {
+            hi = x;
+        } else {
+            lo = x;
+        }
+        let newton = x - diff / derivative;
+        let next = if newton > lo && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+        if (next - x).abs() <= 1e-15 * (1.0 + x.abs()) {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
@@ -0,0 +1,27 @@
+use super::{
+    EddingtonFinkelstein, NearPole0EF, NearPole0RN, NearPolePiEF, NearPolePiRN, ReissnerNordstrom,
+};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the Reissner-Nordström chart and its polar patches
+pub struct ReissnerNordstromAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for ReissnerNordstromAtlas<P> {
+    type Main = ReissnerNordstrom<P>;
+    type Pole0 = NearPole0RN<P>;
+    type PolePi = NearPolePiRN<P>;
+}
+
+/// The atlas consisting of the Eddington-Finkelstein chart and its polar patches
+pub struct EFAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for EFAtlas<P> {
+    type Main = EddingtonFinkelstein<P>;
+    type Pole0 = NearPole0EF<P>;
+    type PolePi = NearPolePiEF<P>;
+}
@@ -0,0 +1,282 @@
+use super::reissner_nordstrom::tortoise;
+use super::{NearPole0EF, NearPolePiEF, Properties, ReissnerNordstrom};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The ingoing Eddington-Finkelstein coordinates (u, r, theta, phi), with u = t + r*. They are
+/// regular at both horizons, so infalling objects can cross them down to the singularity.
+pub struct EddingtonFinkelstein<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for EddingtonFinkelstein<P> {
+    type Dimension = U4;
+}
+
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn g_with(m: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - 2.0*m/r + q*q/(r*r), -1.0, 0.0, 0.0,
+                -1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r - q*q/(r*r), 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        q: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let f = 1.0 - 2.0 * m / r + q * q / (r * r);
+        // half of df/dr
+        let df2 = m / (r * r) - q * q / (r * r * r);
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r, 0.0,
+                0.0, 0.0, 0.0, -r*th.sin()*th.sin(),
+
+                f*df2, -df2, 0.0, 0.0,
+                -df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*f, 0.0,
+                0.0, 0.0, 0.0, -r*f*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for EddingtonFinkelstein<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::charge(), x)
+    }
+}
+
+impl<P> Symmetries for EddingtonFinkelstein<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P> EddingtonFinkelstein<P> {
+    pub(crate) fn to_reissner_nordstrom_point_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Point<ReissnerNordstrom<P>> {
+        let u = p[0];
+        let r = p[1];
+        let t = u - tortoise(m, q, r);
+        Point::new(arr![f64; t, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_reissner_nordstrom_jacobian_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<ReissnerNordstrom<P>> {
+        let r = p[1];
+        let dtdr = -r * r / (r * r - 2.0 * m * r + q * q);
+        Matrix::new(
+            Self::to_reissner_nordstrom_point_with(m, q, p),
+            arr![f64;
+                1.0, dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<ReissnerNordstrom<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<ReissnerNordstrom<P>> {
+        Self::to_reissner_nordstrom_point_with(P::mass(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<ReissnerNordstrom<P>> {
+        Self::to_reissner_nordstrom_jacobian_with(P::mass(), P::charge(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<ReissnerNordstrom<P>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let (m, q) = (P::mass(), P::charge());
+        let dtdr = -r * r / (r * r - 2.0 * m * r + q * q);
+        Tensor::<ReissnerNordstrom<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0EF<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0EF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0EF<P>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0EF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPolePiEF<P>> for EddingtonFinkelstein<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiEF<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiEF<P>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiEF<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
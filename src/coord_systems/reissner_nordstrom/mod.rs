@@ -0,0 +1,19 @@
+mod atlas;
+mod eddington;
+mod polar_eddington;
+mod polar_reissner_nordstrom;
+#[allow(clippy::module_inception)]
+mod reissner_nordstrom;
+mod runtime;
+
+pub trait Properties {
+    fn mass() -> f64;
+    fn charge() -> f64;
+}
+
+pub use self::atlas::{EFAtlas, ReissnerNordstromAtlas};
+pub use self::eddington::EddingtonFinkelstein;
+pub use self::polar_eddington::{NearPole0EF, NearPolePiEF};
+pub use self::polar_reissner_nordstrom::{NearPole0RN, NearPolePiRN};
+pub use self::reissner_nordstrom::ReissnerNordstrom;
+pub use self::runtime::BlackHole;
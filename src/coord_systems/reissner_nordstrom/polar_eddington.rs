@@ -0,0 +1,384 @@
+use super::{EddingtonFinkelstein, Properties};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The coordinate system near the pole theta=0
+pub struct NearPole0EF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPole0EF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPole0EF<P> {
+    pub(crate) fn g_with(m: f64, q: f64, p: &Point<Self>) -> TwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        TwoForm::new(
+            *p,
+            arr![f64;
+                1.0 - 2.0*m/r + q*q/(r*r), -1.0, 0.0, 0.0,
+                -1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -alpha2*r*r, 0.0,
+                0.0, 0.0, 0.0, -alpha2*r*r
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, q: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        InvTwoForm::new(
+            *p,
+            arr![f64;
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r - q*q/(r*r), 0.0, 0.0,
+                0.0, 0.0, -1.0/alpha2/r/r, 0.0,
+                0.0, 0.0, 0.0, -1.0/alpha2/r/r
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let f = 1.0 - 2.0 * m / r + q * q / (r * r);
+        // half of df/dr
+        let df2 = m / (r * r) - q * q / (r * r * r);
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*alpha2,
+
+                f*df2, -df2, 0.0, 0.0,
+                -df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*f*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*f*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPole0EF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::charge(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::charge(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::charge(), p)
+    }
+}
+
+impl<P> Symmetries for NearPole0EF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPole0EF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
+            ],
+        )
+    }
+}
+
+/// The coordinate system near the pole theta=pi
+pub struct NearPolePiEF<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for NearPolePiEF<P> {
+    type Dimension = U4;
+}
+
+impl<P> NearPolePiEF<P> {
+    pub(crate) fn g_with(m: f64, q: f64, p: &Point<Self>) -> TwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        TwoForm::new(
+            *p,
+            arr![f64;
+                1.0 - 2.0*m/r + q*q/(r*r), -1.0, 0.0, 0.0,
+                -1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -alpha2*r*r, 0.0,
+                0.0, 0.0, 0.0, -alpha2*r*r
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, q: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        InvTwoForm::new(
+            *p,
+            arr![f64;
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r - q*q/(r*r), 0.0, 0.0,
+                0.0, 0.0, -1.0/alpha2/r/r, 0.0,
+                0.0, 0.0, 0.0, -1.0/alpha2/r/r
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let f = 1.0 - 2.0 * m / r + q * q / (r * r);
+        // half of df/dr
+        let df2 = m / (r * r) - q * q / (r * r * r);
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*alpha2,
+
+                f*df2, -df2, 0.0, 0.0,
+                -df2, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*f*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*f*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for NearPolePiEF<P> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::charge(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::charge(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::charge(), p)
+    }
+}
+
+impl<P> Symmetries for NearPolePiEF<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<P: 'static> ConversionTo<EddingtonFinkelstein<P>> for NearPolePiEF<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let th = PI - (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
+            ],
+        )
+    }
+}
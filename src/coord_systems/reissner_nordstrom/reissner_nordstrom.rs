@@ -0,0 +1,313 @@
+use super::{EddingtonFinkelstein, NearPole0RN, NearPolePiRN, Properties};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+// how close to m^2 q^2 has to be for the black hole to be treated as extremal
+const EXTREMAL_EPSILON: f64 = 1e-12;
+
+/// The static coordinates (t, r, theta, phi) of a charged black hole. They are singular at both
+/// horizons, so they only describe one of the regions between them.
+pub struct ReissnerNordstrom<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for ReissnerNordstrom<P> {
+    type Dimension = U4;
+}
+
+impl<P> ReissnerNordstrom<P> {
+    pub(crate) fn g_with(m: f64, q: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let coeff = 1.0 - 2.0 * m / r + q * q / (r * r);
+        TwoForm::new(
+            *x,
+            arr![f64;
+                coeff, 0.0, 0.0, 0.0,
+                0.0, -1.0/coeff, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, q: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let coeff = 1.0 - 2.0 * m / r + q * q / (r * r);
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0 / coeff, 0.0, 0.0, 0.0,
+                0.0, -coeff, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        q: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let f = 1.0 - 2.0 * m / r + q * q / (r * r);
+        // half of df/dr
+        let df2 = m / (r * r) - q * q / (r * r * r);
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, df2/f, 0.0, 0.0,
+                df2/f, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                f*df2, 0.0, 0.0, 0.0,
+                0.0, -df2/f, 0.0, 0.0,
+                0.0, 0.0, -r*f, 0.0,
+                0.0, 0.0, 0.0, -r*f*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for ReissnerNordstrom<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(), P::charge(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(), P::charge(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(), P::charge(), x)
+    }
+}
+
+impl<P> Symmetries for ReissnerNordstrom<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+// The tortoise coordinate r*, with dr*/dr = 1/f(r). It is finite on both sides of the horizons,
+// which lets the static chart be used in any of the regions between them.
+pub(crate) fn tortoise(m: f64, q: f64, r: f64) -> f64 {
+    let disc = m * m - q * q;
+    if disc > EXTREMAL_EPSILON * m * m {
+        let r_plus = m + disc.sqrt();
+        let r_minus = m - disc.sqrt();
+        let span = r_plus - r_minus;
+        let outer = r_plus * r_plus / span * ((r - r_plus).abs() / (2.0 * m)).ln();
+        // the inner term vanishes in the uncharged case, where r_minus = 0
+        let inner = if r_minus > 0.0 {
+            r_minus * r_minus / span * ((r - r_minus).abs() / (2.0 * m)).ln()
+        } else {
+            0.0
+        };
+        r + outer - inner
+    } else if disc < -EXTREMAL_EPSILON * m * m {
+        // no horizons - a naked singularity
+        let b = (-disc).sqrt();
+        let rm = r - m;
+        r + m * ((rm * rm + b * b) / (4.0 * m * m)).ln() + (m * m - b * b) / b * (rm / b).atan()
+    } else {
+        // the extremal case, with a single degenerate horizon at r = m
+        r + 2.0 * m * ((r - m).abs() / (2.0 * m)).ln() - m * m / (r - m)
+    }
+}
+
+impl<P> ReissnerNordstrom<P> {
+    pub(crate) fn to_ef_point_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Point<EddingtonFinkelstein<P>> {
+        let t = p[0];
+        let r = p[1];
+        let u = t + tortoise(m, q, r);
+        Point::new(arr![f64; u, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(
+        m: f64,
+        q: f64,
+        p: &Point<Self>,
+    ) -> Matrix<EddingtonFinkelstein<P>> {
+        let r = p[1];
+        let dudr = r * r / (r * r - 2.0 * m * r + q * q);
+        Matrix::new(
+            Self::to_ef_point_with(m, q, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: Properties + 'static> ConversionTo<EddingtonFinkelstein<P>> for ReissnerNordstrom<P> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Self::to_ef_point_with(P::mass(), P::charge(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<P>> {
+        Self::to_ef_jacobian_with(P::mass(), P::charge(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let (m, q) = (P::mass(), P::charge());
+        let dudr = r * r / (r * r - 2.0 * m * r + q * q);
+        Tensor::<EddingtonFinkelstein<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPole0RN<P>> for ReissnerNordstrom<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0RN<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0RN<P>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0RN<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0RN<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<P: 'static> ConversionTo<NearPolePiRN<P>> for ReissnerNordstrom<P> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiRN<P>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiRN<P>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiRN<P>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiRN<P>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
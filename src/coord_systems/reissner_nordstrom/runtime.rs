@@ -0,0 +1,196 @@
+use super::{
+    EddingtonFinkelstein, NearPole0EF, NearPole0RN, NearPolePiEF, NearPolePiRN, ReissnerNordstrom,
+};
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+
+/// A Reissner-Nordström black hole with the mass and charge only known at runtime. It provides
+/// the metric of the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackHole {
+    pub mass: f64,
+    pub charge: f64,
+}
+
+impl BlackHole {
+    pub fn new(mass: f64, charge: f64) -> Self {
+        BlackHole { mass, charge }
+    }
+
+    /// The radius of the outer horizon
+    pub fn horizon_radius(&self) -> f64 {
+        let (m, q) = (self.mass, self.charge);
+        m + (m * m - q * q).sqrt()
+    }
+
+    /// The radius of the inner (Cauchy) horizon
+    pub fn inner_horizon_radius(&self) -> f64 {
+        let (m, q) = (self.mass, self.charge);
+        m - (m * m - q * q).sqrt()
+    }
+}
+
+impl Spacetime<ReissnerNordstrom<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<ReissnerNordstrom<Runtime>>) -> TwoForm<ReissnerNordstrom<Runtime>> {
+        ReissnerNordstrom::g_with(self.mass, self.charge, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<ReissnerNordstrom<Runtime>>,
+    ) -> InvTwoForm<ReissnerNordstrom<Runtime>> {
+        ReissnerNordstrom::inv_g_with(self.mass, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<ReissnerNordstrom<Runtime>>,
+    ) -> Tensor<ReissnerNordstrom<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        ReissnerNordstrom::christoffel_with(self.mass, self.charge, x)
+    }
+}
+
+impl Spacetime<EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> TwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::g_with(self.mass, self.charge, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> InvTwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::inv_g_with(self.mass, self.charge, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Tensor<EddingtonFinkelstein<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        EddingtonFinkelstein::christoffel_with(self.mass, self.charge, x)
+    }
+}
+
+impl Spacetime<NearPole0RN<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0RN<Runtime>>) -> TwoForm<NearPole0RN<Runtime>> {
+        NearPole0RN::g_with(self.mass, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0RN<Runtime>>) -> InvTwoForm<NearPole0RN<Runtime>> {
+        NearPole0RN::inv_g_with(self.mass, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0RN<Runtime>>,
+    ) -> Tensor<NearPole0RN<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0RN::christoffel_with(self.mass, self.charge, p)
+    }
+}
+
+impl Spacetime<NearPolePiRN<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiRN<Runtime>>) -> TwoForm<NearPolePiRN<Runtime>> {
+        NearPolePiRN::g_with(self.mass, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiRN<Runtime>>) -> InvTwoForm<NearPolePiRN<Runtime>> {
+        NearPolePiRN::inv_g_with(self.mass, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiRN<Runtime>>,
+    ) -> Tensor<NearPolePiRN<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiRN::christoffel_with(self.mass, self.charge, p)
+    }
+}
+
+impl Spacetime<NearPole0EF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0EF<Runtime>>) -> TwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::g_with(self.mass, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0EF<Runtime>>) -> InvTwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::inv_g_with(self.mass, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0EF<Runtime>>,
+    ) -> Tensor<NearPole0EF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0EF::christoffel_with(self.mass, self.charge, p)
+    }
+}
+
+impl Spacetime<NearPolePiEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiEF<Runtime>>) -> TwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::g_with(self.mass, self.charge, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiEF<Runtime>>) -> InvTwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::inv_g_with(self.mass, self.charge, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiEF<Runtime>>,
+    ) -> Tensor<NearPolePiEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiEF::christoffel_with(self.mass, self.charge, p)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<ReissnerNordstrom<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<ReissnerNordstrom<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        ReissnerNordstrom::to_ef_point_with(self.mass, self.charge, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<ReissnerNordstrom<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        ReissnerNordstrom::to_ef_jacobian_with(self.mass, self.charge, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, ReissnerNordstrom<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<ReissnerNordstrom<Runtime>> {
+        EddingtonFinkelstein::to_reissner_nordstrom_point_with(self.mass, self.charge, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<ReissnerNordstrom<Runtime>> {
+        EddingtonFinkelstein::to_reissner_nordstrom_jacobian_with(self.mass, self.charge, p)
+    }
+}
+
+// the conversions to and from the polar charts don't depend on the mass
+impl<C1, C2> SpacetimeConversion<C1, C2> for BlackHole
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
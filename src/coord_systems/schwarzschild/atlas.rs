@@ -0,0 +1,39 @@
+use super::{
+    EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF, NearPole0Schw, NearPolePiEF,
+    NearPolePiOutgoingEF, NearPolePiSchw, OutgoingEF, Schwarzschild,
+};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the Schwarzschild chart and its polar patches
+pub struct SchwarzschildAtlas<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M: 'static> Atlas for SchwarzschildAtlas<M> {
+    type Main = Schwarzschild<M>;
+    type Pole0 = NearPole0Schw<M>;
+    type PolePi = NearPolePiSchw<M>;
+}
+
+/// The atlas consisting of the ingoing Eddington-Finkelstein chart and its polar patches
+pub struct EFAtlas<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M: 'static> Atlas for EFAtlas<M> {
+    type Main = EddingtonFinkelstein<M>;
+    type Pole0 = NearPole0EF<M>;
+    type PolePi = NearPolePiEF<M>;
+}
+
+/// The atlas consisting of the outgoing Eddington-Finkelstein chart and its polar patches
+pub struct OutgoingEFAtlas<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M: 'static> Atlas for OutgoingEFAtlas<M> {
+    type Main = OutgoingEF<M>;
+    type Pole0 = NearPole0OutgoingEF<M>;
+    type PolePi = NearPolePiOutgoingEF<M>;
+}
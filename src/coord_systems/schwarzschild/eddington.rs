@@ -1,27 +1,32 @@
-use super::{Mass, NearPole0EF, NearPolePiEF, Schwarzschild};
+use super::painleve::ef_time_shift;
+use super::{
+    KruskalSzekeres, Mass, NearPole0EF, NearPolePiEF, OutgoingEF, PainleveGullstrand, Schwarzschild,
+};
+use crate::conserved::Symmetries;
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-pub struct EddingtonFinkelstein<M: Mass> {
+pub struct EddingtonFinkelstein<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for EddingtonFinkelstein<M> {
+impl<M> CoordinateSystem for EddingtonFinkelstein<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for EddingtonFinkelstein<M> {
-    fn g(x: &Point<Self>) -> TwoForm<Self> {
+impl<M> EddingtonFinkelstein<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         TwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
                 1.0 - 2.0*m/r, -1.0, 0.0, 0.0,
                 -1.0, 0.0, 0.0, 0.0,
@@ -31,31 +36,30 @@ impl<M: Mass> MetricSystem for EddingtonFinkelstein<M> {
         )
     }
 
-    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         InvTwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
-                -1.0 + 2.0*m/r, -1.0, 0.0, 0.0,
-                -1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
                 0.0, 0.0, -1.0/(r*r), 0.0,
                 0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
             ],
         )
     }
 
-    fn christoffel(
+    pub(crate) fn christoffel_with(
+        m: f64,
         x: &Point<Self>,
     ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         let mr = m / r;
         let r2m = r - 2.0 * m;
         Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
-            x.clone(),
+            *x,
             arr![f64;
                 mr/r, 0.0, 0.0, 0.0,
                 0.0, 0.0, 0.0, 0.0,
@@ -79,29 +83,111 @@ impl<M: Mass> MetricSystem for EddingtonFinkelstein<M> {
             ],
         )
     }
+}
+
+impl<M: Mass> MetricSystem for EddingtonFinkelstein<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
 
     // TODO
     //fn dg(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
     //fn covariant_christoffel(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
 }
 
+impl<M> Symmetries for EddingtonFinkelstein<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for EddingtonFinkelstein<M> {
-    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+impl<M> EddingtonFinkelstein<M> {
+    pub(crate) fn to_schwarzschild_point_with(m: f64, p: &Point<Self>) -> Point<Schwarzschild<M>> {
         let u = p[0];
         let r = p[1];
-        let m = M::mass();
         let t = u - r - (0.5 * (r - 2.0 * m) / m).ln() * 2.0 * m;
         Point::new(arr![f64; t, r, p[2], p[3]])
     }
 
-    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+    pub(crate) fn to_schwarzschild_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Schwarzschild<M>> {
         let r = p[1];
-        let m = M::mass();
         let dtdr = -r / (r - 2.0 * m);
         Matrix::new(
-            Self::convert_point(p),
+            Self::to_schwarzschild_point_with(m, p),
+            arr![f64;
+                1.0, dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_kruskal_point_with(m: f64, p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        let (v, r) = (p[0], p[1]);
+        let big_v = (0.25 * v / m).exp();
+        let big_u = -(0.5 * r / m - 1.0) * (0.5 * r / m).exp() / big_v;
+        Point::new(arr![f64; 0.5 * (big_v + big_u), 0.5 * (big_v - big_u), p[2], p[3]])
+    }
+
+    pub(crate) fn to_kruskal_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        KruskalSzekeres::from_ef_jacobian_with(m, &Self::to_kruskal_point_with(m, p))
+    }
+
+    pub(crate) fn to_painleve_point_with(m: f64, p: &Point<Self>) -> Point<PainleveGullstrand<M>> {
+        Point::new(arr![f64; p[0] - ef_time_shift(m, p[1]), p[1], p[2], p[3]])
+    }
+
+    pub(crate) fn to_painleve_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<PainleveGullstrand<M>> {
+        let dtdr = -1.0 / (1.0 + (2.0 * m / p[1]).sqrt());
+        Matrix::new(
+            Self::to_painleve_point_with(m, p),
             arr![f64;
                 1.0, dtdr, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
@@ -111,6 +197,38 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for EddingtonFinkelstein<
         )
     }
 
+    /// Only valid outside of the horizon, r > 2m.
+    pub(crate) fn to_outgoing_point_with(m: f64, p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        let v = p[0];
+        let r = p[1];
+        let u = v - 2.0 * (r + (0.5 * (r - 2.0 * m) / m).ln() * 2.0 * m);
+        Point::new(arr![f64; u, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_outgoing_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        let r = p[1];
+        let dudr = -2.0 * r / (r - 2.0 * m);
+        Matrix::new(
+            Self::to_outgoing_point_with(m, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for EddingtonFinkelstein<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Self::to_schwarzschild_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+        Self::to_schwarzschild_jacobian_with(M::mass(), p)
+    }
+
     fn inv_jacobian(
         p: &Point<Self>,
     ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
@@ -129,7 +247,78 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for EddingtonFinkelstein<
     }
 }
 
-impl<M: Mass + 'static> ConversionTo<NearPole0EF<M>> for EddingtonFinkelstein<M> {
+impl<M: Mass + 'static> ConversionTo<KruskalSzekeres<M>> for EddingtonFinkelstein<M> {
+    fn convert_point(p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        Self::to_kruskal_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        Self::to_kruskal_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        Tensor::<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            x,
+            KruskalSzekeres::to_ef_jacobian_with(M::mass(), &x).coords_array(),
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<PainleveGullstrand<M>> for EddingtonFinkelstein<M> {
+    fn convert_point(p: &Point<Self>) -> Point<PainleveGullstrand<M>> {
+        Self::to_painleve_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<PainleveGullstrand<M>> {
+        Self::to_painleve_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<PainleveGullstrand<M>, (CovariantIndex, ContravariantIndex)> {
+        let dtdr = -1.0 / (1.0 + (2.0 * M::mass() / p[1]).sqrt());
+        Tensor::<PainleveGullstrand<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<OutgoingEF<M>> for EddingtonFinkelstein<M> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        Self::to_outgoing_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        Self::to_outgoing_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let dudr = -2.0 * r / (r - 2.0 * M::mass());
+        Tensor::<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: 'static> ConversionTo<NearPole0EF<M>> for EddingtonFinkelstein<M> {
     fn convert_point(p: &Point<Self>) -> Point<NearPole0EF<M>> {
         let th = p[2];
         let ph = p[3];
@@ -141,14 +330,14 @@ impl<M: Mass + 'static> ConversionTo<NearPole0EF<M>> for EddingtonFinkelstein<M>
     fn jacobian(p: &Point<Self>) -> Matrix<NearPole0EF<M>> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -158,20 +347,20 @@ impl<M: Mass + 'static> ConversionTo<NearPole0EF<M>> for EddingtonFinkelstein<M>
     ) -> Tensor<NearPole0EF<M>, (CovariantIndex, ContravariantIndex)> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPole0EF<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
 }
 
-impl<M: Mass + 'static> ConversionTo<NearPolePiEF<M>> for EddingtonFinkelstein<M> {
+impl<M: 'static> ConversionTo<NearPolePiEF<M>> for EddingtonFinkelstein<M> {
     fn convert_point(p: &Point<Self>) -> Point<NearPolePiEF<M>> {
         let th = p[2];
         let ph = p[3];
@@ -181,16 +370,16 @@ impl<M: Mass + 'static> ConversionTo<NearPolePiEF<M>> for EddingtonFinkelstein<M
     }
 
     fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiEF<M>> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -198,16 +387,16 @@ impl<M: Mass + 'static> ConversionTo<NearPolePiEF<M>> for EddingtonFinkelstein<M
     fn inv_jacobian(
         p: &Point<Self>,
     ) -> Tensor<NearPolePiEF<M>, (CovariantIndex, ContravariantIndex)> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPolePiEF<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
@@ -0,0 +1,187 @@
+use super::{Mass, Schwarzschild};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The harmonic coordinates (t, R, theta, phi), with R = r - m. The Cartesian coordinates built
+/// from R and the angles satisfy the harmonic gauge condition, like the ones of the
+/// post-Newtonian ephemerides; the horizon is at R = m.
+pub struct Harmonic<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for Harmonic<M> {
+    type Dimension = U4;
+}
+
+impl<M> Harmonic<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let coeff = (r - m) / (r + m);
+        let rm2 = (r + m) * (r + m);
+        TwoForm::new(
+            *x,
+            arr![f64;
+                coeff, 0.0, 0.0, 0.0,
+                0.0, -1.0/coeff, 0.0, 0.0,
+                0.0, 0.0, -rm2, 0.0,
+                0.0, 0.0, 0.0, -rm2*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let coeff = (r - m) / (r + m);
+        let rm2 = (r + m) * (r + m);
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0 / coeff, 0.0, 0.0, 0.0,
+                0.0, -coeff, 0.0, 0.0,
+                0.0, 0.0, -1.0/rm2, 0.0,
+                0.0, 0.0, 0.0, -1.0/(rm2*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let mr = m / (r * r - m * m);
+        let rm = r - m;
+        let rp = r + m;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, mr, 0.0, 0.0,
+                mr, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                m*rm/rp/rp/rp, 0.0, 0.0, 0.0,
+                0.0, -mr, 0.0, 0.0,
+                0.0, 0.0, -rm, 0.0,
+                0.0, 0.0, 0.0, -rm*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/rp, 0.0,
+                0.0, 1.0/rp, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/rp,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/rp, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for Harmonic<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+impl<M> Symmetries for Harmonic<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<M> Harmonic<M> {
+    pub(crate) fn to_schwarzschild_point_with(m: f64, p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Point::new(arr![f64; p[0], p[1] + m, p[2], p[3]])
+    }
+
+    pub(crate) fn to_schwarzschild_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Schwarzschild<M>> {
+        Matrix::new(
+            Self::to_schwarzschild_point_with(m, p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for Harmonic<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Self::to_schwarzschild_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+        Self::to_schwarzschild_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
+        Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,201 @@
+use super::{Mass, Schwarzschild};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The isotropic coordinates (t, rho, theta, phi), in which the spatial metric is conformally
+/// flat. The Schwarzschild radius is r = rho (1 + m/2rho)^2; rho > m/2 covers the exterior and
+/// rho < m/2 the second exterior of the Einstein-Rosen bridge, with the throat at rho = m/2.
+pub struct Isotropic<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for Isotropic<M> {
+    type Dimension = U4;
+}
+
+impl<M> Isotropic<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let rho = x[1];
+        let th = x[2];
+        let k = 0.5 * m / rho;
+        let lapse = (1.0 - k) / (1.0 + k);
+        let psi4 = (1.0 + k).powi(4);
+        TwoForm::new(
+            *x,
+            arr![f64;
+                lapse*lapse, 0.0, 0.0, 0.0,
+                0.0, -psi4, 0.0, 0.0,
+                0.0, 0.0, -psi4*rho*rho, 0.0,
+                0.0, 0.0, 0.0, -psi4*rho*rho*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let rho = x[1];
+        let th = x[2];
+        let k = 0.5 * m / rho;
+        let lapse = (1.0 - k) / (1.0 + k);
+        let psi4 = (1.0 + k).powi(4);
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0/(lapse*lapse), 0.0, 0.0, 0.0,
+                0.0, -1.0/psi4, 0.0, 0.0,
+                0.0, 0.0, -1.0/(psi4*rho*rho), 0.0,
+                0.0, 0.0, 0.0, -1.0/(psi4*rho*rho*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let rho = x[1];
+        let th = x[2];
+        let k = 0.5 * m / rho;
+        // the ratio g_tt / g_rhorho
+        let ratio = (1.0 - k) * (1.0 - k) / (1.0 + k).powi(6);
+        // the derivatives of the logarithms of the lapse, of the conformal factor (1 + k)^2 and of
+        // the radius of the spheres rho (1 + k)^2
+        let dlapse = 2.0 * k / rho / (1.0 - k * k);
+        let dpsi = -2.0 * k / rho / (1.0 + k);
+        let dsphere = (1.0 - k) / rho / (1.0 + k);
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, dlapse, 0.0, 0.0,
+                dlapse, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                dlapse*ratio, 0.0, 0.0, 0.0,
+                0.0, dpsi, 0.0, 0.0,
+                0.0, 0.0, -dsphere*rho*rho, 0.0,
+                0.0, 0.0, 0.0, -dsphere*rho*rho*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, dsphere, 0.0,
+                0.0, dsphere, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, dsphere,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, dsphere, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for Isotropic<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+impl<M> Symmetries for Isotropic<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+fn schwarzschild_radius(m: f64, rho: f64) -> f64 {
+    let k = 0.5 * m / rho;
+    rho * (1.0 + k) * (1.0 + k)
+}
+
+// Conversions
+
+impl<M> Isotropic<M> {
+    pub(crate) fn to_schwarzschild_point_with(m: f64, p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Point::new(arr![f64; p[0], schwarzschild_radius(m, p[1]), p[2], p[3]])
+    }
+
+    pub(crate) fn to_schwarzschild_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Schwarzschild<M>> {
+        let k = 0.5 * m / p[1];
+        Matrix::new(
+            Self::to_schwarzschild_point_with(m, p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0 - k * k, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for Isotropic<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Self::to_schwarzschild_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+        Self::to_schwarzschild_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
+        let k = 0.5 * M::mass() / p[1];
+        Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0 / (1.0 - k * k), 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
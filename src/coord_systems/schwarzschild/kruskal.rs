@@ -0,0 +1,399 @@
+use super::{EddingtonFinkelstein, Mass, OutgoingEF, Penrose, Schwarzschild};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use generic_array::arr;
+use std::f64::consts::E;
+use std::marker::PhantomData;
+
+/// The Kruskal-Szekeres coordinates (T, X, theta, phi) covering the maximally extended
+/// Schwarzschild spacetime: the exterior (X > |T|), the black hole (T > |X|), the second exterior
+/// (X < -|T|) and the white hole (T < -|X|). The singularity is at X^2 - T^2 = -1.
+pub struct KruskalSzekeres<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for KruskalSzekeres<M> {
+    type Dimension = U4;
+}
+
+// the principal branch of the Lambert W function, w * exp(w) = x, for x >= -1/e
+fn lambert_w(x: f64) -> f64 {
+    let mut w = if x < -0.25 {
+        // the expansion around the branch point
+        let p = (2.0 * (E * x + 1.0)).max(0.0).sqrt();
+        if p < 1e-8 {
+            return -1.0 + p;
+        }
+        -1.0 + p - p * p / 3.0
+    } else if x > E {
+        x.ln() - x.ln().ln()
+    } else {
+        (1.0 + x).ln()
+    };
+    // Halley's method
+    for _ in 0..50 {
+        let ew = w.exp();
+        let f = w * ew - x;
+        let next = w - f / (ew * (w + 1.0) - (w + 2.0) * f / (2.0 * w + 2.0));
+        let done = (next - w).abs() <= 1e-15 * (1.0 + next.abs());
+        w = next;
+        if done {
+            break;
+        }
+    }
+    w
+}
+
+/// The Schwarzschild radius r at which (r/2m - 1) exp(r/2m) = w, where w = X^2 - T^2 in the
+/// Kruskal-Szekeres coordinates.
+pub(crate) fn radius_with(m: f64, w: f64) -> f64 {
+    2.0 * m * (1.0 + lambert_w(w / E))
+}
+
+// dr/dw = q/2, where q = 8m^2 exp(-r/2m) / r; the conformal factor of the (T, X) plane is 4mq
+fn dr_factor(m: f64, r: f64) -> f64 {
+    8.0 * m * m * (-0.5 * r / m).exp() / r
+}
+
+// The metric G (dt^2 - dx^2) - r^2 dOmega^2, with the conformal factor G and the radius r
+// depending on the first two coordinates, shared with the Penrose chart
+pub(crate) fn conformal_g<C>(x: &Point<C>, conformal: f64, r: f64) -> TwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let sin_th = x[2].sin();
+    TwoForm::new(
+        *x,
+        arr![f64;
+            conformal, 0.0, 0.0, 0.0,
+            0.0, -conformal, 0.0, 0.0,
+            0.0, 0.0, -r*r, 0.0,
+            0.0, 0.0, 0.0, -r*r*sin_th*sin_th
+        ],
+    )
+}
+
+pub(crate) fn conformal_inv_g<C>(x: &Point<C>, conformal: f64, r: f64) -> InvTwoForm<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let sin_th = x[2].sin();
+    InvTwoForm::new(
+        *x,
+        arr![f64;
+            1.0/conformal, 0.0, 0.0, 0.0,
+            0.0, -1.0/conformal, 0.0, 0.0,
+            0.0, 0.0, -1.0/(r*r), 0.0,
+            0.0, 0.0, 0.0, -1.0/(r*r*sin_th*sin_th)
+        ],
+    )
+}
+
+// `d` are the derivatives of ln(G)/2 and `dr` the derivatives of r with respect to the first two
+// coordinates
+pub(crate) fn conformal_christoffel<C>(
+    x: &Point<C>,
+    conformal: f64,
+    d: [f64; 2],
+    r: f64,
+    dr: [f64; 2],
+) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let (sin_th, cos_th) = x[2].sin_cos();
+    let s2 = sin_th * sin_th;
+    let gt = r * dr[0] / conformal;
+    let gx = -r * dr[1] / conformal;
+    let (rt, rx) = (dr[0] / r, dr[1] / r);
+    Tensor::<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+        *x,
+        arr![f64;
+            d[0], d[1], 0.0, 0.0,
+            d[1], d[0], 0.0, 0.0,
+            0.0, 0.0, gt, 0.0,
+            0.0, 0.0, 0.0, gt*s2,
+
+            d[1], d[0], 0.0, 0.0,
+            d[0], d[1], 0.0, 0.0,
+            0.0, 0.0, gx, 0.0,
+            0.0, 0.0, 0.0, gx*s2,
+
+            0.0, 0.0, rt, 0.0,
+            0.0, 0.0, rx, 0.0,
+            rt, rx, 0.0, 0.0,
+            0.0, 0.0, 0.0, -sin_th*cos_th,
+
+            0.0, 0.0, 0.0, rt,
+            0.0, 0.0, 0.0, rx,
+            0.0, 0.0, 0.0, cos_th/sin_th,
+            rt, rx, cos_th/sin_th, 0.0
+        ],
+    )
+}
+
+impl<M> KruskalSzekeres<M> {
+    pub(crate) fn radius_with(m: f64, x: &Point<Self>) -> f64 {
+        radius_with(m, x[1] * x[1] - x[0] * x[0])
+    }
+
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = Self::radius_with(m, x);
+        conformal_g(x, 4.0 * m * dr_factor(m, r), r)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = Self::radius_with(m, x);
+        conformal_inv_g(x, 4.0 * m * dr_factor(m, r), r)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = Self::radius_with(m, x);
+        let q = dr_factor(m, r);
+        let dr = [-x[0] * q, x[1] * q];
+        // G = 32m^3 exp(-r/2m) / r, so d ln(G) / dr = -(r + 2m) / (2mr)
+        let s = -0.25 * (r + 2.0 * m) / (m * r);
+        conformal_christoffel(x, 4.0 * m * q, [s * dr[0], s * dr[1]], r, dr)
+    }
+}
+
+impl<M: Mass> MetricSystem for KruskalSzekeres<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+// Conversions
+
+impl<M> KruskalSzekeres<M> {
+    /// Valid everywhere except the horizons, X = ±T. The exterior regions are mapped to r > 2m,
+    /// the black and white holes to r < 2m.
+    pub(crate) fn to_schwarzschild_point_with(m: f64, p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        let (tk, xk) = (p[0], p[1]);
+        let r = Self::radius_with(m, p);
+        let t = if xk.abs() > tk.abs() {
+            4.0 * m * (tk / xk).atanh()
+        } else {
+            4.0 * m * (xk / tk).atanh()
+        };
+        Point::new(arr![f64; t, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_schwarzschild_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Schwarzschild<M>> {
+        let (tk, xk) = (p[0], p[1]);
+        let w = xk * xk - tk * tk;
+        let q = dr_factor(m, Self::radius_with(m, p));
+        Matrix::new(
+            Self::to_schwarzschild_point_with(m, p),
+            arr![f64;
+                4.0 * m * xk / w, -4.0 * m * tk / w, 0.0, 0.0,
+                -tk * q, xk * q, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    // the jacobian d(T, X)/d(t, r) of the conversion from the Schwarzschild chart, evaluated at a
+    // point of this chart; unlike the conversion itself, it's valid in all four regions
+    pub(crate) fn from_schwarzschild_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<Self> {
+        let (tk, xk) = (p[0], p[1]);
+        let w = xk * xk - tk * tk;
+        let qw = dr_factor(m, Self::radius_with(m, p)) * w;
+        Matrix::new(
+            *p,
+            arr![f64;
+                0.25 * xk / m, tk / qw, 0.0, 0.0,
+                0.25 * tk / m, xk / qw, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    /// Valid in the exterior and the black hole, T + X > 0.
+    pub(crate) fn to_ef_point_with(m: f64, p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        let v = 4.0 * m * (p[0] + p[1]).ln();
+        let r = Self::radius_with(m, p);
+        Point::new(arr![f64; v, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        let (tk, xk) = (p[0], p[1]);
+        let dvdx = 4.0 * m / (tk + xk);
+        let q = dr_factor(m, Self::radius_with(m, p));
+        Matrix::new(
+            Self::to_ef_point_with(m, p),
+            arr![f64;
+                dvdx, dvdx, 0.0, 0.0,
+                -tk * q, xk * q, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    // the jacobian d(T, X)/d(v, r) of the conversion from the Eddington-Finkelstein chart, evaluated
+    // at a point of this chart
+    pub(crate) fn from_ef_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<Self> {
+        let (tk, xk) = (p[0], p[1]);
+        let qv = dr_factor(m, Self::radius_with(m, p)) * (tk + xk);
+        Matrix::new(
+            *p,
+            arr![f64;
+                0.25 * xk / m, -1.0 / qv, 0.0, 0.0,
+                0.25 * tk / m, 1.0 / qv, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    /// Valid in the exterior and the white hole, X - T > 0.
+    pub(crate) fn to_outgoing_point_with(m: f64, p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        let u = -4.0 * m * (p[1] - p[0]).ln();
+        let r = Self::radius_with(m, p);
+        Point::new(arr![f64; u, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_outgoing_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        let (tk, xk) = (p[0], p[1]);
+        let dudt = 4.0 * m / (xk - tk);
+        let q = dr_factor(m, Self::radius_with(m, p));
+        Matrix::new(
+            Self::to_outgoing_point_with(m, p),
+            arr![f64;
+                dudt, -dudt, 0.0, 0.0,
+                -tk * q, xk * q, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    // the jacobian d(T, X)/d(u, r) of the conversion from the outgoing Eddington-Finkelstein
+    // chart, evaluated at a point of this chart
+    pub(crate) fn from_outgoing_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<Self> {
+        let (tk, xk) = (p[0], p[1]);
+        let qu = dr_factor(m, Self::radius_with(m, p)) * (xk - tk);
+        Matrix::new(
+            *p,
+            arr![f64;
+                0.25 * xk / m, 1.0 / qu, 0.0, 0.0,
+                0.25 * tk / m, 1.0 / qu, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for KruskalSzekeres<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Self::to_schwarzschild_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+        Self::to_schwarzschild_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
+        Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            Self::convert_point(p),
+            Self::from_schwarzschild_jacobian_with(M::mass(), p).coords_array(),
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for KruskalSzekeres<M> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        Self::to_ef_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        Self::to_ef_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
+        Tensor::<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            Self::convert_point(p),
+            Self::from_ef_jacobian_with(M::mass(), p).coords_array(),
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<OutgoingEF<M>> for KruskalSzekeres<M> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        Self::to_outgoing_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        Self::to_outgoing_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        Tensor::<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            Self::convert_point(p),
+            Self::from_outgoing_jacobian_with(M::mass(), p).coords_array(),
+        )
+    }
+}
+
+impl<M: 'static> ConversionTo<Penrose<M>> for KruskalSzekeres<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Penrose<M>> {
+        let (u, v) = ((p[0] - p[1]).atan(), (p[0] + p[1]).atan());
+        Point::new(arr![f64; 0.5 * (v + u), 0.5 * (v - u), p[2], p[3]])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Penrose<M>> {
+        let (big_u, big_v) = (p[0] - p[1], p[0] + p[1]);
+        let (du, dv) = (1.0 / (1.0 + big_u * big_u), 1.0 / (1.0 + big_v * big_v));
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                0.5 * (dv + du), 0.5 * (dv - du), 0.0, 0.0,
+                0.5 * (dv - du), 0.5 * (dv + du), 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Penrose<M>, (CovariantIndex, ContravariantIndex)> {
+        let (big_u, big_v) = (p[0] - p[1], p[0] + p[1]);
+        let (sec2u, sec2v) = (1.0 + big_u * big_u, 1.0 + big_v * big_v);
+        Tensor::<Penrose<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                0.5 * (sec2v + sec2u), 0.5 * (sec2v - sec2u), 0.0, 0.0,
+                0.5 * (sec2v - sec2u), 0.5 * (sec2v + sec2u), 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
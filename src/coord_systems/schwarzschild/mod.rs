@@ -1,13 +1,32 @@
+mod atlas;
 mod eddington;
+mod harmonic;
+mod isotropic;
+mod kruskal;
+mod outgoing;
+mod painleve;
+mod penrose;
 mod polar_eddington;
+mod polar_outgoing;
 mod polar_schwarzschild;
+mod runtime;
+#[allow(clippy::module_inception)]
 mod schwarzschild;
 
 pub trait Mass {
     fn mass() -> f64;
 }
 
+pub use self::atlas::{EFAtlas, OutgoingEFAtlas, SchwarzschildAtlas};
 pub use self::eddington::EddingtonFinkelstein;
+pub use self::harmonic::Harmonic;
+pub use self::isotropic::Isotropic;
+pub use self::kruskal::KruskalSzekeres;
+pub use self::outgoing::OutgoingEF;
+pub use self::painleve::PainleveGullstrand;
+pub use self::penrose::Penrose;
 pub use self::polar_eddington::{NearPole0EF, NearPolePiEF};
+pub use self::polar_outgoing::{NearPole0OutgoingEF, NearPolePiOutgoingEF};
 pub use self::polar_schwarzschild::{NearPole0Schw, NearPolePiSchw};
+pub use self::runtime::BlackHole;
 pub use self::schwarzschild::Schwarzschild;
@@ -0,0 +1,358 @@
+use super::{
+    EddingtonFinkelstein, KruskalSzekeres, Mass, NearPole0OutgoingEF, NearPolePiOutgoingEF,
+    Schwarzschild,
+};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The outgoing Eddington-Finkelstein coordinates (u, r, theta, phi), with the retarded time
+/// u = t - r - 2m ln(r/2m - 1). The chart covers the exterior and the white hole, and is regular
+/// across the past horizon, so that outgoing light rays are the lines of constant u.
+pub struct OutgoingEF<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for OutgoingEF<M> {
+    type Dimension = U4;
+}
+
+impl<M> OutgoingEF<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - 2.0*m/r, 1.0, 0.0, 0.0,
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                0.0, 1.0, 0.0, 0.0,
+                1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                -mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, r, 0.0,
+                0.0, 0.0, 0.0, r*th.sin()*th.sin(),
+
+                m*r2m/r/r/r, mr/r, 0.0, 0.0,
+                mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r2m, 0.0,
+                0.0, 0.0, 0.0, -r2m*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for OutgoingEF<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+impl<M> Symmetries for OutgoingEF<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<M> OutgoingEF<M> {
+    /// Maps either side of the horizon to the corresponding region of the Schwarzschild chart.
+    pub(crate) fn to_schwarzschild_point_with(m: f64, p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        let u = p[0];
+        let r = p[1];
+        let t = u + r + (0.5 * (r - 2.0 * m) / m).abs().ln() * 2.0 * m;
+        Point::new(arr![f64; t, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_schwarzschild_jacobian_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Matrix<Schwarzschild<M>> {
+        let r = p[1];
+        let dtdr = r / (r - 2.0 * m);
+        Matrix::new(
+            Self::to_schwarzschild_point_with(m, p),
+            arr![f64;
+                1.0, dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    /// Only valid outside of the horizon, r > 2m.
+    pub(crate) fn to_ef_point_with(m: f64, p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        let u = p[0];
+        let r = p[1];
+        let v = u + 2.0 * (r + (0.5 * (r - 2.0 * m) / m).ln() * 2.0 * m);
+        Point::new(arr![f64; v, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        let r = p[1];
+        let dvdr = 2.0 * r / (r - 2.0 * m);
+        Matrix::new(
+            Self::to_ef_point_with(m, p),
+            arr![f64;
+                1.0, dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_kruskal_point_with(m: f64, p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        let (u, r) = (p[0], p[1]);
+        let big_u = -(-0.25 * u / m).exp();
+        let big_v = -(0.5 * r / m - 1.0) * (0.5 * r / m).exp() / big_u;
+        Point::new(arr![f64; 0.5 * (big_v + big_u), 0.5 * (big_v - big_u), p[2], p[3]])
+    }
+
+    pub(crate) fn to_kruskal_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        KruskalSzekeres::from_outgoing_jacobian_with(m, &Self::to_kruskal_point_with(m, p))
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
+        Self::to_schwarzschild_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
+        Self::to_schwarzschild_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let m = M::mass();
+        let dtdr = r / (r - 2.0 * m);
+        Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dtdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        Self::to_ef_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        Self::to_ef_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let dvdr = 2.0 * r / (r - 2.0 * M::mass());
+        Tensor::<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<KruskalSzekeres<M>> for OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        Self::to_kruskal_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        Self::to_kruskal_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        Tensor::<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            x,
+            KruskalSzekeres::to_outgoing_jacobian_with(M::mass(), &x).coords_array(),
+        )
+    }
+}
+
+impl<M: 'static> ConversionTo<NearPole0OutgoingEF<M>> for OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPole0OutgoingEF<M>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = (th / 2.0).tan() * ph.cos();
+        let y = (th / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPole0OutgoingEF<M>> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPole0OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = p[2] / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPole0OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
+
+impl<M: 'static> ConversionTo<NearPolePiOutgoingEF<M>> for OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<NearPolePiOutgoingEF<M>> {
+        let th = p[2];
+        let ph = p[3];
+        let x = ((PI - th) / 2.0).tan() * ph.cos();
+        let y = ((PI - th) / 2.0).tan() * ph.sin();
+        Point::new(arr![f64; p[0], p[1], x, y])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiOutgoingEF<M>> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let cos2t2 = t2.cos() * t2.cos();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<NearPolePiOutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let t2 = (PI - p[2]) / 2.0;
+        let ph = p[3];
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        Tensor::<NearPolePiOutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+            ],
+        )
+    }
+}
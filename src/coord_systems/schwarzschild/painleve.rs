@@ -0,0 +1,189 @@
+use super::{EddingtonFinkelstein, Mass};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Painlevé-Gullstrand coordinates (T, r, theta, phi). T is the proper time of observers
+/// falling radially from rest at infinity and the surfaces of constant T are flat; the chart
+/// covers the exterior and the black hole.
+pub struct PainleveGullstrand<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for PainleveGullstrand<M> {
+    type Dimension = U4;
+}
+
+impl<M> PainleveGullstrand<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let b = (2.0 * m / r).sqrt();
+        TwoForm::new(
+            *x,
+            arr![f64;
+                1.0 - 2.0*m/r, -b, 0.0, 0.0,
+                -b, -1.0, 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        let b = (2.0 * m / r).sqrt();
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                1.0, -b, 0.0, 0.0,
+                -b, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let b = (2.0 * m / r).sqrt();
+        let mr2 = m / r / r;
+        let r2m = r - 2.0 * m;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                b*mr2, mr2, 0.0, 0.0,
+                mr2, 0.5*b/r, 0.0, 0.0,
+                0.0, 0.0, -b*r, 0.0,
+                0.0, 0.0, 0.0, -b*r*th.sin()*th.sin(),
+
+                mr2*r2m/r, -b*mr2, 0.0, 0.0,
+                -b*mr2, -mr2, 0.0, 0.0,
+                0.0, 0.0, -r2m, 0.0,
+                0.0, 0.0, 0.0, -r2m*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for PainleveGullstrand<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+impl<M> Symmetries for PainleveGullstrand<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
+// Conversions
+
+// v - T, where v is the Eddington-Finkelstein time
+pub(crate) fn ef_time_shift(m: f64, r: f64) -> f64 {
+    r - 2.0 * (2.0 * m * r).sqrt() + 4.0 * m * (1.0 + (0.5 * r / m).sqrt()).ln()
+}
+
+impl<M> PainleveGullstrand<M> {
+    pub(crate) fn to_ef_point_with(m: f64, p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        Point::new(arr![f64; p[0] + ef_time_shift(m, p[1]), p[1], p[2], p[3]])
+    }
+
+    pub(crate) fn to_ef_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        let dvdr = 1.0 / (1.0 + (2.0 * m / p[1]).sqrt());
+        Matrix::new(
+            Self::to_ef_point_with(m, p),
+            arr![f64;
+                1.0, dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for PainleveGullstrand<M> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        Self::to_ef_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        Self::to_ef_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
+        let dvdr = 1.0 / (1.0 + (2.0 * M::mass() / p[1]).sqrt());
+        Tensor::<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dvdr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use super::kruskal::{conformal_christoffel, conformal_g, conformal_inv_g, radius_with};
+use super::{KruskalSzekeres, Mass};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The compactified Kruskal-Szekeres coordinates (tau, chi, theta, phi), with
+/// tau - chi = arctan(T - X) and tau + chi = arctan(T + X), mapping the whole extended spacetime
+/// to a bounded region of a conformal diagram. The singularities are at tau = ±pi/4.
+///
+/// The conversions to the other charts go through `KruskalSzekeres`.
+pub struct Penrose<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for Penrose<M> {
+    type Dimension = U4;
+}
+
+impl<M> Penrose<M> {
+    // the conformal factor of the (tau, chi) plane and the radius
+    fn conformal_with(m: f64, x: &Point<Self>) -> (f64, f64) {
+        let (tan_u, tan_v) = ((x[0] - x[1]).tan(), (x[0] + x[1]).tan());
+        let r = radius_with(m, -tan_u * tan_v);
+        let conformal = 32.0 * m * m * m * (-0.5 * r / m).exp() / r
+            * (1.0 + tan_u * tan_u)
+            * (1.0 + tan_v * tan_v);
+        (conformal, r)
+    }
+
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let (conformal, r) = Self::conformal_with(m, x);
+        conformal_g(x, conformal, r)
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let (conformal, r) = Self::conformal_with(m, x);
+        conformal_inv_g(x, conformal, r)
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (tan_u, tan_v) = ((x[0] - x[1]).tan(), (x[0] + x[1]).tan());
+        let (conformal, r) = Self::conformal_with(m, x);
+        let q = 8.0 * m * m * (-0.5 * r / m).exp() / r;
+        // the derivatives of r with respect to u = tau - chi and v = tau + chi
+        let dru = -0.5 * q * tan_v * (1.0 + tan_u * tan_u);
+        let drv = -0.5 * q * tan_u * (1.0 + tan_v * tan_v);
+        let dr = [dru + drv, drv - dru];
+        let s = -0.25 * (r + 2.0 * m) / (m * r);
+        let d = [s * dr[0] + tan_u + tan_v, s * dr[1] + tan_v - tan_u];
+        conformal_christoffel(x, conformal, d, r, dr)
+    }
+}
+
+impl<M: Mass> MetricSystem for Penrose<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
+}
+
+// Conversions
+
+impl<M: 'static> ConversionTo<KruskalSzekeres<M>> for Penrose<M> {
+    fn convert_point(p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        let (tan_u, tan_v) = ((p[0] - p[1]).tan(), (p[0] + p[1]).tan());
+        Point::new(arr![f64; 0.5 * (tan_v + tan_u), 0.5 * (tan_v - tan_u), p[2], p[3]])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        let (tan_u, tan_v) = ((p[0] - p[1]).tan(), (p[0] + p[1]).tan());
+        let (sec2u, sec2v) = (1.0 + tan_u * tan_u, 1.0 + tan_v * tan_v);
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                0.5 * (sec2v + sec2u), 0.5 * (sec2v - sec2u), 0.0, 0.0,
+                0.5 * (sec2v - sec2u), 0.5 * (sec2v + sec2u), 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)> {
+        let (cos_u, cos_v) = ((p[0] - p[1]).cos(), (p[0] + p[1]).cos());
+        let (cos2u, cos2v) = (cos_u * cos_u, cos_v * cos_v);
+        Tensor::<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                0.5 * (cos2v + cos2u), 0.5 * (cos2v - cos2u), 0.0, 0.0,
+                0.5 * (cos2v - cos2u), 0.5 * (cos2v + cos2u), 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
@@ -1,31 +1,33 @@
 use super::{EddingtonFinkelstein, Mass};
+use crate::conserved::Symmetries;
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-/// The coordinate system near the pole theta=pi
-pub struct NearPole0EF<M: Mass> {
+/// The coordinate system near the pole theta=0
+pub struct NearPole0EF<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for NearPole0EF<M> {
+impl<M> CoordinateSystem for NearPole0EF<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for NearPole0EF<M> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<M> NearPole0EF<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 1.0 - 2.0*m/r, -1.0, 0.0, 0.0,
                 -1.0, 0.0, 0.0, 0.0,
@@ -35,28 +37,121 @@ impl<M: Mass> MetricSystem for NearPole0EF<M> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
-                -1.0 + 2.0*m/r, -1.0, 0.0, 0.0,
-                -1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
                 0.0, 0.0, -1.0/alpha2/r/r, 0.0,
                 0.0, 0.0, 0.0, -1.0/alpha2/r/r
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*alpha2,
+
+                m*r2m/r/r/r, -mr/r, 0.0, 0.0,
+                -mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPole0EF<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPole0EF<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
 }
 
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPole0EF<M> {
+impl<M: 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPole0EF<M> {
     fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
         let x = p[2];
         let y = p[3];
@@ -68,14 +163,14 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPole0EF<M>
     fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, -y,
-                0.0, 0.0, y * coeff, x,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -85,38 +180,37 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPole0EF<M>
     ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
             ],
         )
     }
 }
 
 /// The coordinate system near the pole theta=pi
-pub struct NearPolePiEF<M: Mass> {
+pub struct NearPolePiEF<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for NearPolePiEF<M> {
+impl<M> CoordinateSystem for NearPolePiEF<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for NearPolePiEF<M> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<M> NearPolePiEF<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 1.0 - 2.0*m/r, -1.0, 0.0, 0.0,
                 -1.0, 0.0, 0.0, 0.0,
@@ -126,28 +220,124 @@ impl<M: Mass> MetricSystem for NearPolePiEF<M> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
-                -1.0 + 2.0*m/r, -1.0, 0.0, 0.0,
-                -1.0, 0.0, 0.0, 0.0,
+                0.0, -1.0, 0.0, 0.0,
+                -1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
                 0.0, 0.0, -1.0/alpha2/r/r, 0.0,
                 0.0, 0.0, 0.0, -1.0/alpha2/r/r
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r*alpha2,
+
+                m*r2m/r/r/r, -mr/r, 0.0, 0.0,
+                -mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPolePiEF<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPolePiEF<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
 }
 
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPolePiEF<M> {
+impl<M: 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPolePiEF<M> {
     fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
         let x = p[2];
         let y = p[3];
@@ -159,14 +349,14 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPolePiEF<M
     fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y,
-                0.0, 0.0, -y * coeff, x,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -176,14 +366,14 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for NearPolePiEF<M
     ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
             ],
         )
     }
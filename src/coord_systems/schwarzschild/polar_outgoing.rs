@@ -0,0 +1,380 @@
+use super::{Mass, OutgoingEF};
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+/// The coordinate system near the pole theta=0
+pub struct NearPole0OutgoingEF<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for NearPole0OutgoingEF<M> {
+    type Dimension = U4;
+}
+
+impl<M> NearPole0OutgoingEF<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        TwoForm::new(
+            *p,
+            arr![f64;
+                1.0 - 2.0*m/r, 1.0, 0.0, 0.0,
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -alpha2*r*r, 0.0,
+                0.0, 0.0, 0.0, -alpha2*r*r
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        InvTwoForm::new(
+            *p,
+            arr![f64;
+                0.0, 1.0, 0.0, 0.0,
+                1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/alpha2/r/r, 0.0,
+                0.0, 0.0, 0.0, -1.0/alpha2/r/r
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                -mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, r*alpha2, 0.0,
+                0.0, 0.0, 0.0, r*alpha2,
+
+                m*r2m/r/r/r, mr/r, 0.0, 0.0,
+                mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPole0OutgoingEF<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPole0OutgoingEF<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<M: 'static> ConversionTo<OutgoingEF<M>> for NearPole0OutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        let x = p[2];
+        let y = p[3];
+        let th = (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
+            ],
+        )
+    }
+}
+
+/// The coordinate system near the pole theta=pi
+pub struct NearPolePiOutgoingEF<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> CoordinateSystem for NearPolePiOutgoingEF<M> {
+    type Dimension = U4;
+}
+
+impl<M> NearPolePiOutgoingEF<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        TwoForm::new(
+            *p,
+            arr![f64;
+                1.0 - 2.0*m/r, 1.0, 0.0, 0.0,
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, -alpha2*r*r, 0.0,
+                0.0, 0.0, 0.0, -alpha2*r*r
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        InvTwoForm::new(
+            *p,
+            arr![f64;
+                0.0, 1.0, 0.0, 0.0,
+                1.0, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/alpha2/r/r, 0.0,
+                0.0, 0.0, 0.0, -1.0/alpha2/r/r
+            ],
+        )
+    }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                -mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, r*alpha2, 0.0,
+                0.0, 0.0, 0.0, r*alpha2,
+
+                m*r2m/r/r/r, mr/r, 0.0, 0.0,
+                mr/r, 0.0, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPolePiOutgoingEF<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPolePiOutgoingEF<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
+}
+
+// Conversions
+
+impl<M: 'static> ConversionTo<OutgoingEF<M>> for NearPolePiOutgoingEF<M> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        let x = p[2];
+        let y = p[3];
+        let th = PI - (x * x + y * y).sqrt().atan() * 2.0;
+        let ph = y.atan2(x);
+        Point::new(arr![f64; p[0], p[1], th, ph])
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        Matrix::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+            ],
+        )
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = p[2];
+        let y = p[3];
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        Tensor::<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
+            ],
+        )
+    }
+}
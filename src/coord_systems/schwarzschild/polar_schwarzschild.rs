@@ -1,32 +1,34 @@
 use super::{Mass, Schwarzschild};
+use crate::conserved::Symmetries;
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-/// The coordinate system near the pole theta=pi
-pub struct NearPole0Schw<M: Mass> {
+/// The coordinate system near the pole theta=0
+pub struct NearPole0Schw<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for NearPole0Schw<M> {
+impl<M> CoordinateSystem for NearPole0Schw<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for NearPole0Schw<M> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<M> NearPole0Schw<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 coeff, 0.0, 0.0, 0.0,
                 0.0, -1.0 / coeff, 0.0, 0.0,
@@ -36,16 +38,15 @@ impl<M: Mass> MetricSystem for NearPole0Schw<M> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 1.0 / coeff, 0.0, 0.0, 0.0,
                 0.0, -coeff, 0.0, 0.0,
@@ -54,11 +55,105 @@ impl<M: Mass> MetricSystem for NearPole0Schw<M> {
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                0.0, mr/r2m, 0.0, 0.0,
+                mr/r2m, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                mr*r2m/r/r, 0.0, 0.0, 0.0,
+                0.0, -mr/r2m, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPole0Schw<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPole0Schw<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        (1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, coeff * ph.cos(), coeff * ph.sin()]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
 }
 
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPole0Schw<M> {
+impl<M: 'static> ConversionTo<Schwarzschild<M>> for NearPole0Schw<M> {
     fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
         let x = p[2];
         let y = p[3];
@@ -70,14 +165,14 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPole0Schw<M> {
     fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, -y,
-                0.0, 0.0, y * coeff, x,
+                0.0, 0.0, x * coeff, y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -87,39 +182,38 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPole0Schw<M> {
     ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, x * coeff, y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, x * coeff, -y,
+                0.0, 0.0, y * coeff, x,
             ],
         )
     }
 }
 
 /// The coordinate system near the pole theta=pi
-pub struct NearPolePiSchw<M: Mass> {
+pub struct NearPolePiSchw<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for NearPolePiSchw<M> {
+impl<M> CoordinateSystem for NearPolePiSchw<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for NearPolePiSchw<M> {
-    fn g(p: &Point<Self>) -> TwoForm<Self> {
+impl<M> NearPolePiSchw<M> {
+    pub(crate) fn g_with(m: f64, p: &Point<Self>) -> TwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         TwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 coeff, 0.0, 0.0, 0.0,
                 0.0, -1.0 / coeff, 0.0, 0.0,
@@ -129,16 +223,15 @@ impl<M: Mass> MetricSystem for NearPolePiSchw<M> {
         )
     }
 
-    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, p: &Point<Self>) -> InvTwoForm<Self> {
         let r = p[1];
         let x = p[2];
         let y = p[3];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         let xy1 = 1.0 + x * x + y * y;
         let alpha2 = 4.0 / xy1 / xy1;
         InvTwoForm::new(
-            p.clone(),
+            *p,
             arr![f64;
                 1.0 / coeff, 0.0, 0.0, 0.0,
                 0.0, -coeff, 0.0, 0.0,
@@ -147,11 +240,108 @@ impl<M: Mass> MetricSystem for NearPolePiSchw<M> {
             ],
         )
     }
+
+    pub(crate) fn christoffel_with(
+        m: f64,
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = p[1];
+        let x = p[2];
+        let y = p[3];
+        let mr = m / r;
+        let r2m = r - 2.0 * m;
+        let xy1 = 1.0 + x * x + y * y;
+        let alpha2 = 4.0 / xy1 / xy1;
+        // the derivatives of ln(alpha) over x and y
+        let lx = -2.0 * x / xy1;
+        let ly = -2.0 * y / xy1;
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *p,
+            arr![f64;
+                0.0, mr/r2m, 0.0, 0.0,
+                mr/r2m, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                mr*r2m/r/r, 0.0, 0.0, 0.0,
+                0.0, -mr/r2m, 0.0, 0.0,
+                0.0, 0.0, -r2m*alpha2, 0.0,
+                0.0, 0.0, 0.0, -r2m*alpha2,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, lx, ly,
+                0.0, 0.0, ly, -lx,
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, -ly, lx,
+                0.0, 1.0/r, lx, ly
+            ],
+        )
+    }
+}
+
+impl<M: Mass> MetricSystem for NearPolePiSchw<M> {
+    fn g(p: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), p)
+    }
+
+    fn inv_g(p: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), p)
+    }
+
+    fn christoffel(
+        p: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), p)
+    }
+}
+
+impl<M> Symmetries for NearPolePiSchw<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, -x[3], x[2]])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        let xy2 = x[2] * x[2] + x[3] * x[3];
+        -(1.0 - xy2) / (1.0 + xy2)
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let x = p.get_point();
+        let xy1 = 1.0 + x[2] * x[2] + x[3] * x[3];
+        (p[2] * p[2] + p[3] * p[3]) * xy1 * xy1 / 4.0
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        let ph = x[3].atan2(x[2]);
+        let coeff = 2.0 / (1.0 + x[2] * x[2] + x[3] * x[3]);
+        [
+            Covector::new(
+                *x,
+                arr![f64; 0.0, 0.0, -coeff * ph.cos(), -coeff * ph.sin()],
+            ),
+            Covector::new(*x, arr![f64; 0.0, 0.0, -coeff * ph.sin(), coeff * ph.cos()]),
+        ]
+    }
 }
 
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPolePiSchw<M> {
+impl<M: 'static> ConversionTo<Schwarzschild<M>> for NearPolePiSchw<M> {
     fn convert_point(p: &Point<Self>) -> Point<Schwarzschild<M>> {
         let x = p[2];
         let y = p[3];
@@ -163,14 +353,14 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPolePiSchw<M> {
     fn jacobian(p: &Point<Self>) -> Matrix<Schwarzschild<M>> {
         let x = p[2];
         let y = p[3];
-        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
+        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y,
-                0.0, 0.0, -y * coeff, x,
+                0.0, 0.0, -x * coeff, -y * coeff,
+                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
             ],
         )
     }
@@ -180,14 +370,14 @@ impl<M: Mass + 'static> ConversionTo<Schwarzschild<M>> for NearPolePiSchw<M> {
     ) -> Tensor<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)> {
         let x = p[2];
         let y = p[3];
-        let coeff = 2.0 / (1.0 + x * x + y * y) / (x * x + y * y).sqrt();
+        let coeff = (1.0 + x * x + y * y) / 2.0 / (x * x + y * y).sqrt();
         Tensor::<Schwarzschild<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -x * coeff, -y * coeff,
-                0.0, 0.0, -y/(x*x + y*y), x/(x*x + y*y),
+                0.0, 0.0, -x * coeff, -y,
+                0.0, 0.0, -y * coeff, x,
             ],
         )
     }
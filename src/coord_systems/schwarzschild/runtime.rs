@@ -0,0 +1,525 @@
+use super::{
+    EddingtonFinkelstein, Harmonic, Isotropic, KruskalSzekeres, NearPole0EF, NearPole0OutgoingEF,
+    NearPole0Schw, NearPolePiEF, NearPolePiOutgoingEF, NearPolePiSchw, OutgoingEF,
+    PainleveGullstrand, Penrose, Schwarzschild,
+};
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+
+/// A Schwarzschild black hole with the mass only known at runtime. It provides the metric of
+/// the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackHole {
+    pub mass: f64,
+}
+
+impl BlackHole {
+    pub fn new(mass: f64) -> Self {
+        BlackHole { mass }
+    }
+
+    /// The radius of the horizon
+    pub fn horizon_radius(&self) -> f64 {
+        2.0 * self.mass
+    }
+}
+
+impl Spacetime<Schwarzschild<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<Schwarzschild<Runtime>>) -> TwoForm<Schwarzschild<Runtime>> {
+        Schwarzschild::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<Schwarzschild<Runtime>>) -> InvTwoForm<Schwarzschild<Runtime>> {
+        Schwarzschild::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Schwarzschild<Runtime>>,
+    ) -> Tensor<Schwarzschild<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        Schwarzschild::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> TwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::g_with(self.mass, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> InvTwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Tensor<EddingtonFinkelstein<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        EddingtonFinkelstein::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<OutgoingEF<Runtime>>) -> TwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<OutgoingEF<Runtime>>) -> InvTwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<OutgoingEF<Runtime>>,
+    ) -> Tensor<OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        OutgoingEF::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<KruskalSzekeres<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<KruskalSzekeres<Runtime>>) -> TwoForm<KruskalSzekeres<Runtime>> {
+        KruskalSzekeres::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<KruskalSzekeres<Runtime>>) -> InvTwoForm<KruskalSzekeres<Runtime>> {
+        KruskalSzekeres::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<KruskalSzekeres<Runtime>>,
+    ) -> Tensor<KruskalSzekeres<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        KruskalSzekeres::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<Penrose<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<Penrose<Runtime>>) -> TwoForm<Penrose<Runtime>> {
+        Penrose::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<Penrose<Runtime>>) -> InvTwoForm<Penrose<Runtime>> {
+        Penrose::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Penrose<Runtime>>,
+    ) -> Tensor<Penrose<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Penrose::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<PainleveGullstrand<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<PainleveGullstrand<Runtime>>) -> TwoForm<PainleveGullstrand<Runtime>> {
+        PainleveGullstrand::g_with(self.mass, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<PainleveGullstrand<Runtime>>,
+    ) -> InvTwoForm<PainleveGullstrand<Runtime>> {
+        PainleveGullstrand::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<PainleveGullstrand<Runtime>>,
+    ) -> Tensor<PainleveGullstrand<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        PainleveGullstrand::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<Isotropic<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<Isotropic<Runtime>>) -> TwoForm<Isotropic<Runtime>> {
+        Isotropic::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<Isotropic<Runtime>>) -> InvTwoForm<Isotropic<Runtime>> {
+        Isotropic::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Isotropic<Runtime>>,
+    ) -> Tensor<Isotropic<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Isotropic::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<Harmonic<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<Harmonic<Runtime>>) -> TwoForm<Harmonic<Runtime>> {
+        Harmonic::g_with(self.mass, x)
+    }
+
+    fn inv_g(&self, x: &Point<Harmonic<Runtime>>) -> InvTwoForm<Harmonic<Runtime>> {
+        Harmonic::inv_g_with(self.mass, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Harmonic<Runtime>>,
+    ) -> Tensor<Harmonic<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Harmonic::christoffel_with(self.mass, x)
+    }
+}
+
+impl Spacetime<NearPole0Schw<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0Schw<Runtime>>) -> TwoForm<NearPole0Schw<Runtime>> {
+        NearPole0Schw::g_with(self.mass, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0Schw<Runtime>>) -> InvTwoForm<NearPole0Schw<Runtime>> {
+        NearPole0Schw::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0Schw<Runtime>>,
+    ) -> Tensor<NearPole0Schw<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPole0Schw::christoffel_with(self.mass, p)
+    }
+}
+
+impl Spacetime<NearPolePiSchw<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiSchw<Runtime>>) -> TwoForm<NearPolePiSchw<Runtime>> {
+        NearPolePiSchw::g_with(self.mass, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiSchw<Runtime>>) -> InvTwoForm<NearPolePiSchw<Runtime>> {
+        NearPolePiSchw::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiSchw<Runtime>>,
+    ) -> Tensor<NearPolePiSchw<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiSchw::christoffel_with(self.mass, p)
+    }
+}
+
+impl Spacetime<NearPole0EF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0EF<Runtime>>) -> TwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::g_with(self.mass, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0EF<Runtime>>) -> InvTwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0EF<Runtime>>,
+    ) -> Tensor<NearPole0EF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0EF::christoffel_with(self.mass, p)
+    }
+}
+
+impl Spacetime<NearPolePiEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiEF<Runtime>>) -> TwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::g_with(self.mass, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiEF<Runtime>>) -> InvTwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiEF<Runtime>>,
+    ) -> Tensor<NearPolePiEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiEF::christoffel_with(self.mass, p)
+    }
+}
+
+impl Spacetime<NearPole0OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0OutgoingEF<Runtime>>) -> TwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::g_with(self.mass, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> Tensor<NearPole0OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPole0OutgoingEF::christoffel_with(self.mass, p)
+    }
+}
+
+impl Spacetime<NearPolePiOutgoingEF<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> TwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::g_with(self.mass, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::inv_g_with(self.mass, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> Tensor<NearPolePiOutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiOutgoingEF::christoffel_with(self.mass, p)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<Schwarzschild<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<Schwarzschild<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        Schwarzschild::to_ef_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Schwarzschild<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        Schwarzschild::to_ef_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, Schwarzschild<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<Schwarzschild<Runtime>> {
+        EddingtonFinkelstein::to_schwarzschild_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<Schwarzschild<Runtime>> {
+        EddingtonFinkelstein::to_schwarzschild_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Schwarzschild<Runtime>, KruskalSzekeres<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Schwarzschild<Runtime>>) -> Point<KruskalSzekeres<Runtime>> {
+        Schwarzschild::to_kruskal_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Schwarzschild<Runtime>>) -> Matrix<KruskalSzekeres<Runtime>> {
+        Schwarzschild::to_kruskal_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<KruskalSzekeres<Runtime>, Schwarzschild<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<KruskalSzekeres<Runtime>>) -> Point<Schwarzschild<Runtime>> {
+        KruskalSzekeres::to_schwarzschild_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<KruskalSzekeres<Runtime>>) -> Matrix<Schwarzschild<Runtime>> {
+        KruskalSzekeres::to_schwarzschild_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, KruskalSzekeres<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<KruskalSzekeres<Runtime>> {
+        EddingtonFinkelstein::to_kruskal_point_with(self.mass, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<KruskalSzekeres<Runtime>> {
+        EddingtonFinkelstein::to_kruskal_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<KruskalSzekeres<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<KruskalSzekeres<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        KruskalSzekeres::to_ef_point_with(self.mass, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<KruskalSzekeres<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        KruskalSzekeres::to_ef_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, PainleveGullstrand<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<PainleveGullstrand<Runtime>> {
+        EddingtonFinkelstein::to_painleve_point_with(self.mass, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<PainleveGullstrand<Runtime>> {
+        EddingtonFinkelstein::to_painleve_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<PainleveGullstrand<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<PainleveGullstrand<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        PainleveGullstrand::to_ef_point_with(self.mass, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<PainleveGullstrand<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        PainleveGullstrand::to_ef_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Schwarzschild<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Schwarzschild<Runtime>>) -> Point<OutgoingEF<Runtime>> {
+        Schwarzschild::to_outgoing_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Schwarzschild<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        Schwarzschild::to_outgoing_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, Schwarzschild<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<OutgoingEF<Runtime>>) -> Point<Schwarzschild<Runtime>> {
+        OutgoingEF::to_schwarzschild_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<Schwarzschild<Runtime>> {
+        OutgoingEF::to_schwarzschild_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<OutgoingEF<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<KruskalSzekeres<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<KruskalSzekeres<Runtime>>) -> Point<OutgoingEF<Runtime>> {
+        KruskalSzekeres::to_outgoing_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<KruskalSzekeres<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        KruskalSzekeres::to_outgoing_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, KruskalSzekeres<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<OutgoingEF<Runtime>>) -> Point<KruskalSzekeres<Runtime>> {
+        OutgoingEF::to_kruskal_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<KruskalSzekeres<Runtime>> {
+        OutgoingEF::to_kruskal_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Schwarzschild<Runtime>, Isotropic<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Schwarzschild<Runtime>>) -> Point<Isotropic<Runtime>> {
+        Schwarzschild::to_isotropic_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Schwarzschild<Runtime>>) -> Matrix<Isotropic<Runtime>> {
+        Schwarzschild::to_isotropic_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Isotropic<Runtime>, Schwarzschild<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Isotropic<Runtime>>) -> Point<Schwarzschild<Runtime>> {
+        Isotropic::to_schwarzschild_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Isotropic<Runtime>>) -> Matrix<Schwarzschild<Runtime>> {
+        Isotropic::to_schwarzschild_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Schwarzschild<Runtime>, Harmonic<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Schwarzschild<Runtime>>) -> Point<Harmonic<Runtime>> {
+        Schwarzschild::to_harmonic_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Schwarzschild<Runtime>>) -> Matrix<Harmonic<Runtime>> {
+        Schwarzschild::to_harmonic_jacobian_with(self.mass, p)
+    }
+}
+
+impl SpacetimeConversion<Harmonic<Runtime>, Schwarzschild<Runtime>> for BlackHole {
+    fn convert_point(&self, p: &Point<Harmonic<Runtime>>) -> Point<Schwarzschild<Runtime>> {
+        Harmonic::to_schwarzschild_point_with(self.mass, p)
+    }
+
+    fn jacobian(&self, p: &Point<Harmonic<Runtime>>) -> Matrix<Schwarzschild<Runtime>> {
+        Harmonic::to_schwarzschild_jacobian_with(self.mass, p)
+    }
+}
+
+// the conversions to and from the polar and Penrose charts don't depend on the mass
+impl<C1, C2> SpacetimeConversion<C1, C2> for BlackHole
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
@@ -1,28 +1,33 @@
-use super::{EddingtonFinkelstein, Mass, NearPole0Schw, NearPolePiSchw};
+use super::{
+    EddingtonFinkelstein, Harmonic, Isotropic, KruskalSzekeres, Mass, NearPole0Schw,
+    NearPolePiSchw, OutgoingEF,
+};
+use crate::conserved::Symmetries;
 use crate::typenum::consts::U4;
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
 use generic_array::arr;
 use std::f64::consts::PI;
 use std::marker::PhantomData;
 
-pub struct Schwarzschild<M: Mass> {
+pub struct Schwarzschild<M> {
     _m: PhantomData<M>,
 }
 
-impl<M: Mass> CoordinateSystem for Schwarzschild<M> {
+impl<M> CoordinateSystem for Schwarzschild<M> {
     type Dimension = U4;
 }
 
-impl<M: Mass> MetricSystem for Schwarzschild<M> {
-    fn g(x: &Point<Self>) -> TwoForm<Self> {
+impl<M> Schwarzschild<M> {
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         TwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
                 coeff, 0.0, 0.0, 0.0,
                 0.0, -1.0/coeff, 0.0, 0.0,
@@ -32,13 +37,12 @@ impl<M: Mass> MetricSystem for Schwarzschild<M> {
         )
     }
 
-    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         let coeff = 1.0 - 2.0 * m / r;
         InvTwoForm::new(
-            x.clone(),
+            *x,
             arr![f64;
                 1.0 / coeff, 0.0, 0.0, 0.0,
                 0.0, -coeff, 0.0, 0.0,
@@ -48,16 +52,16 @@ impl<M: Mass> MetricSystem for Schwarzschild<M> {
         )
     }
 
-    fn christoffel(
+    pub(crate) fn christoffel_with(
+        m: f64,
         x: &Point<Self>,
     ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
         let r = x[1];
         let th = x[2];
-        let m = M::mass();
         let mr = m / r;
         let r2m = r - 2.0 * m;
         Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
-            x.clone(),
+            *x,
             arr![f64;
                 0.0, mr/r2m, 0.0, 0.0,
                 mr/r2m, 0.0, 0.0, 0.0,
@@ -81,29 +85,77 @@ impl<M: Mass> MetricSystem for Schwarzschild<M> {
             ],
         )
     }
+}
+
+impl<M: Mass> MetricSystem for Schwarzschild<M> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(M::mass(), x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(M::mass(), x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(M::mass(), x)
+    }
 
     // TODO
     //fn dg(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
     //fn covariant_christoffel(x: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))>
 }
 
+impl<M> Symmetries for Schwarzschild<M> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
+
 // Conversions
 
-impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for Schwarzschild<M> {
-    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+impl<M> Schwarzschild<M> {
+    pub(crate) fn to_ef_point_with(m: f64, p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
         let t = p[0];
         let r = p[1];
-        let m = M::mass();
         let u = t + r + (0.5 * (r - 2.0 * m) / m).ln() * 2.0 * m;
         Point::new(arr![f64; u, r, p[2], p[3]])
     }
 
-    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+    pub(crate) fn to_ef_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
         let r = p[1];
-        let m = M::mass();
         let dudr = r / (r - 2.0 * m);
         Matrix::new(
-            Self::convert_point(p),
+            Self::to_ef_point_with(m, p),
             arr![f64;
                 1.0, dudr, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
@@ -113,6 +165,95 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for Schwarzschild<
         )
     }
 
+    /// Maps the region r > 2m to the exterior and r < 2m to the black hole of the Kruskal-Szekeres
+    /// chart.
+    pub(crate) fn to_kruskal_point_with(m: f64, p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        let (t, r) = (p[0], p[1]);
+        let rho = 0.5 * r / m;
+        let scale = (rho - 1.0).abs().sqrt() * (0.5 * rho).exp();
+        let (sinh, cosh) = ((0.25 * t / m).sinh(), (0.25 * t / m).cosh());
+        let (tk, xk) = if r > 2.0 * m {
+            (scale * sinh, scale * cosh)
+        } else {
+            (scale * cosh, scale * sinh)
+        };
+        Point::new(arr![f64; tk, xk, p[2], p[3]])
+    }
+
+    pub(crate) fn to_kruskal_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        KruskalSzekeres::from_schwarzschild_jacobian_with(m, &Self::to_kruskal_point_with(m, p))
+    }
+
+    /// Maps the region r > 2m to the exterior and r < 2m to the white hole of the outgoing chart.
+    pub(crate) fn to_outgoing_point_with(m: f64, p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        let t = p[0];
+        let r = p[1];
+        let u = t - r - (0.5 * (r - 2.0 * m) / m).abs().ln() * 2.0 * m;
+        Point::new(arr![f64; u, r, p[2], p[3]])
+    }
+
+    pub(crate) fn to_outgoing_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        let r = p[1];
+        let dudr = -r / (r - 2.0 * m);
+        Matrix::new(
+            Self::to_outgoing_point_with(m, p),
+            arr![f64;
+                1.0, dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    /// Maps the region r > 2m to the exterior sheet of the isotropic chart, rho > m/2.
+    pub(crate) fn to_isotropic_point_with(m: f64, p: &Point<Self>) -> Point<Isotropic<M>> {
+        let r = p[1];
+        let rho = 0.5 * (r - m + (r * r - 2.0 * m * r).sqrt());
+        Point::new(arr![f64; p[0], rho, p[2], p[3]])
+    }
+
+    pub(crate) fn to_isotropic_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<Isotropic<M>> {
+        let x = Self::to_isotropic_point_with(m, p);
+        let r = p[1];
+        let drhodr = x[1] / (r * r - 2.0 * m * r).sqrt();
+        Matrix::new(
+            x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, drhodr, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+
+    pub(crate) fn to_harmonic_point_with(m: f64, p: &Point<Self>) -> Point<Harmonic<M>> {
+        Point::new(arr![f64; p[0], p[1] - m, p[2], p[3]])
+    }
+
+    pub(crate) fn to_harmonic_jacobian_with(m: f64, p: &Point<Self>) -> Matrix<Harmonic<M>> {
+        Matrix::new(
+            Self::to_harmonic_point_with(m, p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for Schwarzschild<M> {
+    fn convert_point(p: &Point<Self>) -> Point<EddingtonFinkelstein<M>> {
+        Self::to_ef_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<EddingtonFinkelstein<M>> {
+        Self::to_ef_jacobian_with(M::mass(), p)
+    }
+
     fn inv_jacobian(
         p: &Point<Self>,
     ) -> Tensor<EddingtonFinkelstein<M>, (CovariantIndex, ContravariantIndex)> {
@@ -131,7 +272,101 @@ impl<M: Mass + 'static> ConversionTo<EddingtonFinkelstein<M>> for Schwarzschild<
     }
 }
 
-impl<M: Mass + 'static> ConversionTo<NearPole0Schw<M>> for Schwarzschild<M> {
+impl<M: Mass + 'static> ConversionTo<KruskalSzekeres<M>> for Schwarzschild<M> {
+    fn convert_point(p: &Point<Self>) -> Point<KruskalSzekeres<M>> {
+        Self::to_kruskal_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<KruskalSzekeres<M>> {
+        Self::to_kruskal_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        Tensor::<KruskalSzekeres<M>, (CovariantIndex, ContravariantIndex)>::from_slice(
+            x,
+            KruskalSzekeres::to_schwarzschild_jacobian_with(M::mass(), &x).coords_array(),
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<OutgoingEF<M>> for Schwarzschild<M> {
+    fn convert_point(p: &Point<Self>) -> Point<OutgoingEF<M>> {
+        Self::to_outgoing_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<OutgoingEF<M>> {
+        Self::to_outgoing_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(
+        p: &Point<Self>,
+    ) -> Tensor<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)> {
+        let r = p[1];
+        let m = M::mass();
+        let dudr = -r / (r - 2.0 * m);
+        Tensor::<OutgoingEF<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, -dudr, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Isotropic<M>> for Schwarzschild<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Isotropic<M>> {
+        Self::to_isotropic_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Isotropic<M>> {
+        Self::to_isotropic_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Isotropic<M>, (CovariantIndex, ContravariantIndex)> {
+        let x = Self::convert_point(p);
+        let r = p[1];
+        let drhodr = x[1] / (r * r - 2.0 * M::mass() * r).sqrt();
+        Tensor::<Isotropic<M>, (CovariantIndex, ContravariantIndex)>::new(
+            x,
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0 / drhodr, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: Mass + 'static> ConversionTo<Harmonic<M>> for Schwarzschild<M> {
+    fn convert_point(p: &Point<Self>) -> Point<Harmonic<M>> {
+        Self::to_harmonic_point_with(M::mass(), p)
+    }
+
+    fn jacobian(p: &Point<Self>) -> Matrix<Harmonic<M>> {
+        Self::to_harmonic_jacobian_with(M::mass(), p)
+    }
+
+    fn inv_jacobian(p: &Point<Self>) -> Tensor<Harmonic<M>, (CovariantIndex, ContravariantIndex)> {
+        Tensor::<Harmonic<M>, (CovariantIndex, ContravariantIndex)>::new(
+            Self::convert_point(p),
+            arr![f64;
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        )
+    }
+}
+
+impl<M: 'static> ConversionTo<NearPole0Schw<M>> for Schwarzschild<M> {
     fn convert_point(p: &Point<Self>) -> Point<NearPole0Schw<M>> {
         let th = p[2];
         let ph = p[3];
@@ -143,14 +378,14 @@ impl<M: Mass + 'static> ConversionTo<NearPole0Schw<M>> for Schwarzschild<M> {
     fn jacobian(p: &Point<Self>) -> Matrix<NearPole0Schw<M>> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -160,20 +395,20 @@ impl<M: Mass + 'static> ConversionTo<NearPole0Schw<M>> for Schwarzschild<M> {
     ) -> Tensor<NearPole0Schw<M>, (CovariantIndex, ContravariantIndex)> {
         let t2 = p[2] / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPole0Schw<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, 0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, 2.0 * ph.cos() / tan2th1, 2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
 }
 
-impl<M: Mass + 'static> ConversionTo<NearPolePiSchw<M>> for Schwarzschild<M> {
+impl<M: 'static> ConversionTo<NearPolePiSchw<M>> for Schwarzschild<M> {
     fn convert_point(p: &Point<Self>) -> Point<NearPolePiSchw<M>> {
         let th = p[2];
         let ph = p[3];
@@ -183,16 +418,16 @@ impl<M: Mass + 'static> ConversionTo<NearPolePiSchw<M>> for Schwarzschild<M> {
     }
 
     fn jacobian(p: &Point<Self>) -> Matrix<NearPolePiSchw<M>> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let tan2th1 = 1.0 + t2.tan() * t2.tan();
+        let cos2t2 = t2.cos() * t2.cos();
         Matrix::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
-                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
+                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
+                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
             ],
         )
     }
@@ -200,16 +435,16 @@ impl<M: Mass + 'static> ConversionTo<NearPolePiSchw<M>> for Schwarzschild<M> {
     fn inv_jacobian(
         p: &Point<Self>,
     ) -> Tensor<NearPolePiSchw<M>, (CovariantIndex, ContravariantIndex)> {
-        let t2 = p[2] / 2.0;
+        let t2 = (PI - p[2]) / 2.0;
         let ph = p[3];
-        let cos2t2 = t2.cos() * t2.cos();
+        let tan2th1 = 1.0 + t2.tan() * t2.tan();
         Tensor::<NearPolePiSchw<M>, (CovariantIndex, ContravariantIndex)>::new(
             Self::convert_point(p),
             arr![f64;
                 1.0, 0.0, 0.0, 0.0,
                 0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, -0.5 * ph.cos() / cos2t2, -t2.tan() * ph.sin(),
-                0.0, 0.0, -0.5 * ph.sin() / cos2t2, t2.tan() * ph.cos(),
+                0.0, 0.0, -2.0 * ph.cos() / tan2th1, -2.0 * ph.sin() / tan2th1,
+                0.0, 0.0, -ph.sin() / t2.tan(), ph.cos() / t2.tan(),
             ],
         )
     }
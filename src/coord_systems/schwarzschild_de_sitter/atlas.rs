@@ -0,0 +1,39 @@
+use super::{
+    EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF, NearPole0SdS, NearPolePiEF,
+    NearPolePiOutgoingEF, NearPolePiSdS, OutgoingEF, SchwarzschildDeSitter,
+};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the Schwarzschild-de Sitter chart and its polar patches
+pub struct SchwarzschildDeSitterAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for SchwarzschildDeSitterAtlas<P> {
+    type Main = SchwarzschildDeSitter<P>;
+    type Pole0 = NearPole0SdS<P>;
+    type PolePi = NearPolePiSdS<P>;
+}
+
+/// The atlas consisting of the ingoing Eddington-Finkelstein chart and its polar patches
+pub struct EFAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for EFAtlas<P> {
+    type Main = EddingtonFinkelstein<P>;
+    type Pole0 = NearPole0EF<P>;
+    type PolePi = NearPolePiEF<P>;
+}
+
+/// The atlas consisting of the outgoing Eddington-Finkelstein chart and its polar patches
+pub struct OutgoingEFAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for OutgoingEFAtlas<P> {
+    type Main = OutgoingEF<P>;
+    type Pole0 = NearPole0OutgoingEF<P>;
+    type PolePi = NearPolePiOutgoingEF<P>;
+}
@@ -0,0 +1,25 @@
+mod atlas;
+mod eddington;
+mod outgoing;
+mod polar_eddington;
+mod polar_outgoing;
+mod polar_schwarzschild_de_sitter;
+mod runtime;
+#[allow(clippy::module_inception)]
+mod schwarzschild_de_sitter;
+
+/// The parameters of a Schwarzschild-de Sitter black hole; the cosmological constant has to be
+/// positive and 9 Lambda M^2 < 1, so that both horizons exist
+pub trait Properties {
+    fn mass() -> f64;
+    fn cosmological_constant() -> f64;
+}
+
+pub use self::atlas::{EFAtlas, OutgoingEFAtlas, SchwarzschildDeSitterAtlas};
+pub use self::eddington::EddingtonFinkelstein;
+pub use self::outgoing::OutgoingEF;
+pub use self::polar_eddington::{NearPole0EF, NearPolePiEF};
+pub use self::polar_outgoing::{NearPole0OutgoingEF, NearPolePiOutgoingEF};
+pub use self::polar_schwarzschild_de_sitter::{NearPole0SdS, NearPolePiSdS};
+pub use self::runtime::BlackHole;
+pub use self::schwarzschild_de_sitter::SchwarzschildDeSitter;
@@ -0,0 +1,335 @@
+use super::schwarzschild_de_sitter::horizons;
+use super::{
+    EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF, NearPole0SdS, NearPolePiEF,
+    NearPolePiOutgoingEF, NearPolePiSdS, OutgoingEF, SchwarzschildDeSitter,
+};
+use crate::coord_systems::Runtime;
+use crate::typenum::consts::U4;
+use crate::{Spacetime, SpacetimeConversion};
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Matrix, Tensor, TwoForm};
+
+/// A Schwarzschild-de Sitter black hole with the mass and cosmological constant only known at
+/// runtime. It provides the metric of the charts parametrized with `Runtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackHole {
+    pub mass: f64,
+    pub cosmological_constant: f64,
+}
+
+impl BlackHole {
+    pub fn new(mass: f64, cosmological_constant: f64) -> Self {
+        BlackHole {
+            mass,
+            cosmological_constant,
+        }
+    }
+
+    /// The radius of the black hole horizon
+    pub fn horizon_radius(&self) -> f64 {
+        horizons(self.mass, self.cosmological_constant).0
+    }
+
+    /// The radius of the cosmological horizon
+    pub fn cosmological_horizon_radius(&self) -> f64 {
+        horizons(self.mass, self.cosmological_constant).1
+    }
+}
+
+impl Spacetime<SchwarzschildDeSitter<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> TwoForm<SchwarzschildDeSitter<Runtime>> {
+        SchwarzschildDeSitter::g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> InvTwoForm<SchwarzschildDeSitter<Runtime>> {
+        SchwarzschildDeSitter::inv_g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> Tensor<
+        SchwarzschildDeSitter<Runtime>,
+        (ContravariantIndex, (CovariantIndex, CovariantIndex)),
+    > {
+        SchwarzschildDeSitter::christoffel_with(self.mass, self.cosmological_constant, x)
+    }
+}
+
+impl Spacetime<EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> TwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn inv_g(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> InvTwoForm<EddingtonFinkelstein<Runtime>> {
+        EddingtonFinkelstein::inv_g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Tensor<EddingtonFinkelstein<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        EddingtonFinkelstein::christoffel_with(self.mass, self.cosmological_constant, x)
+    }
+}
+
+impl Spacetime<OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, x: &Point<OutgoingEF<Runtime>>) -> TwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn inv_g(&self, x: &Point<OutgoingEF<Runtime>>) -> InvTwoForm<OutgoingEF<Runtime>> {
+        OutgoingEF::inv_g_with(self.mass, self.cosmological_constant, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<OutgoingEF<Runtime>>,
+    ) -> Tensor<OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        OutgoingEF::christoffel_with(self.mass, self.cosmological_constant, x)
+    }
+}
+
+impl Spacetime<NearPole0SdS<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0SdS<Runtime>>) -> TwoForm<NearPole0SdS<Runtime>> {
+        NearPole0SdS::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0SdS<Runtime>>) -> InvTwoForm<NearPole0SdS<Runtime>> {
+        NearPole0SdS::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0SdS<Runtime>>,
+    ) -> Tensor<NearPole0SdS<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0SdS::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl Spacetime<NearPolePiSdS<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiSdS<Runtime>>) -> TwoForm<NearPolePiSdS<Runtime>> {
+        NearPolePiSdS::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiSdS<Runtime>>) -> InvTwoForm<NearPolePiSdS<Runtime>> {
+        NearPolePiSdS::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiSdS<Runtime>>,
+    ) -> Tensor<NearPolePiSdS<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiSdS::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl Spacetime<NearPole0EF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0EF<Runtime>>) -> TwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPole0EF<Runtime>>) -> InvTwoForm<NearPole0EF<Runtime>> {
+        NearPole0EF::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0EF<Runtime>>,
+    ) -> Tensor<NearPole0EF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0EF::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl Spacetime<NearPolePiEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPolePiEF<Runtime>>) -> TwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(&self, p: &Point<NearPolePiEF<Runtime>>) -> InvTwoForm<NearPolePiEF<Runtime>> {
+        NearPolePiEF::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiEF<Runtime>>,
+    ) -> Tensor<NearPolePiEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiEF::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl Spacetime<NearPole0OutgoingEF<Runtime>> for BlackHole {
+    fn g(&self, p: &Point<NearPole0OutgoingEF<Runtime>>) -> TwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPole0OutgoingEF<Runtime>> {
+        NearPole0OutgoingEF::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPole0OutgoingEF<Runtime>>,
+    ) -> Tensor<NearPole0OutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPole0OutgoingEF::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl Spacetime<NearPolePiOutgoingEF<Runtime>> for BlackHole {
+    fn g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> TwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn inv_g(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> InvTwoForm<NearPolePiOutgoingEF<Runtime>> {
+        NearPolePiOutgoingEF::inv_g_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn christoffel(
+        &self,
+        p: &Point<NearPolePiOutgoingEF<Runtime>>,
+    ) -> Tensor<NearPolePiOutgoingEF<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+    {
+        NearPolePiOutgoingEF::christoffel_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+// Conversions
+
+impl SpacetimeConversion<SchwarzschildDeSitter<Runtime>, EddingtonFinkelstein<Runtime>>
+    for BlackHole
+{
+    fn convert_point(
+        &self,
+        p: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        SchwarzschildDeSitter::to_ef_point_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        SchwarzschildDeSitter::to_ef_jacobian_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, SchwarzschildDeSitter<Runtime>>
+    for BlackHole
+{
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<SchwarzschildDeSitter<Runtime>> {
+        EddingtonFinkelstein::to_schwarzschild_de_sitter_point_with(
+            self.mass,
+            self.cosmological_constant,
+            p,
+        )
+    }
+
+    fn jacobian(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Matrix<SchwarzschildDeSitter<Runtime>> {
+        EddingtonFinkelstein::to_schwarzschild_de_sitter_jacobian_with(
+            self.mass,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<SchwarzschildDeSitter<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<SchwarzschildDeSitter<Runtime>>,
+    ) -> Point<OutgoingEF<Runtime>> {
+        SchwarzschildDeSitter::to_outgoing_point_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn jacobian(&self, p: &Point<SchwarzschildDeSitter<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        SchwarzschildDeSitter::to_outgoing_jacobian_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, SchwarzschildDeSitter<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<OutgoingEF<Runtime>>,
+    ) -> Point<SchwarzschildDeSitter<Runtime>> {
+        OutgoingEF::to_schwarzschild_de_sitter_point_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<SchwarzschildDeSitter<Runtime>> {
+        OutgoingEF::to_schwarzschild_de_sitter_jacobian_with(
+            self.mass,
+            self.cosmological_constant,
+            p,
+        )
+    }
+}
+
+impl SpacetimeConversion<EddingtonFinkelstein<Runtime>, OutgoingEF<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<EddingtonFinkelstein<Runtime>>,
+    ) -> Point<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_point_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn jacobian(&self, p: &Point<EddingtonFinkelstein<Runtime>>) -> Matrix<OutgoingEF<Runtime>> {
+        EddingtonFinkelstein::to_outgoing_jacobian_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+impl SpacetimeConversion<OutgoingEF<Runtime>, EddingtonFinkelstein<Runtime>> for BlackHole {
+    fn convert_point(
+        &self,
+        p: &Point<OutgoingEF<Runtime>>,
+    ) -> Point<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_point_with(self.mass, self.cosmological_constant, p)
+    }
+
+    fn jacobian(&self, p: &Point<OutgoingEF<Runtime>>) -> Matrix<EddingtonFinkelstein<Runtime>> {
+        OutgoingEF::to_ef_jacobian_with(self.mass, self.cosmological_constant, p)
+    }
+}
+
+// the conversions to and from the polar charts don't depend on the parameters
+impl<C1, C2> SpacetimeConversion<C1, C2> for BlackHole
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
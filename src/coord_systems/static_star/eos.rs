@@ -0,0 +1,89 @@
+use super::EquationOfState;
+
+/// A polytrope P = K rho^Gamma in the rest mass density rho, with the energy density
+/// e = rho + P / (Gamma - 1) including the internal energy of an ideal gas
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polytrope {
+    pub k: f64,
+    pub gamma: f64,
+}
+
+impl Polytrope {
+    pub fn new(k: f64, gamma: f64) -> Self {
+        Polytrope { k, gamma }
+    }
+
+    /// The pressure at the rest mass density `rho`
+    pub fn pressure(&self, rho: f64) -> f64 {
+        self.k * rho.powf(self.gamma)
+    }
+}
+
+impl EquationOfState for Polytrope {
+    fn energy_density(&self, pressure: f64) -> f64 {
+        (pressure / self.k).powf(1.0 / self.gamma) + pressure / (self.gamma - 1.0)
+    }
+}
+
+/// An incompressible fluid with the same energy density at any pressure, which makes a star of
+/// constant density
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Incompressible {
+    pub energy_density: f64,
+}
+
+impl Incompressible {
+    pub fn new(energy_density: f64) -> Self {
+        Incompressible { energy_density }
+    }
+}
+
+impl EquationOfState for Incompressible {
+    fn energy_density(&self, _pressure: f64) -> f64 {
+        self.energy_density
+    }
+}
+
+/// An equation of state given as a table of the energy density at increasing pressures, like the
+/// ones computed for the matter of neutron stars. Between the entries the energy density is
+/// interpolated linearly in logarithms, ie. as a piecewise polytrope; the first and the last
+/// pieces are continued below and above the table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tabulated {
+    /// The pairs of the pressure and the energy density
+    entries: Vec<(f64, f64)>,
+}
+
+impl Tabulated {
+    /// `entries` are the pairs (pressure, energy density), with both positive and increasing
+    pub fn new(entries: Vec<(f64, f64)>) -> Self {
+        assert!(
+            entries.len() >= 2,
+            "a tabulated equation of state needs at least two entries"
+        );
+        assert!(
+            entries[0].0 > 0.0 && entries[0].1 > 0.0,
+            "the tabulated pressures and energy densities must be positive"
+        );
+        assert!(
+            entries
+                .windows(2)
+                .all(|w| w[1].0 > w[0].0 && w[1].1 >= w[0].1),
+            "the tabulated pressures and energy densities must be increasing"
+        );
+        Tabulated { entries }
+    }
+}
+
+impl EquationOfState for Tabulated {
+    fn energy_density(&self, pressure: f64) -> f64 {
+        if pressure <= 0.0 {
+            return 0.0;
+        }
+        let i = self.entries.partition_point(|e| e.0 <= pressure);
+        let i = i.max(1).min(self.entries.len() - 1) - 1;
+        let ((p0, e0), (p1, e1)) = (self.entries[i], self.entries[i + 1]);
+        let exponent = (e1 / e0).ln() / (p1 / p0).ln();
+        e0 * (pressure / p0).powf(exponent)
+    }
+}
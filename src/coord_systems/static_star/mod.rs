@@ -0,0 +1,46 @@
+mod eos;
+mod runtime;
+#[allow(clippy::module_inception)]
+mod static_star;
+mod tov;
+mod uniform;
+
+/// The structure of a static, spherically symmetric star,
+/// ds^2 = e^(2 Phi(r)) dt^2 - dr^2 / (1 - 2m(r)/r) - r^2 dOmega^2, as functions of the areal
+/// radius r. Outside of the star m is its total mass M and e^(2 Phi) = 1 - 2M/r.
+pub trait Properties {
+    /// The mass m enclosed by the sphere of radius r, and dm/dr
+    fn mass(r: f64) -> (f64, f64);
+
+    /// The redshift function Phi and its derivative dPhi/dr
+    fn redshift(r: f64) -> (f64, f64);
+}
+
+/// The structure of a star only known at runtime, see `Properties`
+pub trait Profile {
+    /// The mass m enclosed by the sphere of radius r, and dm/dr
+    fn mass(&self, r: f64) -> (f64, f64);
+
+    /// The redshift function Phi and its derivative dPhi/dr
+    fn redshift(&self, r: f64) -> (f64, f64);
+}
+
+/// The equation of state of a perfect fluid: the energy density as a function of the pressure,
+/// both in the geometric units of the engine.
+///
+/// It's implemented for closures mapping the pressure to the energy density.
+pub trait EquationOfState {
+    fn energy_density(&self, pressure: f64) -> f64;
+}
+
+impl<F: Fn(f64) -> f64> EquationOfState for F {
+    fn energy_density(&self, pressure: f64) -> f64 {
+        self(pressure)
+    }
+}
+
+pub use self::eos::{Incompressible, Polytrope, Tabulated};
+pub use self::runtime::Star;
+pub use self::static_star::StaticStar;
+pub use self::tov::Tov;
+pub use self::uniform::UniformDensity;
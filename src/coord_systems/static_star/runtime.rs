@@ -0,0 +1,54 @@
+use super::{EquationOfState, Profile, StaticStar, Tov, UniformDensity};
+use crate::coord_systems::Runtime;
+use crate::Spacetime;
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+
+/// A static star with the structure only known at runtime. It provides the metric of the charts
+/// parametrized with `Runtime`.
+#[derive(Clone, Debug)]
+pub struct Star<P> {
+    pub profile: P,
+}
+
+impl<P> Star<P> {
+    pub fn new(profile: P) -> Self {
+        Star { profile }
+    }
+}
+
+impl Star<UniformDensity> {
+    /// The star of constant density with the given total mass and radius
+    pub fn uniform(mass: f64, radius: f64) -> Self {
+        Star::new(UniformDensity::new(mass, radius))
+    }
+}
+
+impl<E: EquationOfState> Star<Tov<E>> {
+    /// The star made of the matter with the equation of state `eos`, with the pressure
+    /// `central_pressure` at the centre
+    pub fn solve(eos: E, central_pressure: f64) -> Self {
+        Star::new(Tov::solve(eos, central_pressure))
+    }
+}
+
+impl<P: Profile> Spacetime<StaticStar<Runtime>> for Star<P> {
+    fn g(&self, x: &Point<StaticStar<Runtime>>) -> TwoForm<StaticStar<Runtime>> {
+        let (m, _) = self.profile.mass(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        StaticStar::g_with(m, phi, x)
+    }
+
+    fn inv_g(&self, x: &Point<StaticStar<Runtime>>) -> InvTwoForm<StaticStar<Runtime>> {
+        let (m, _) = self.profile.mass(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        StaticStar::inv_g_with(m, phi, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<StaticStar<Runtime>>,
+    ) -> Tensor<StaticStar<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        StaticStar::christoffel_with(self.profile.mass(x[1]), self.profile.redshift(x[1]), x)
+    }
+}
@@ -0,0 +1,142 @@
+use super::Properties;
+use crate::conserved::Symmetries;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Tensor, TwoForm, Vector,
+};
+use generic_array::arr;
+use std::marker::PhantomData;
+
+/// The Schwarzschild-like coordinates (t, r, theta, phi) of a static star, inside and outside of
+/// it: ds^2 = e^(2 Phi(r)) dt^2 - dr^2 / (1 - 2m(r)/r) - r^2 dOmega^2. Outside of the star the
+/// chart is the `schwarzschild::Schwarzschild` chart of its total mass, so geodesics pass
+/// through the surface and the interior without any change of coordinates.
+pub struct StaticStar<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for StaticStar<P> {
+    type Dimension = U4;
+}
+
+impl<P> StaticStar<P> {
+    /// `m` is the enclosed mass and `phi` the redshift function at the point `x`
+    pub(crate) fn g_with(m: f64, phi: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        TwoForm::new(
+            *x,
+            arr![f64;
+                (2.0 * phi).exp(), 0.0, 0.0, 0.0,
+                0.0, -r/(r - 2.0*m), 0.0, 0.0,
+                0.0, 0.0, -r*r, 0.0,
+                0.0, 0.0, 0.0, -r*r*th.sin()*th.sin()
+            ],
+        )
+    }
+
+    pub(crate) fn inv_g_with(m: f64, phi: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let r = x[1];
+        let th = x[2];
+        InvTwoForm::new(
+            *x,
+            arr![f64;
+                (-2.0 * phi).exp(), 0.0, 0.0, 0.0,
+                0.0, -1.0 + 2.0*m/r, 0.0, 0.0,
+                0.0, 0.0, -1.0/(r*r), 0.0,
+                0.0, 0.0, 0.0, -1.0/(r*r*th.sin()*th.sin())
+            ],
+        )
+    }
+
+    /// `mass` and `redshift` are m and Phi with their derivatives over r at the point `x`
+    pub(crate) fn christoffel_with(
+        mass: (f64, f64),
+        redshift: (f64, f64),
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let r = x[1];
+        let th = x[2];
+        let (m, dm) = mass;
+        let (phi, dphi) = redshift;
+        let r2m = r - 2.0 * m;
+        let gamma_rtt = (2.0 * phi).exp() * dphi * r2m / r;
+        let gamma_rrr = (r * dm - m) / (r * r2m);
+        Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *x,
+            arr![f64;
+                0.0, dphi, 0.0, 0.0,
+                dphi, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+
+                gamma_rtt, 0.0, 0.0, 0.0,
+                0.0, gamma_rrr, 0.0, 0.0,
+                0.0, 0.0, -r2m, 0.0,
+                0.0, 0.0, 0.0, -r2m*th.sin()*th.sin(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0/r, 0.0,
+                0.0, 1.0/r, 0.0, 0.0,
+                0.0, 0.0, 0.0, -th.sin()*th.cos(),
+
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 1.0/r,
+                0.0, 0.0, 0.0, th.cos()/th.sin(),
+                0.0, 1.0/r, th.cos()/th.sin(), 0.0
+            ],
+        )
+    }
+}
+
+impl<P: Properties> MetricSystem for StaticStar<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(x[1]).0, P::redshift(x[1]).0, x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(x[1]).0, P::redshift(x[1]).0, x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(x[1]), P::redshift(x[1]), x)
+    }
+}
+
+impl<P> Symmetries for StaticStar<P> {
+    fn time_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 1.0, 0.0, 0.0, 0.0])
+    }
+
+    fn axial_killing_vector(x: &Point<Self>) -> Vector<Self> {
+        Vector::new(*x, arr![f64; 0.0, 0.0, 0.0, 1.0])
+    }
+
+    fn cos_theta(x: &Point<Self>) -> f64 {
+        x[2].cos()
+    }
+
+    fn radius(x: &Point<Self>) -> f64 {
+        x[1]
+    }
+
+    fn sphere_norm_sq(p: &Covector<Self>) -> f64 {
+        let sin_th = p.get_point()[2].sin();
+        p[2] * p[2] + p[3] * p[3] / (sin_th * sin_th)
+    }
+
+    fn radial_gradient(x: &Point<Self>) -> Covector<Self> {
+        Covector::new(*x, arr![f64; 0.0, 1.0, 0.0, 0.0])
+    }
+
+    fn angular_coframe(x: &Point<Self>) -> [Covector<Self>; 2] {
+        [
+            Covector::new(*x, arr![f64; 0.0, 0.0, 1.0, 0.0]),
+            Covector::new(*x, arr![f64; 0.0, 0.0, 0.0, x[2].sin()]),
+        ]
+    }
+}
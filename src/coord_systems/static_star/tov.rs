@@ -0,0 +1,219 @@
+use super::{EquationOfState, Profile};
+use crate::numeric::hermite;
+use std::f64::consts::PI;
+
+// the number of integration steps per the radius at which the pressure would vanish in a star
+// of constant density with the same centre
+const STEPS_PER_SCALE: f64 = 2000.0;
+// the limit on the number of steps, reached by the equations of state that pass the check of the
+// adiabatic index but still give no surface, or one too far away
+const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    r: f64,
+    m: f64,
+    dm: f64,
+    phi: f64,
+    dphi: f64,
+    pressure: f64,
+}
+
+/// A star in hydrostatic equilibrium, found by integrating the Tolman-Oppenheimer-Volkoff
+/// equations outwards from the centre with the given equation of state:
+/// dP/dr = -(e + P)(m + 4 pi r^3 P) / (r (r - 2m)), dm/dr = 4 pi r^2 e.
+///
+/// The integration runs once, on construction, with the classical Runge-Kutta method up to the
+/// surface, where the pressure vanishes. The redshift function is then shifted to match the
+/// exterior Schwarzschild metric of the total mass there.
+#[derive(Clone, Debug)]
+pub struct Tov<E> {
+    eos: E,
+    central_pressure: f64,
+    step: f64,
+    nodes: Vec<Node>,
+}
+
+impl<E: EquationOfState> Tov<E> {
+    pub fn solve(eos: E, central_pressure: f64) -> Self {
+        let mut result = Tov {
+            eos,
+            central_pressure,
+            step: 0.0,
+            nodes: vec![],
+        };
+        result.integrate();
+        result
+    }
+
+    /// The equation of state of the matter of the star
+    pub fn eos(&self) -> &E {
+        &self.eos
+    }
+
+    /// The pressure at the centre
+    pub fn central_pressure(&self) -> f64 {
+        self.central_pressure
+    }
+
+    /// The areal radius of the surface
+    pub fn radius(&self) -> f64 {
+        self.nodes[self.nodes.len() - 1].r
+    }
+
+    /// The total gravitational mass, as seen by the Schwarzschild exterior
+    pub fn total_mass(&self) -> f64 {
+        self.nodes[self.nodes.len() - 1].m
+    }
+
+    /// The pressure at the radius `r`, interpolated linearly between the nodes
+    pub fn pressure(&self, r: f64) -> f64 {
+        if r >= self.radius() {
+            return 0.0;
+        }
+        let (n0, n1) = self.segment(r);
+        n0.pressure + (n1.pressure - n0.pressure) * (r - n0.r) / (n1.r - n0.r)
+    }
+
+    // dm/dr, dP/dr and dPhi/dr
+    fn rates(&self, r: f64, m: f64, pressure: f64) -> (f64, f64, f64) {
+        let pressure = pressure.max(0.0);
+        let e = self.eos.energy_density(pressure);
+        let dphi = (m + 4.0 * PI * r * r * r * pressure) / (r * (r - 2.0 * m));
+        (4.0 * PI * r * r * e, -(e + pressure) * dphi, dphi)
+    }
+
+    // a step of the classical Runge-Kutta method of the length `h` from the node `n`
+    fn advance(&self, n: &Node, h: f64) -> Node {
+        let k1 = self.rates(n.r, n.m, n.pressure);
+        let k2 = self.rates(
+            n.r + 0.5 * h,
+            n.m + 0.5 * h * k1.0,
+            n.pressure + 0.5 * h * k1.1,
+        );
+        let k3 = self.rates(
+            n.r + 0.5 * h,
+            n.m + 0.5 * h * k2.0,
+            n.pressure + 0.5 * h * k2.1,
+        );
+        let k4 = self.rates(n.r + h, n.m + h * k3.0, n.pressure + h * k3.1);
+        let m = n.m + h * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0) / 6.0;
+        let pressure = n.pressure + h * (k1.1 + 2.0 * k2.1 + 2.0 * k3.1 + k4.1) / 6.0;
+        let phi = n.phi + h * (k1.2 + 2.0 * k2.2 + 2.0 * k3.2 + k4.2) / 6.0;
+        self.node(n.r + h, m, phi, pressure)
+    }
+
+    fn node(&self, r: f64, m: f64, phi: f64, pressure: f64) -> Node {
+        let (dm, _, dphi) = self.rates(r, m, pressure);
+        Node {
+            r,
+            m,
+            dm,
+            phi,
+            dphi,
+            pressure,
+        }
+    }
+
+    fn integrate(&mut self) {
+        let pc = self.central_pressure;
+        let ec = self.eos.energy_density(pc);
+        assert!(
+            pc > 0.0 && ec > 0.0,
+            "a star needs a positive central pressure and energy density"
+        );
+        // the adiabatic index Gamma = (e + P) / P dP/de of the matter near the surface, equal to
+        // the exponent of a polytrope; at Gamma <= 6/5 the pressure falls off too slowly to
+        // vanish, the margin covers the error of the finite difference
+        let p = 1e-12 * pc;
+        let (e, dp) = (self.eos.energy_density(p), 1e-4 * p);
+        let de = (self.eos.energy_density(p + dp) - self.eos.energy_density(p - dp)) / (2.0 * dp);
+        let gamma = (e + p) / (p * de);
+        assert!(
+            gamma > 1.2 + 1e-6,
+            "the equation of state gives no surface: its adiabatic index {} at low pressures \
+             doesn't exceed 6/5",
+            gamma
+        );
+        // the expansion of the solution around the regular centre
+        let curvature = 2.0 * PI / 3.0 * (ec + 3.0 * pc);
+        let scale = (pc / (curvature * (ec + pc))).sqrt();
+        let h = scale / STEPS_PER_SCALE;
+        self.step = h;
+        let mut nodes = vec![Node {
+            r: 0.0,
+            m: 0.0,
+            dm: 0.0,
+            phi: 0.0,
+            dphi: 0.0,
+            pressure: pc,
+        }];
+        let first = self.node(
+            h,
+            4.0 * PI / 3.0 * ec * h * h * h,
+            curvature * h * h,
+            pc - curvature * (ec + pc) * h * h,
+        );
+        nodes.push(first);
+
+        let mut last = first;
+        loop {
+            assert!(
+                nodes.len() < MAX_STEPS,
+                "the pressure doesn't vanish anywhere; the equation of state gives no surface"
+            );
+            let next = self.advance(&last, h);
+            if next.pressure > 0.0 {
+                nodes.push(next);
+                last = next;
+                continue;
+            }
+            // the surface lies within the step; find where the pressure vanishes
+            let (mut lo, mut hi) = (0.0, h);
+            for _ in 0..60 {
+                let mid = 0.5 * (lo + hi);
+                if self.advance(&last, mid).pressure > 0.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            let surface = self.advance(&last, hi);
+            nodes.push(self.node(surface.r, surface.m, surface.phi, 0.0));
+            break;
+        }
+
+        // e^(2 Phi) = 1 - 2M/R at the surface
+        let surface = nodes[nodes.len() - 1];
+        let shift = 0.5 * (1.0 - 2.0 * surface.m / surface.r).ln() - surface.phi;
+        for node in &mut nodes {
+            node.phi += shift;
+        }
+        self.nodes = nodes;
+    }
+
+    // the nodes around the radius `r` inside the star
+    fn segment(&self, r: f64) -> (Node, Node) {
+        let i = ((r / self.step) as usize).min(self.nodes.len() - 2);
+        (self.nodes[i], self.nodes[i + 1])
+    }
+}
+
+impl<E: EquationOfState> Profile for Tov<E> {
+    fn mass(&self, r: f64) -> (f64, f64) {
+        if r >= self.radius() {
+            return (self.total_mass(), 0.0);
+        }
+        let (n0, n1) = self.segment(r);
+        hermite(r, (n0.r, n0.m, n0.dm), (n1.r, n1.m, n1.dm))
+    }
+
+    fn redshift(&self, r: f64) -> (f64, f64) {
+        if r >= self.radius() {
+            let m = self.total_mass();
+            return (0.5 * (1.0 - 2.0 * m / r).ln(), m / (r * (r - 2.0 * m)));
+        }
+        let (n0, n1) = self.segment(r);
+        hermite(r, (n0.r, n0.phi, n0.dphi), (n1.r, n1.phi, n1.dphi))
+    }
+}
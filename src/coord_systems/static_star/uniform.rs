@@ -0,0 +1,59 @@
+use super::Profile;
+use std::f64::consts::PI;
+
+/// Schwarzschild's interior solution: a star of constant density, with the enclosed mass
+/// m = M r^3 / R^3 and e^Phi = 3/2 sqrt(1 - 2M/R) - 1/2 sqrt(1 - 2M r^2 / R^3) inside, matched to
+/// the exterior Schwarzschild metric at the surface r = R.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformDensity {
+    pub mass: f64,
+    pub radius: f64,
+}
+
+impl UniformDensity {
+    /// A star of the total mass `mass` and the radius `radius`, which must be larger than 9/4 of
+    /// the mass (the Buchdahl limit) for the central pressure to stay finite
+    pub fn new(mass: f64, radius: f64) -> Self {
+        assert!(
+            radius > 2.25 * mass,
+            "a star of constant density needs a radius above the Buchdahl limit 9M/4"
+        );
+        UniformDensity { mass, radius }
+    }
+
+    pub fn energy_density(&self) -> f64 {
+        3.0 * self.mass / (4.0 * PI * self.radius.powi(3))
+    }
+
+    /// The pressure at the radius `r` inside the star
+    pub fn pressure(&self, r: f64) -> f64 {
+        let surface = (1.0 - 2.0 * self.mass / self.radius).sqrt();
+        let inner = (1.0 - 2.0 * self.mass * r * r / self.radius.powi(3)).sqrt();
+        self.energy_density() * (inner - surface) / (3.0 * surface - inner)
+    }
+
+    pub fn central_pressure(&self) -> f64 {
+        self.pressure(0.0)
+    }
+}
+
+impl Profile for UniformDensity {
+    fn mass(&self, r: f64) -> (f64, f64) {
+        if r >= self.radius {
+            return (self.mass, 0.0);
+        }
+        let r3 = self.radius.powi(3);
+        (self.mass * r * r * r / r3, 3.0 * self.mass * r * r / r3)
+    }
+
+    fn redshift(&self, r: f64) -> (f64, f64) {
+        let m = self.mass;
+        if r >= self.radius {
+            return (0.5 * (1.0 - 2.0 * m / r).ln(), m / (r * (r - 2.0 * m)));
+        }
+        let r3 = self.radius.powi(3);
+        let inner = (1.0 - 2.0 * m * r * r / r3).sqrt();
+        let lapse = 1.5 * (1.0 - 2.0 * m / self.radius).sqrt() - 0.5 * inner;
+        (lapse.ln(), m * r / (r3 * inner * lapse))
+    }
+}
@@ -0,0 +1,26 @@
+mod runtime;
+#[allow(clippy::module_inception)]
+mod vaidya;
+
+/// The mass of a Vaidya black hole as a function of the advanced time v
+pub trait Properties {
+    /// The mass m and its derivative dm/dv
+    fn mass(v: f64) -> (f64, f64);
+}
+
+/// The mass of a Vaidya black hole only known at runtime, as a function of the advanced time v.
+///
+/// It's implemented for closures returning m and dm/dv.
+pub trait MassFunction {
+    /// The mass m and its derivative dm/dv
+    fn mass(&self, v: f64) -> (f64, f64);
+}
+
+impl<F: Fn(f64) -> (f64, f64)> MassFunction for F {
+    fn mass(&self, v: f64) -> (f64, f64) {
+        self(v)
+    }
+}
+
+pub use self::runtime::BlackHole;
+pub use self::vaidya::Vaidya;
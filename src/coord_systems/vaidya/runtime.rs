@@ -0,0 +1,40 @@
+use super::{MassFunction, Vaidya};
+use crate::coord_systems::Runtime;
+use crate::Spacetime;
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+
+/// A Vaidya black hole with the mass function only known at runtime. It provides the metric of
+/// the charts parametrized with `Runtime`.
+#[derive(Clone, Debug)]
+pub struct BlackHole<F> {
+    pub mass: F,
+}
+
+impl<F: MassFunction> BlackHole<F> {
+    pub fn new(mass: F) -> Self {
+        BlackHole { mass }
+    }
+
+    /// The radius of the apparent horizon at the advanced time `v`
+    pub fn apparent_horizon_radius(&self, v: f64) -> f64 {
+        2.0 * self.mass.mass(v).0
+    }
+}
+
+impl<F: MassFunction> Spacetime<Vaidya<Runtime>> for BlackHole<F> {
+    fn g(&self, x: &Point<Vaidya<Runtime>>) -> TwoForm<Vaidya<Runtime>> {
+        Vaidya::g_with(self.mass.mass(x[0]).0, x)
+    }
+
+    fn inv_g(&self, x: &Point<Vaidya<Runtime>>) -> InvTwoForm<Vaidya<Runtime>> {
+        Vaidya::inv_g_with(self.mass.mass(x[0]).0, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<Vaidya<Runtime>>,
+    ) -> Tensor<Vaidya<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Vaidya::christoffel_with(self.mass.mass(x[0]), x)
+    }
+}
@@ -0,0 +1,69 @@
+use super::Properties;
+use crate::coord_systems::schwarzschild::EddingtonFinkelstein;
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+use std::marker::PhantomData;
+
+/// The ingoing coordinates (v, r, theta, phi) of the Vaidya spacetime: the ingoing
+/// Eddington-Finkelstein form of the Schwarzschild metric with the mass depending on the advanced
+/// time, ds^2 = (1 - 2m(v)/r) dv^2 - 2 dv dr - r^2 dOmega^2. The mass grows when null dust falls
+/// into the black hole and shrinks when it radiates, with the apparent horizon at r = 2m(v).
+pub struct Vaidya<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P> CoordinateSystem for Vaidya<P> {
+    type Dimension = U4;
+}
+
+impl<P> Vaidya<P> {
+    // the same point in the Eddington-Finkelstein chart with the mass frozen at m(v)
+    fn ef_point(x: &Point<Self>) -> Point<EddingtonFinkelstein<P>> {
+        Point::new(*x.coords_array())
+    }
+
+    pub(crate) fn g_with(m: f64, x: &Point<Self>) -> TwoForm<Self> {
+        let g = EddingtonFinkelstein::g_with(m, &Self::ef_point(x));
+        TwoForm::from_slice(*x, g.coords_array())
+    }
+
+    pub(crate) fn inv_g_with(m: f64, x: &Point<Self>) -> InvTwoForm<Self> {
+        let inv_g = EddingtonFinkelstein::inv_g_with(m, &Self::ef_point(x));
+        InvTwoForm::from_slice(*x, inv_g.coords_array())
+    }
+
+    /// `mass` is m and dm/dv at the advanced time of the point `x`
+    pub(crate) fn christoffel_with(
+        mass: (f64, f64),
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let (m, dm) = mass;
+        let christoffel = EddingtonFinkelstein::christoffel_with(m, &Self::ef_point(x));
+        let mut result =
+            Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::from_slice(
+                *x,
+                christoffel.coords_array(),
+            );
+        // the only symbol containing the derivative of g_vv over v is Gamma^r_vv
+        result[16] += dm / x[1];
+        result
+    }
+}
+
+impl<P: Properties> MetricSystem for Vaidya<P> {
+    fn g(x: &Point<Self>) -> TwoForm<Self> {
+        Self::g_with(P::mass(x[0]).0, x)
+    }
+
+    fn inv_g(x: &Point<Self>) -> InvTwoForm<Self> {
+        Self::inv_g_with(P::mass(x[0]).0, x)
+    }
+
+    fn christoffel(
+        x: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Self::christoffel_with(P::mass(x[0]), x)
+    }
+}
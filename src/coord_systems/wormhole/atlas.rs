@@ -0,0 +1,14 @@
+use super::{MorrisThorne, NearPole0MT, NearPolePiMT};
+use crate::Atlas;
+use std::marker::PhantomData;
+
+/// The atlas consisting of the Morris-Thorne chart and its polar patches
+pub struct MorrisThorneAtlas<P> {
+    _m: PhantomData<P>,
+}
+
+impl<P: 'static> Atlas for MorrisThorneAtlas<P> {
+    type Main = MorrisThorne<P>;
+    type Pole0 = NearPole0MT<P>;
+    type PolePi = NearPolePiMT<P>;
+}
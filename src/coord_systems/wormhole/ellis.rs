@@ -0,0 +1,25 @@
+use super::Profile;
+
+/// The Ellis drainhole without gravitational pull, r = sqrt(l^2 + b^2), Phi = 0: the simplest
+/// traversable wormhole, joining two flat asymptotic regions through a throat of radius b.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellis {
+    pub throat: f64,
+}
+
+impl Ellis {
+    pub fn new(throat: f64) -> Self {
+        Ellis { throat }
+    }
+}
+
+impl Profile for Ellis {
+    fn radius(&self, l: f64) -> (f64, f64) {
+        let r = (l * l + self.throat * self.throat).sqrt();
+        (r, l / r)
+    }
+
+    fn redshift(&self, _l: f64) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+}
@@ -0,0 +1,33 @@
+mod atlas;
+mod ellis;
+mod morris_thorne;
+mod polar_morris_thorne;
+mod runtime;
+mod shape;
+
+/// The shape of a static, spherically symmetric traversable wormhole,
+/// ds^2 = e^(2 Phi(l)) dt^2 - dl^2 - r(l)^2 dOmega^2, as functions of the proper radial distance
+/// l from the throat, which runs over the whole real line.
+pub trait Properties {
+    /// The areal radius r and its derivative dr/dl
+    fn radius(l: f64) -> (f64, f64);
+
+    /// The redshift function Phi and its derivative dPhi/dl
+    fn redshift(l: f64) -> (f64, f64);
+}
+
+/// The shape of a wormhole only known at runtime, see `Properties`
+pub trait Profile {
+    /// The areal radius r and its derivative dr/dl
+    fn radius(&self, l: f64) -> (f64, f64);
+
+    /// The redshift function Phi and its derivative dPhi/dl
+    fn redshift(&self, l: f64) -> (f64, f64);
+}
+
+pub use self::atlas::MorrisThorneAtlas;
+pub use self::ellis::Ellis;
+pub use self::morris_thorne::MorrisThorne;
+pub use self::polar_morris_thorne::{NearPole0MT, NearPolePiMT};
+pub use self::runtime::Wormhole;
+pub use self::shape::ShapeFunction;
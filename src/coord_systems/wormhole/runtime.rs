@@ -0,0 +1,88 @@
+use super::{Ellis, MorrisThorne, NearPole0MT, NearPolePiMT, Profile};
+use crate::coord_systems::Runtime;
+use crate::Spacetime;
+use diffgeom::coordinates::Point;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+
+/// A traversable wormhole with the shape only known at runtime. It provides the metric of the
+/// charts parametrized with `Runtime`.
+#[derive(Clone, Debug)]
+pub struct Wormhole<P> {
+    pub profile: P,
+}
+
+impl<P> Wormhole<P> {
+    pub fn new(profile: P) -> Self {
+        Wormhole { profile }
+    }
+}
+
+impl Wormhole<Ellis> {
+    /// The Ellis drainhole with the throat of radius `throat`
+    pub fn ellis(throat: f64) -> Self {
+        Wormhole::new(Ellis::new(throat))
+    }
+}
+
+impl<P: Profile> Spacetime<MorrisThorne<Runtime>> for Wormhole<P> {
+    fn g(&self, x: &Point<MorrisThorne<Runtime>>) -> TwoForm<MorrisThorne<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        MorrisThorne::g_with(r, phi, x)
+    }
+
+    fn inv_g(&self, x: &Point<MorrisThorne<Runtime>>) -> InvTwoForm<MorrisThorne<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        MorrisThorne::inv_g_with(r, phi, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<MorrisThorne<Runtime>>,
+    ) -> Tensor<MorrisThorne<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        MorrisThorne::christoffel_with(self.profile.radius(x[1]), self.profile.redshift(x[1]), x)
+    }
+}
+
+impl<P: Profile> Spacetime<NearPole0MT<Runtime>> for Wormhole<P> {
+    fn g(&self, x: &Point<NearPole0MT<Runtime>>) -> TwoForm<NearPole0MT<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        NearPole0MT::g_with(r, phi, x)
+    }
+
+    fn inv_g(&self, x: &Point<NearPole0MT<Runtime>>) -> InvTwoForm<NearPole0MT<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        NearPole0MT::inv_g_with(r, phi, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<NearPole0MT<Runtime>>,
+    ) -> Tensor<NearPole0MT<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPole0MT::christoffel_with(self.profile.radius(x[1]), self.profile.redshift(x[1]), x)
+    }
+}
+
+impl<P: Profile> Spacetime<NearPolePiMT<Runtime>> for Wormhole<P> {
+    fn g(&self, x: &Point<NearPolePiMT<Runtime>>) -> TwoForm<NearPolePiMT<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        NearPolePiMT::g_with(r, phi, x)
+    }
+
+    fn inv_g(&self, x: &Point<NearPolePiMT<Runtime>>) -> InvTwoForm<NearPolePiMT<Runtime>> {
+        let (r, _) = self.profile.radius(x[1]);
+        let (phi, _) = self.profile.redshift(x[1]);
+        NearPolePiMT::inv_g_with(r, phi, x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<NearPolePiMT<Runtime>>,
+    ) -> Tensor<NearPolePiMT<Runtime>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        NearPolePiMT::christoffel_with(self.profile.radius(x[1]), self.profile.redshift(x[1]), x)
+    }
+}
@@ -0,0 +1,117 @@
+use super::Profile;
+use crate::numeric::{hermite, segment};
+
+// the extent of the table, in units of the throat radius, and the number of its segments
+const R_MAX: f64 = 1e4;
+const SEGMENTS: usize = 4000;
+// the nodes and weights of the 3-point Gauss-Legendre quadrature on [0, 1]
+const GAUSS: [(f64, f64); 3] = [
+    (0.112_701_665_379_258_3, 5.0 / 18.0),
+    (0.5, 8.0 / 18.0),
+    (0.887_298_334_620_741_7, 5.0 / 18.0),
+];
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    l: f64,
+    r: f64,
+    dr: f64,
+    phi: f64,
+    dphi: f64,
+}
+
+/// A Morris-Thorne wormhole given in the usual form
+/// ds^2 = e^(2 Phi(r)) dt^2 - dr^2 / (1 - b(r)/r) - r^2 dOmega^2, with the shape function b and
+/// the redshift function Phi of the areal radius, the same on both sides of the throat.
+///
+/// The proper distance l(r) is integrated once, on construction, up to r = 1e4 times the radius of
+/// the throat; further out the wormhole is continued with constant dr/dl and Phi.
+#[derive(Clone, Debug)]
+pub struct ShapeFunction {
+    throat: f64,
+    nodes: Vec<Node>,
+}
+
+impl ShapeFunction {
+    /// Tabulates the wormhole with the throat at r = `throat`, where b(r) = r. The shape function
+    /// must satisfy b(r) < r further out and b'(r) < 1 at the throat (the flare-out condition);
+    /// `redshift` returns Phi(r) and dPhi/dr.
+    pub fn new<B, F>(throat: f64, shape: B, redshift: F) -> Self
+    where
+        B: Fn(f64) -> f64,
+        F: Fn(f64) -> (f64, f64),
+    {
+        // with r = throat + x^2 the integrand of l(x) stays finite at the throat
+        let x_max = ((R_MAX - 1.0) * throat).sqrt();
+        let slope = |r: f64| {
+            let f = 1.0 - shape(r) / r;
+            assert!(
+                f > 0.0,
+                "the shape function must satisfy b(r) < r outside of the throat"
+            );
+            f.sqrt()
+        };
+        let node = |l: f64, x: f64| {
+            let r = throat + x * x;
+            let dr = if x == 0.0 { 0.0 } else { slope(r) };
+            let (phi, dphi_dr) = redshift(r);
+            Node {
+                l,
+                r,
+                dr,
+                phi,
+                dphi: dphi_dr * dr,
+            }
+        };
+
+        let mut nodes = vec![node(0.0, 0.0)];
+        let mut l = 0.0;
+        for i in 0..SEGMENTS {
+            // the nodes are denser near the throat
+            let x0 = x_max * (i as f64 / SEGMENTS as f64).powi(2);
+            let x1 = x_max * ((i + 1) as f64 / SEGMENTS as f64).powi(2);
+            l += GAUSS
+                .iter()
+                .map(|&(s, w)| {
+                    let x = x0 + s * (x1 - x0);
+                    w * 2.0 * x / slope(throat + x * x)
+                })
+                .sum::<f64>()
+                * (x1 - x0);
+            nodes.push(node(l, x1));
+        }
+        ShapeFunction { throat, nodes }
+    }
+
+    /// The radius of the throat
+    pub fn throat(&self) -> f64 {
+        self.throat
+    }
+
+    // the radius and the redshift function with their derivatives at the distance |l| from the
+    // throat
+    fn interpolate(&self, l: f64) -> ((f64, f64), (f64, f64)) {
+        let last = self.nodes[self.nodes.len() - 1];
+        if l > last.l {
+            return ((last.r + (l - last.l) * last.dr, last.dr), (last.phi, 0.0));
+        }
+        let i = segment(&self.nodes, l, |n| n.l);
+        let (n0, n1) = (self.nodes[i], self.nodes[i + 1]);
+        (
+            hermite(l, (n0.l, n0.r, n0.dr), (n1.l, n1.r, n1.dr)),
+            hermite(l, (n0.l, n0.phi, n0.dphi), (n1.l, n1.phi, n1.dphi)),
+        )
+    }
+}
+
+impl Profile for ShapeFunction {
+    fn radius(&self, l: f64) -> (f64, f64) {
+        let ((r, dr), _) = self.interpolate(l.abs());
+        (r, dr * l.signum())
+    }
+
+    fn redshift(&self, l: f64) -> (f64, f64) {
+        let (_, (phi, dphi)) = self.interpolate(l.abs());
+        (phi, dphi * l.signum())
+    }
+}
@@ -1,10 +1,17 @@
+use crate::conserved::Symmetries;
+use crate::constraints::{self, Causality, Constraints};
+use crate::events::Body;
+use crate::frame::Frame;
 use crate::numeric::StateVector;
-use crate::typenum::consts::{U0, U1, U2, U3, U5};
+use crate::particle::PosAndVel;
+use crate::spacetime::{convert_vector, Spacetime, SpacetimeConversion, StaticSpacetime};
+use crate::trajectory::Recordable;
+use crate::typenum::consts::{U0, U1, U2, U20, U3, U4, U5};
 use crate::typenum::{Exp, Pow, Prod, Same, Unsigned};
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::inner;
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::Vector;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, Tensor, TwoForm, Vector};
 use generic_array::{ArrayLength, GenericArray};
 use numeric_algs::State;
 use std::ops::Mul;
@@ -56,7 +63,7 @@ where
         local_z: Vector<C>,
     ) -> Self {
         Entity {
-            x: x,
+            x,
             dirs: [v, local_x, local_y, local_z],
             force: [0.0; 3],
             ang_vel: [0.0; 3],
@@ -70,7 +77,15 @@ where
         Exp<C::Dimension, U2>: ArrayLength<f64>,
         Exp<C::Dimension, U3>: ArrayLength<f64>,
     {
-        let g = C::g(&self.x);
+        self.orthonormalize_with(C::g(&self.x));
+    }
+
+    fn orthonormalize_with(&mut self, g: TwoForm<C>)
+    where
+        C::Dimension: Pow<U2> + Pow<U3>,
+        Exp<C::Dimension, U2>: ArrayLength<f64>,
+        Exp<C::Dimension, U3>: ArrayLength<f64>,
+    {
         for i in 0..4 {
             let dirs_i_cov = inner!(_, Vector<C>; U1, U2; g.clone(), self.dirs[i].clone());
             for j in 0..i {
@@ -125,8 +140,8 @@ where
 
         let mut result = Vector::zero(self.x.clone());
 
-        for i in 0..4 {
-            result += gen_matrix[i][dir] * self.dirs[i].clone();
+        for (row, dir_vec) in gen_matrix.iter().zip(self.dirs.iter()) {
+            result += row[dir] * dir_vec.clone();
         }
 
         result
@@ -135,12 +150,12 @@ where
 
 impl<C: CoordinateSystem> State for Entity<C>
 where
-    C::Dimension: Pow<U1> + Mul<U2> + Unsigned,
+    C::Dimension: Pow<U1> + Mul<U5> + Unsigned,
     Exp<C::Dimension, U1>: ArrayLength<f64>,
-    Prod<C::Dimension, U2>: ArrayLength<f64>,
-    <Prod<C::Dimension, U2> as ArrayLength<f64>>::ArrayType: Copy,
+    Prod<C::Dimension, U5>: ArrayLength<f64>,
+    <Prod<C::Dimension, U5> as ArrayLength<f64>>::ArrayType: Copy,
 {
-    type Derivative = StateVector<Prod<C::Dimension, U2>>;
+    type Derivative = StateVector<Prod<C::Dimension, U5>>;
 
     fn shift_in_place(&mut self, dir: &Self::Derivative, amount: f64) {
         let d = C::Dimension::to_usize();
@@ -165,7 +180,23 @@ where
     Exp<C::Dimension, U3>: ArrayLength<f64>,
 {
     pub fn derivative(&self) -> StateVector<Prod<C::Dimension, U5>> {
-        let christoffel = C::christoffel(&self.x);
+        self.transport_derivative(C::christoffel(&self.x))
+    }
+}
+
+impl<C: CoordinateSystem> Entity<C>
+where
+    C::Dimension: Pow<U1> + Mul<U5> + Unsigned + Pow<U2> + Pow<U3>,
+    Exp<C::Dimension, U1>: ArrayLength<f64>,
+    Prod<C::Dimension, U5>: ArrayLength<f64>,
+    <Prod<C::Dimension, U5> as ArrayLength<f64>>::ArrayType: Copy,
+    Exp<C::Dimension, U2>: ArrayLength<f64>,
+    Exp<C::Dimension, U3>: ArrayLength<f64>,
+{
+    fn transport_derivative(
+        &self,
+        christoffel: Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>,
+    ) -> StateVector<Prod<C::Dimension, U5>> {
         let chr_times_v = inner!(_, Vector<C>; U1, U3; christoffel, self.dirs[0].clone());
         let mut result = GenericArray::default();
         let d = C::Dimension::to_usize();
@@ -217,12 +248,106 @@ where
     }
 }
 
-pub trait PosAndVel<D: Unsigned + ArrayLength<f64> + Pow<U1>>
-where
-    Exp<D, U1>: ArrayLength<f64>,
-{
-    fn get_pos(&self) -> &GenericArray<f64, D>;
-    fn get_vel(&self) -> &GenericArray<f64, Exp<D, U1>>;
+impl<C: CoordinateSystem<Dimension = U4>> Entity<C> {
+    /// Like `derivative`, but takes the metric from a runtime `Spacetime`
+    pub fn derivative_in<S: Spacetime<C>>(&self, spacetime: &S) -> StateVector<U20> {
+        self.transport_derivative(spacetime.christoffel(&self.x))
+    }
+
+    /// Like `orthonormalize`, but takes the metric from a runtime `Spacetime`
+    pub fn orthonormalize_in<S: Spacetime<C>>(&mut self, spacetime: &S) {
+        let g = spacetime.g(&self.x);
+        self.orthonormalize_with(g);
+    }
+
+    /// The local frame of the entity, with the spatial axes pointing forward, right and up. It
+    /// is only orthonormal if the entity has been orthonormalized.
+    pub fn frame(&self) -> Frame<C> {
+        Frame::new(self.x, self.dirs)
+    }
+
+    /// Projects the 4-velocity back onto the constraints and orthonormalizes the local basis
+    /// around it. The 4-velocity of an entity is always timelike, so `constraints.causality` is
+    /// ignored.
+    ///
+    /// Returns false if the norm couldn't be restored without changing the conserved quantities.
+    pub fn project(&mut self, constraints: &Constraints) -> bool
+    where
+        C: MetricSystem + Symmetries,
+    {
+        self.project_in(&StaticSpacetime, constraints)
+    }
+
+    /// Like `project`, but takes the metric from a runtime `Spacetime`
+    pub fn project_in<S: Spacetime<C>>(&mut self, spacetime: &S, constraints: &Constraints) -> bool
+    where
+        C: Symmetries,
+    {
+        let g = spacetime.g(&self.x);
+        let constraints = Constraints {
+            causality: Causality::Timelike,
+            ..*constraints
+        };
+        let result = constraints::project(&g, &mut self.dirs[0], &constraints);
+        self.orthonormalize_with(g);
+        result
+    }
+
+    /// Like `convert`, but for conversions depending on the parameters of a runtime `Spacetime`
+    pub fn convert_in<C2, S>(&self, spacetime: &S) -> Entity<C2>
+    where
+        C2: CoordinateSystem<Dimension = U4>,
+        S: SpacetimeConversion<C, C2>,
+    {
+        let jacobian = spacetime.jacobian(&self.x);
+        let new_dirs: [Vector<C2>; 4] = [
+            convert_vector(&jacobian, &self.dirs[0]),
+            convert_vector(&jacobian, &self.dirs[1]),
+            convert_vector(&jacobian, &self.dirs[2]),
+            convert_vector(&jacobian, &self.dirs[3]),
+        ];
+        Entity {
+            x: spacetime.convert_point(&self.x),
+            dirs: new_dirs,
+            force: self.force,
+            ang_vel: self.ang_vel,
+        }
+    }
+}
+
+impl<C: Symmetries> Body for Entity<C> {
+    fn time_coordinate(&self) -> f64 {
+        self.x[0]
+    }
+
+    fn radius(&self) -> f64 {
+        C::radius(&self.x)
+    }
+
+    fn cos_theta(&self) -> f64 {
+        C::cos_theta(&self.x)
+    }
+}
+
+impl<C: CoordinateSystem<Dimension = U4>> Recordable for Entity<C> {
+    fn position(&self) -> [f64; 4] {
+        [self.x[0], self.x[1], self.x[2], self.x[3]]
+    }
+
+    fn velocity(&self) -> [f64; 4] {
+        let v = &self.dirs[0];
+        [v[0], v[1], v[2], v[3]]
+    }
+
+    fn tetrad(&self) -> Option<[[f64; 4]; 3]> {
+        let mut result = [[0.0; 4]; 3];
+        for (axis, dir) in result.iter_mut().zip(self.dirs[1..].iter()) {
+            for i in 0..4 {
+                axis[i] = dir[i];
+            }
+        }
+        Some(result)
+    }
 }
 
 impl<C: CoordinateSystem> PosAndVel<C::Dimension> for Entity<C>
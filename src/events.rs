@@ -0,0 +1,355 @@
+use crate::numeric::{DenseDPIntegrator, DenseStep};
+use numeric_algs::integration::StepSize;
+use numeric_algs::State;
+
+// the limit of iterations when locating an event within a step
+const MAX_ITERATIONS: usize = 100;
+
+/// A body moving through a stationary, axisymmetric spacetime, whose position can be checked by
+/// the predefined events
+pub trait Body: State {
+    /// The coordinate x^0 of the chart the body is expressed in, eg. the advanced time in the
+    /// Eddington-Finkelstein charts; it changes meaning when an atlas body switches charts
+    fn time_coordinate(&self) -> f64;
+
+    /// The radial coordinate r
+    fn radius(&self) -> f64;
+
+    /// The cosine of the polar angle theta
+    fn cos_theta(&self) -> f64;
+}
+
+/// The direction in which an event function has to cross zero to trigger the event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crossing {
+    /// From negative to positive values
+    Rising,
+    /// From positive to negative values
+    Falling,
+    /// In either direction
+    Any,
+}
+
+impl Crossing {
+    fn matches(self, before: f64, after: f64) -> Option<Crossing> {
+        let crossing = if before < 0.0 && after >= 0.0 {
+            Crossing::Rising
+        } else if before > 0.0 && after <= 0.0 {
+            Crossing::Falling
+        } else {
+            return None;
+        };
+        if self == Crossing::Any || self == crossing {
+            Some(crossing)
+        } else {
+            None
+        }
+    }
+}
+
+/// A condition checked along a trajectory: the event happens where its function crosses zero.
+///
+/// Terminal events stop the integration; the others are only reported. The function is only
+/// compared between the ends of each step, so two crossings within one step cancel out and are
+/// both missed; the step size has to resolve them.
+pub struct Event<S> {
+    name: String,
+    function: Box<dyn Fn(&S) -> f64>,
+    direction: Crossing,
+    terminal: bool,
+}
+
+impl<S> Event<S> {
+    /// Creates a non-terminal event triggered by the function crossing zero in any direction
+    pub fn new<N: Into<String>, F: Fn(&S) -> f64 + 'static>(name: N, function: F) -> Self {
+        Event {
+            name: name.into(),
+            function: Box::new(function),
+            direction: Crossing::Any,
+            terminal: false,
+        }
+    }
+
+    pub fn with_direction(self, direction: Crossing) -> Self {
+        Event { direction, ..self }
+    }
+
+    pub fn with_terminal(self, terminal: bool) -> Self {
+        Event { terminal, ..self }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn direction(&self) -> Crossing {
+        self.direction
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+
+    pub fn value(&self, state: &S) -> f64 {
+        (self.function)(state)
+    }
+}
+
+impl<S: Body> Event<S> {
+    /// The radial coordinate reaching `r`
+    pub fn radius(r: f64) -> Self {
+        Event::new("radius", move |s: &S| s.radius() - r)
+    }
+
+    /// Crossing the equatorial plane; rising when moving from the northern hemisphere to the
+    /// southern one
+    pub fn equator() -> Self {
+        Event::new("equator", |s: &S| -s.cos_theta())
+    }
+
+    /// The coordinate x^0 of the body's chart reaching `t`
+    pub fn time_coordinate(t: f64) -> Self {
+        Event::new("time coordinate", move |s: &S| s.time_coordinate() - t)
+    }
+
+    /// Falling through the horizon at radius `r_horizon`; terminal
+    pub fn horizon(r_horizon: f64) -> Self {
+        Event::new("horizon", move |s: &S| s.radius() - r_horizon)
+            .with_direction(Crossing::Falling)
+            .with_terminal(true)
+    }
+}
+
+/// An occurrence of an event, located within a step
+pub struct EventHit<S> {
+    /// The index of the event, in the order it was added to the driver
+    pub index: usize,
+    /// The name of the event
+    pub name: String,
+    /// The direction in which the event function crossed zero
+    pub crossing: Crossing,
+    /// Whether the event stopped the integration
+    pub terminal: bool,
+    /// The value of the parameter along the trajectory (eg. proper time) at the event
+    pub param: f64,
+    /// The state at the event
+    pub state: S,
+}
+
+impl<S: Clone> Clone for EventHit<S> {
+    fn clone(&self) -> Self {
+        EventHit {
+            index: self.index,
+            name: self.name.clone(),
+            crossing: self.crossing,
+            terminal: self.terminal,
+            param: self.param,
+            state: self.state.clone(),
+        }
+    }
+}
+
+// projects a state onto its constraints, returning false if it failed
+type Projection<S> = Box<dyn Fn(&mut S) -> bool>;
+
+/// Propagates a trajectory and watches for events, locating each of them within a step using the
+/// dense output of the integrator
+pub struct Driver<S: State> {
+    integrator: DenseDPIntegrator<S>,
+    events: Vec<Event<S>>,
+    projection: Option<Projection<S>>,
+    projection_failures: usize,
+    last_step: Option<DenseStep<S>>,
+    param: f64,
+    tolerance: f64,
+}
+
+impl<S: State> Driver<S> {
+    /// Creates a driver with no events; the events are located to the error tolerance of the
+    /// integrator
+    pub fn new(integrator: DenseDPIntegrator<S>) -> Self {
+        let tolerance = integrator.max_err();
+        Driver {
+            integrator,
+            events: Vec::new(),
+            projection: None,
+            projection_failures: 0,
+            last_step: None,
+            param: 0.0,
+            tolerance,
+        }
+    }
+
+    pub fn with_event(mut self, event: Event<S>) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Sets the precision with which the parameter of the events is located
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Driver { tolerance, ..self }
+    }
+
+    /// Projects the state onto its constraints after every step (eg. with `Particle::project_in`),
+    /// forgetting the derivative cached by the integrator. The projection returns false if it
+    /// failed, in which case the state is left as it was integrated.
+    pub fn with_projection<F: Fn(&mut S) -> bool + 'static>(self, projection: F) -> Self {
+        Driver {
+            projection: Some(Box::new(projection)),
+            ..self
+        }
+    }
+
+    /// The number of steps after which the projection failed
+    pub fn projection_failures(&self) -> usize {
+        self.projection_failures
+    }
+
+    pub fn add_event(&mut self, event: Event<S>) -> usize {
+        self.events.push(event);
+        self.events.len() - 1
+    }
+
+    pub fn events(&self) -> &[Event<S>] {
+        &self.events
+    }
+
+    /// The parameter along the trajectory, accumulated over the steps
+    pub fn param(&self) -> f64 {
+        self.param
+    }
+
+    pub fn set_param(&mut self, param: f64) {
+        self.param = param;
+    }
+
+    /// The last step made, with the dense output of the integrator; if it was stopped by a
+    /// terminal event, it ends there
+    pub fn last_step(&self) -> Option<&DenseStep<S>> {
+        self.last_step.as_ref()
+    }
+
+    /// Makes the integrator forget the derivative cached from the last step; should be called
+    /// after the state is modified between steps (eg. after projecting it onto the constraints)
+    pub fn reset(&mut self) {
+        self.integrator.forget_derivative();
+    }
+
+    /// Advances the state by a single step and returns the events that happened during it,
+    /// ordered along the trajectory. If one of them is terminal, the state is stopped at it and
+    /// it is the last one returned. The state is projected at the end, if there is a projection.
+    pub fn step<D>(&mut self, state: &mut S, diff_eq: D) -> Vec<EventHit<S>>
+    where
+        D: Fn(&S) -> S::Derivative,
+    {
+        let before: Vec<f64> = self.events.iter().map(|e| e.value(state)).collect();
+        let mut step = self.integrator.step(state, diff_eq, StepSize::UseDefault);
+
+        let mut hits: Vec<(f64, usize, Crossing)> = Vec::new();
+        for (index, (event, &value0)) in self.events.iter().zip(before.iter()).enumerate() {
+            let value1 = event.value(state);
+            if let Some(crossing) = event.direction.matches(value0, value1) {
+                let theta = self.locate(&step, event, value0, value1);
+                hits.push((theta, index, crossing));
+            }
+        }
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some(pos) = hits.iter().position(|hit| self.events[hit.1].terminal) {
+            hits.truncate(pos + 1);
+        }
+
+        let param0 = self.param;
+        self.param += step.step_size();
+        let mut result = Vec::with_capacity(hits.len());
+        let mut end = 1.0;
+        for (theta, index, crossing) in hits {
+            let event = &self.events[index];
+            let hit_state = step.at(theta);
+            let param = param0 + theta * step.step_size();
+            if event.terminal {
+                *state = hit_state.clone();
+                self.param = param;
+                end = theta;
+                // the derivative cached by the integrator belongs to the end of the full step
+                self.integrator.forget_derivative();
+            }
+            result.push(EventHit {
+                index,
+                name: event.name.clone(),
+                crossing,
+                terminal: event.terminal,
+                param,
+                state: hit_state,
+            });
+        }
+        step.truncate(end);
+        self.last_step = Some(step);
+
+        if let Some(ref projection) = self.projection {
+            let mut projected = state.clone();
+            if projection(&mut projected) {
+                *state = projected;
+                self.integrator.forget_derivative();
+            } else {
+                self.projection_failures += 1;
+            }
+        }
+        result
+    }
+
+    /// Propagates the state until a terminal event happens or `max_steps` steps are made.
+    /// Returns all the events that happened, the last of which is the terminal one if the
+    /// integration was stopped by an event.
+    pub fn run<D>(&mut self, state: &mut S, diff_eq: D, max_steps: usize) -> Vec<EventHit<S>>
+    where
+        D: Fn(&S) -> S::Derivative,
+    {
+        let mut result = Vec::new();
+        for _ in 0..max_steps {
+            let hits = self.step(state, &diff_eq);
+            let stop = hits.last().is_some_and(|hit| hit.terminal);
+            result.extend(hits);
+            if stop {
+                break;
+            }
+        }
+        result
+    }
+
+    // finds the fraction of the step at which the event function crosses zero, using the
+    // Illinois variant of the regula falsi on the dense output
+    fn locate(&self, step: &DenseStep<S>, event: &Event<S>, value0: f64, value1: f64) -> f64 {
+        let (mut a, mut fa) = (0.0, value0);
+        let (mut b, mut fb) = (1.0, value1);
+        let tolerance = self.tolerance / step.step_size().abs();
+        let mut last_side = 0;
+        for _ in 0..MAX_ITERATIONS {
+            if b - a <= tolerance || fb == 0.0 {
+                break;
+            }
+            let c = b - fb * (b - a) / (fb - fa);
+            // guard against the interpolation getting stuck at the ends of the interval
+            let c = if c > a && c < b { c } else { 0.5 * (a + b) };
+            let fc = event.value(&step.at(c));
+            if fc == 0.0 {
+                return c;
+            }
+            if (fc < 0.0) == (fb < 0.0) {
+                b = c;
+                fb = fc;
+                if last_side == 1 {
+                    fa *= 0.5;
+                }
+                last_side = 1;
+            } else {
+                a = c;
+                fa = fc;
+                if last_side == -1 {
+                    fb *= 0.5;
+                }
+                last_side = -1;
+            }
+        }
+        b
+    }
+}
@@ -0,0 +1,179 @@
+use crate::conserved::Symmetries;
+use crate::particle::Particle;
+use crate::spacetime::{contract, dot, raise, Spacetime, StaticSpacetime};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::Vector;
+
+/// The speed of a particle launched by an observer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Speed {
+    /// A massive particle moving with the given fraction of the speed of light
+    Massive(f64),
+    /// A photon; its energy measured by the observer is 1
+    Null,
+}
+
+/// The orthonormal frame of a local observer: its 4-velocity followed by three spatial axes.
+///
+/// The frames of the static observers and ZAMOs have their spatial axes pointing along growing
+/// r, theta and phi, in that order. The frame of an `Entity` uses its forward, right and up
+/// directions.
+pub struct Frame<C: CoordinateSystem<Dimension = U4>> {
+    x: Point<C>,
+    axes: [Vector<C>; 4],
+}
+
+impl<C: CoordinateSystem<Dimension = U4>> Clone for Frame<C> {
+    fn clone(&self) -> Self {
+        Frame {
+            x: self.x,
+            axes: self.axes,
+        }
+    }
+}
+
+impl<C: CoordinateSystem<Dimension = U4>> Frame<C> {
+    /// Creates a frame from 4 orthonormal vectors, the first of which is timelike
+    pub fn new(x: Point<C>, axes: [Vector<C>; 4]) -> Self {
+        Frame { x, axes }
+    }
+
+    /// The frame of the observer at rest with respect to infinity. Returns `None` where such an
+    /// observer can't exist, ie. inside the ergosphere.
+    pub fn static_observer(x: &Point<C>) -> Option<Self>
+    where
+        C: MetricSystem + Symmetries,
+    {
+        Self::static_observer_in(&StaticSpacetime, x)
+    }
+
+    /// Like `static_observer`, but takes the metric from a runtime `Spacetime`
+    pub fn static_observer_in<S: Spacetime<C>>(spacetime: &S, x: &Point<C>) -> Option<Self>
+    where
+        C: Symmetries,
+    {
+        Self::observer_in(spacetime, C::time_killing_vector(x))
+    }
+
+    /// The frame of the zero angular momentum observer, co-rotating with the spacetime. Returns
+    /// `None` where such an observer can't exist, ie. inside the horizon.
+    pub fn zamo(x: &Point<C>) -> Option<Self>
+    where
+        C: MetricSystem + Symmetries,
+    {
+        Self::zamo_in(&StaticSpacetime, x)
+    }
+
+    /// Like `zamo`, but takes the metric from a runtime `Spacetime`
+    pub fn zamo_in<S: Spacetime<C>>(spacetime: &S, x: &Point<C>) -> Option<Self>
+    where
+        C: Symmetries,
+    {
+        let g = spacetime.g(x);
+        let xi_t = C::time_killing_vector(x);
+        let xi_phi = C::axial_killing_vector(x);
+        let g_pp = dot(&g, &xi_phi, &xi_phi);
+        // on the axis the ZAMO is just the static observer
+        let omega = if g_pp == 0.0 {
+            0.0
+        } else {
+            -dot(&g, &xi_t, &xi_phi) / g_pp
+        };
+        let mut u = xi_t;
+        for i in 0..4 {
+            u[i] += omega * xi_phi[i];
+        }
+        Self::observer_in(spacetime, u)
+    }
+
+    // builds the frame of the observer with 4-velocity along `u`, using the Gram-Schmidt process
+    // on the gradients of r and of the angles, so that the result doesn't depend on the chart
+    fn observer_in<S: Spacetime<C>>(spacetime: &S, mut u: Vector<C>) -> Option<Self>
+    where
+        C: Symmetries,
+    {
+        let x = *u.get_point();
+        let g = spacetime.g(&x);
+        let inv_g = spacetime.inv_g(&x);
+
+        let norm = dot(&g, &u, &u);
+        if norm <= 0.0 {
+            return None;
+        }
+        u /= norm.sqrt();
+
+        let [d_theta, d_phi] = C::angular_coframe(&x);
+        let mut axes = [u; 4];
+        for (i, gradient) in [C::radial_gradient(&x), d_theta, d_phi].iter().enumerate() {
+            let mut e = raise(&inv_g, gradient);
+            for prev in &axes[..=i] {
+                let coeff = dot(&g, &e, prev) / dot(&g, prev, prev);
+                for j in 0..4 {
+                    e[j] -= coeff * prev[j];
+                }
+            }
+            let norm = dot(&g, &e, &e);
+            if norm >= 0.0 {
+                return None;
+            }
+            let sign = if contract(gradient, &e) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            e *= sign / (-norm).sqrt();
+            axes[i + 1] = e;
+        }
+        Some(Frame { x, axes })
+    }
+
+    pub fn get_pos(&self) -> &Point<C> {
+        &self.x
+    }
+
+    /// The 4-velocity of the observer followed by the spatial axes
+    pub fn axes(&self) -> &[Vector<C>; 4] {
+        &self.axes
+    }
+
+    /// Converts a vector from the components in the frame to the components in the chart
+    pub fn to_global(&self, local: [f64; 4]) -> Vector<C> {
+        let mut result = Vector::zero(*self.get_pos());
+        for (component, axis) in local.iter().zip(self.axes.iter()) {
+            for i in 0..4 {
+                result[i] += component * axis[i];
+            }
+        }
+        result
+    }
+
+    /// Creates a particle launched by the observer in the given direction, expressed in the
+    /// spatial axes of the frame. The direction doesn't need to be normalized, but it can only
+    /// be zero for a massive particle at rest.
+    pub fn particle(&self, direction: [f64; 3], speed: Speed) -> Particle<C> {
+        let len = direction.iter().map(|d| d * d).sum::<f64>().sqrt();
+        let (time, space) = match speed {
+            Speed::Massive(v) => {
+                assert!((0.0..1.0).contains(&v), "the speed must be in [0, 1)");
+                let gamma = 1.0 / (1.0 - v * v).sqrt();
+                (gamma, gamma * v)
+            }
+            Speed::Null => (1.0, 1.0),
+        };
+        let space = if space == 0.0 {
+            0.0
+        } else {
+            assert!(len > 0.0, "a moving particle needs a direction");
+            space / len
+        };
+        let local = [
+            time,
+            direction[0] * space,
+            direction[1] * space,
+            direction[2] * space,
+        ];
+        Particle::new(*self.get_pos(), self.to_global(local))
+    }
+}
@@ -8,10 +8,29 @@ extern crate numeric_algs;
 
 pub use generic_array::typenum;
 
+mod atlas;
+mod conserved;
+mod constraints;
 pub mod coord_systems;
 mod entity;
+mod events;
+mod frame;
 pub mod numeric;
 mod particle;
+mod render;
+mod spacetime;
+mod trajectory;
 
+pub use crate::atlas::{
+    Atlas, AtlasEntity, AtlasParticle, Chart, PoleThresholds, DEFAULT_ENTER_POLE,
+    DEFAULT_LEAVE_POLE,
+};
+pub use crate::conserved::{ConservedQuantities, DriftReport, Symmetries};
+pub use crate::constraints::{Causality, Constraints};
 pub use crate::entity::Entity;
+pub use crate::events::{Body, Crossing, Driver, Event, EventHit};
+pub use crate::frame::{Frame, Speed};
 pub use crate::particle::{Particle, PosAndVel};
+pub use crate::render::{Camera, Disk, Image, RayOutcome, Scene};
+pub use crate::spacetime::{Spacetime, SpacetimeConversion, StaticSpacetime};
+pub use crate::trajectory::{Recordable, Sample, Trajectory};
@@ -0,0 +1,223 @@
+use numeric_algs::integration::{Integrator, StepSize};
+use numeric_algs::{State, StateDerivative};
+
+// the coefficients of the continuous extension of the Dormand-Prince method: the weight of k_i
+// at the fraction theta of the step is sum_j DENSE[i][j] * theta^(j+1)
+const DENSE: [[f64; 4]; 7] = [
+    [
+        1.0,
+        -8048581381.0 / 2820520608.0,
+        8663915743.0 / 2820520608.0,
+        -12715105075.0 / 11282082432.0,
+    ],
+    [0.0, 0.0, 0.0, 0.0],
+    [
+        0.0,
+        131558114200.0 / 32700410799.0,
+        -68118460800.0 / 10900136933.0,
+        87487479700.0 / 32700410799.0,
+    ],
+    [
+        0.0,
+        -1754552775.0 / 470086768.0,
+        14199869525.0 / 1410260304.0,
+        -10690763975.0 / 1880347072.0,
+    ],
+    [
+        0.0,
+        127303824393.0 / 49829197408.0,
+        -318862633887.0 / 49829197408.0,
+        701980252875.0 / 199316789632.0,
+    ],
+    [
+        0.0,
+        -282668133.0 / 205662961.0,
+        2019193451.0 / 616988883.0,
+        -1453857185.0 / 822651844.0,
+    ],
+    [
+        0.0,
+        40617522.0 / 29380423.0,
+        -110615467.0 / 29380423.0,
+        69997945.0 / 29380423.0,
+    ],
+];
+
+/// A single step of `DenseDPIntegrator`, which can reconstruct the state anywhere within the
+/// step with 4th order accuracy
+pub struct DenseStep<S: State> {
+    start: S,
+    h: f64,
+    // the fraction of the integrator's step this covers, less than 1 if it was cut short
+    end: f64,
+    k: Vec<S::Derivative>,
+}
+
+impl<S: State> DenseStep<S> {
+    /// The state at the beginning of the step
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The length of the step
+    pub fn step_size(&self) -> f64 {
+        self.h * self.end
+    }
+
+    /// The state at the fraction `theta` (between 0 and 1) of the step
+    pub fn at(&self, theta: f64) -> S {
+        let theta = theta * self.end;
+        let mut dir = self.k[0].clone() * 0.0;
+        for (k, coeffs) in self.k.iter().zip(DENSE.iter()) {
+            let mut weight = 0.0;
+            let mut power = 1.0;
+            for c in coeffs {
+                power *= theta;
+                weight += c * power;
+            }
+            if weight != 0.0 {
+                dir = dir + k.clone() * weight;
+            }
+        }
+        self.start.shift(&dir, self.h)
+    }
+
+    // cuts the step short at the fraction `theta`, which becomes its end
+    pub(crate) fn truncate(&mut self, theta: f64) {
+        self.end *= theta;
+    }
+}
+
+/// An adaptive Dormand-Prince integrator, working like `numeric_algs::DPIntegrator`, which
+/// additionally exposes its steps for dense output
+pub struct DenseDPIntegrator<S: State> {
+    default_step: f64,
+    original_default_step: f64,
+    max_err: f64,
+    min_step: f64,
+    max_step: f64,
+    last_derivative: Option<S::Derivative>,
+}
+
+impl<S: State> DenseDPIntegrator<S> {
+    pub fn new(default_step: f64, min_step: f64, max_step: f64, max_err: f64) -> Self {
+        DenseDPIntegrator {
+            default_step,
+            original_default_step: default_step,
+            min_step,
+            max_step,
+            max_err,
+            last_derivative: None,
+        }
+    }
+
+    /// The error allowed in a single step
+    pub fn max_err(&self) -> f64 {
+        self.max_err
+    }
+
+    pub fn reset_default_step(&mut self) {
+        self.default_step = self.original_default_step;
+    }
+
+    /// Forgets the cached derivative and the adapted step size; should be called after the state
+    /// is modified between steps
+    pub fn reset(&mut self) {
+        self.forget_derivative();
+        self.reset_default_step();
+    }
+
+    // forgets only the cached derivative, keeping the step size
+    pub(crate) fn forget_derivative(&mut self) {
+        self.last_derivative = None;
+    }
+
+    /// Advances the state by a single step and returns the information needed to reconstruct it
+    /// anywhere within the step
+    pub fn step<D>(&mut self, state: &mut S, diff_eq: D, step_size: StepSize) -> DenseStep<S>
+    where
+        D: Fn(&S) -> S::Derivative,
+    {
+        let k1 = match self.last_derivative.take() {
+            Some(derivative) => derivative,
+            None => diff_eq(state),
+        };
+
+        loop {
+            let h = match step_size {
+                StepSize::UseDefault => self.default_step,
+                StepSize::Step(x) => x,
+            };
+
+            let k2 = diff_eq(&state.shift(&(k1.clone() / 5.0), h));
+            let k3 = diff_eq(&state.shift(&(k1.clone() * 3.0 / 40.0 + k2.clone() * 9.0 / 40.0), h));
+            let k4 = diff_eq(&state.shift(
+                &(k1.clone() * 44.0 / 45.0 - k2.clone() * 56.0 / 15.0 + k3.clone() * 32.0 / 9.0),
+                h,
+            ));
+            let k5 = diff_eq(&state.shift(
+                &(k1.clone() * 19372.0 / 6561.0 - k2.clone() * 25360.0 / 2187.0
+                    + k3.clone() * 64448.0 / 6561.0
+                    - k4.clone() * 212.0 / 729.0),
+                h,
+            ));
+            let k6 = diff_eq(&state.shift(
+                &(k1.clone() * 9017.0 / 3168.0 - k2.clone() * 355.0 / 33.0
+                    + k3.clone() * 46732.0 / 5247.0
+                    + k4.clone() * 49.0 / 176.0
+                    - k5.clone() * 5103.0 / 18656.0),
+                h,
+            ));
+
+            let new_state = state.shift(
+                &(k1.clone() * 35.0 / 384.0
+                    + k3.clone() * 500.0 / 1113.0
+                    + k4.clone() * 125.0 / 192.0
+                    - k5.clone() * 2187.0 / 6784.0
+                    + k6.clone() * 11.0 / 84.0),
+                h,
+            );
+
+            let k7 = diff_eq(&new_state);
+
+            let error = ((k1.clone() * 71.0 / 57600.0 - k3.clone() * 71.0 / 16695.0
+                + k4.clone() * 71.0 / 1920.0
+                - k5.clone() * 17253.0 / 339200.0
+                + k6.clone() * 22.0 / 525.0
+                - k7.clone() / 40.0)
+                * h)
+                .abs();
+
+            if error != 0.0 {
+                self.default_step = h * (self.max_err / error).powf(0.25);
+            } else {
+                self.default_step = self.max_step;
+            }
+            self.default_step = self.default_step.max(self.min_step).min(self.max_step);
+
+            // If the step adjustment makes it much smaller, repeat the calculation to avoid
+            // larger errors
+            if self.default_step < 0.8 * h && step_size == StepSize::UseDefault {
+                continue;
+            }
+
+            let start = std::mem::replace(state, new_state);
+            self.last_derivative = Some(k7.clone());
+            return DenseStep {
+                start,
+                h,
+                end: 1.0,
+                k: vec![k1, k2, k3, k4, k5, k6, k7],
+            };
+        }
+    }
+}
+
+impl<S: State> Integrator<S> for DenseDPIntegrator<S> {
+    fn propagate_in_place<D>(&mut self, start: &mut S, diff_eq: D, step_size: StepSize)
+    where
+        D: Fn(&S) -> S::Derivative,
+    {
+        self.step(start, diff_eq, step_size);
+    }
+}
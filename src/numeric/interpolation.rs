@@ -0,0 +1,22 @@
+// the cubic Hermite interpolation between two points given as (x, y, dy/dx); returns y and dy/dx
+pub(crate) fn hermite(x: f64, p0: (f64, f64, f64), p1: (f64, f64, f64)) -> (f64, f64) {
+    let h = p1.0 - p0.0;
+    let s = (x - p0.0) / h;
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let value = (2.0 * s3 - 3.0 * s2 + 1.0) * p0.1
+        + (s3 - 2.0 * s2 + s) * h * p0.2
+        + (-2.0 * s3 + 3.0 * s2) * p1.1
+        + (s3 - s2) * h * p1.2;
+    let derivative = (6.0 * s2 - 6.0 * s) * (p0.1 - p1.1) / h
+        + (3.0 * s2 - 4.0 * s + 1.0) * p0.2
+        + (3.0 * s2 - 2.0 * s) * p1.2;
+    (value, derivative)
+}
+
+// the index i of the segment [nodes[i], nodes[i + 1]] of a table containing `value` of the
+// monotonic column `column`; values beyond the ends fall into the first or last segment
+pub(crate) fn segment<T, F: Fn(&T) -> f64>(nodes: &[T], value: f64, column: F) -> usize {
+    let i = nodes.partition_point(|n| column(n) <= value);
+    i.max(1).min(nodes.len() - 1) - 1
+}
@@ -1,3 +1,7 @@
+mod dense;
+mod interpolation;
 mod state_vector;
 
+pub use self::dense::{DenseDPIntegrator, DenseStep};
+pub(crate) use self::interpolation::{hermite, segment};
 pub use self::state_vector::StateVector;
@@ -1,10 +1,15 @@
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::constraints::{self, Causality, Constraints};
+use crate::events::Body;
 use crate::numeric::StateVector;
-use crate::typenum::consts::{U1, U2, U3};
+use crate::spacetime::{convert_vector, Spacetime, SpacetimeConversion, StaticSpacetime};
+use crate::trajectory::Recordable;
+use crate::typenum::consts::{U1, U2, U3, U4, U8};
 use crate::typenum::{Exp, Pow, Prod, Same, Unsigned};
 use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
 use diffgeom::inner;
 use diffgeom::metric::MetricSystem;
-use diffgeom::tensors::Vector;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, Tensor, Vector};
 use generic_array::{ArrayLength, GenericArray};
 use numeric_algs::State;
 use std::ops::Mul;
@@ -37,7 +42,7 @@ where
     Exp<C::Dimension, U1>: ArrayLength<f64>,
 {
     pub fn new(x: Point<C>, v: Vector<C>) -> Self {
-        Particle { x: x, v: v }
+        Particle { x, v }
     }
 
     pub fn get_pos(&self) -> &Point<C> {
@@ -79,7 +84,23 @@ where
     Exp<C::Dimension, U3>: ArrayLength<f64>,
 {
     pub fn derivative(&self) -> StateVector<Prod<C::Dimension, U2>> {
-        let christoffel = C::christoffel(&self.x);
+        self.geodesic_derivative(C::christoffel(&self.x))
+    }
+}
+
+impl<C: CoordinateSystem> Particle<C>
+where
+    C::Dimension: Pow<U1> + Mul<U2> + Unsigned + Pow<U2> + Pow<U3>,
+    Exp<C::Dimension, U1>: ArrayLength<f64>,
+    Prod<C::Dimension, U2>: ArrayLength<f64>,
+    <Prod<C::Dimension, U2> as ArrayLength<f64>>::ArrayType: Copy,
+    Exp<C::Dimension, U2>: ArrayLength<f64>,
+    Exp<C::Dimension, U3>: ArrayLength<f64>,
+{
+    fn geodesic_derivative(
+        &self,
+        christoffel: Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>,
+    ) -> StateVector<Prod<C::Dimension, U2>> {
         let temp = inner!(_, Vector<C>; U1, U3; christoffel, self.v.clone());
         let cov_der = inner!(_, Vector<C>; U1, U2; temp, self.v.clone());
         let mut result = GenericArray::default();
@@ -111,6 +132,76 @@ where
     }
 }
 
+impl<C: CoordinateSystem<Dimension = U4>> Particle<C> {
+    /// Like `derivative`, but takes the metric from a runtime `Spacetime`
+    pub fn derivative_in<S: Spacetime<C>>(&self, spacetime: &S) -> StateVector<U8> {
+        self.geodesic_derivative(spacetime.christoffel(&self.x))
+    }
+
+    /// Restores the norm of the 4-velocity, which drifts during integration. Integrators
+    /// caching the derivative between steps (like `DPIntegrator`) should be reset afterwards.
+    pub fn normalize(&mut self, causality: Causality)
+    where
+        C: MetricSystem,
+    {
+        self.normalize_in(&StaticSpacetime, causality);
+    }
+
+    /// Like `normalize`, but takes the metric from a runtime `Spacetime`
+    pub fn normalize_in<S: Spacetime<C>>(&mut self, spacetime: &S, causality: Causality) {
+        constraints::normalize(&spacetime.g(&self.x), &mut self.v, causality);
+    }
+
+    /// Projects the 4-velocity back onto the constraints: its norm and, optionally, fixed values
+    /// of the Killing energy and angular momentum.
+    ///
+    /// Returns false if the norm couldn't be restored without changing the conserved quantities.
+    /// Integrators caching the derivative between steps should be reset afterwards.
+    pub fn project(&mut self, constraints: &Constraints) -> bool
+    where
+        C: MetricSystem + Symmetries,
+    {
+        self.project_in(&StaticSpacetime, constraints)
+    }
+
+    /// Like `project`, but takes the metric from a runtime `Spacetime`
+    pub fn project_in<S: Spacetime<C>>(&mut self, spacetime: &S, constraints: &Constraints) -> bool
+    where
+        C: Symmetries,
+    {
+        constraints::project(&spacetime.g(&self.x), &mut self.v, constraints)
+    }
+
+    /// Calculates the quantities conserved along the geodesic
+    pub fn conserved(&self) -> ConservedQuantities
+    where
+        C: MetricSystem + Symmetries,
+    {
+        self.conserved_in(&StaticSpacetime)
+    }
+
+    /// Like `conserved`, but takes the metric from a runtime `Spacetime`
+    pub fn conserved_in<S: Spacetime<C>>(&self, spacetime: &S) -> ConservedQuantities
+    where
+        C: Symmetries,
+    {
+        ConservedQuantities::calculate(spacetime, &self.x, &self.v)
+    }
+
+    /// Like `convert`, but for conversions depending on the parameters of a runtime `Spacetime`
+    pub fn convert_in<C2, S>(&self, spacetime: &S) -> Particle<C2>
+    where
+        C2: CoordinateSystem<Dimension = U4>,
+        S: SpacetimeConversion<C, C2>,
+    {
+        let jacobian = spacetime.jacobian(&self.x);
+        Particle {
+            x: spacetime.convert_point(&self.x),
+            v: convert_vector(&jacobian, &self.v),
+        }
+    }
+}
+
 pub trait PosAndVel<D: Unsigned + ArrayLength<f64> + Pow<U1>>
 where
     Exp<D, U1>: ArrayLength<f64>,
@@ -119,6 +210,30 @@ where
     fn get_vel(&self) -> &GenericArray<f64, Exp<D, U1>>;
 }
 
+impl<C: Symmetries> Body for Particle<C> {
+    fn time_coordinate(&self) -> f64 {
+        self.x[0]
+    }
+
+    fn radius(&self) -> f64 {
+        C::radius(&self.x)
+    }
+
+    fn cos_theta(&self) -> f64 {
+        C::cos_theta(&self.x)
+    }
+}
+
+impl<C: CoordinateSystem<Dimension = U4>> Recordable for Particle<C> {
+    fn position(&self) -> [f64; 4] {
+        [self.x[0], self.x[1], self.x[2], self.x[3]]
+    }
+
+    fn velocity(&self) -> [f64; 4] {
+        [self.v[0], self.v[1], self.v[2], self.v[3]]
+    }
+}
+
 impl<C: CoordinateSystem> PosAndVel<C::Dimension> for Particle<C>
 where
     C::Dimension: Pow<U1>,
@@ -0,0 +1,337 @@
+use crate::atlas::{Atlas, AtlasParticle};
+use crate::conserved::Symmetries;
+use crate::entity::Entity;
+use crate::events::{Body, Crossing, Driver, Event};
+use crate::frame::Frame;
+use crate::numeric::DenseDPIntegrator;
+use crate::particle::Particle;
+use crate::spacetime::Spacetime;
+use std::f64::consts::PI;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// the default height of the capture radius above the horizon, as a fraction of its radius
+const CAPTURE_MARGIN: f64 = 0.01;
+
+/// A pinhole camera carried by an observer. It looks along the forward direction of the
+/// observer's frame, with the right and up directions spanning the image plane.
+pub struct Camera<A: Atlas> {
+    frame: Frame<A::Main>,
+    width: usize,
+    height: usize,
+    fov: f64,
+    threads: usize,
+}
+
+impl<A: Atlas> Camera<A> {
+    /// Creates a camera from the tetrad of an entity; `fov` is the vertical field of view in
+    /// radians. The entity should be orthonormalized.
+    pub fn new(entity: &Entity<A::Main>, width: usize, height: usize, fov: f64) -> Self {
+        Self::from_frame(entity.frame(), width, height, fov)
+    }
+
+    /// Like `new`, but takes the axes (forward, right, up) from a frame
+    pub fn from_frame(frame: Frame<A::Main>, width: usize, height: usize, fov: f64) -> Self {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        Camera {
+            frame,
+            width,
+            height,
+            fov,
+            threads,
+        }
+    }
+
+    /// Sets the number of threads used for rendering
+    pub fn with_threads(self, threads: usize) -> Self {
+        Camera {
+            threads: threads.max(1),
+            ..self
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The photon arriving at the center of the given pixel, with the 4-velocity reversed so that
+    /// integrating it traces the ray back to its source
+    pub fn ray(&self, column: usize, row: usize) -> Particle<A::Main> {
+        let scale = (0.5 * self.fov).tan();
+        let aspect = self.width as f64 / self.height as f64;
+        let x = (2.0 * (column as f64 + 0.5) / self.width as f64 - 1.0) * scale * aspect;
+        let y = (1.0 - 2.0 * (row as f64 + 0.5) / self.height as f64) * scale;
+        let len = (1.0 + x * x + y * y).sqrt();
+        let v = self.frame.to_global([-1.0, 1.0 / len, x / len, y / len]);
+        Particle::new(*self.frame.get_pos(), v)
+    }
+
+    /// Traces the ray of every pixel and colors it according to its fate, using all the threads
+    /// of the camera
+    pub fn render<S, F>(&self, scene: &Scene, spacetime: &S, color: F) -> Image
+    where
+        A::Main: Symmetries,
+        A::Pole0: Symmetries,
+        A::PolePi: Symmetries,
+        Frame<A::Main>: Sync,
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi> + Sync,
+        F: Fn(&RayOutcome) -> [u8; 3] + Sync,
+    {
+        let next_row = AtomicUsize::new(0);
+        let mut rows: Vec<(usize, Vec<[u8; 3]>)> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let row = next_row.fetch_add(1, Ordering::Relaxed);
+                            if row >= self.height {
+                                return done;
+                            }
+                            let pixels = (0..self.width)
+                                .map(|column| {
+                                    color(&scene.trace::<A, S>(spacetime, self.ray(column, row)))
+                                })
+                                .collect();
+                            done.push((row, pixels));
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("a rendering thread panicked"))
+                .collect()
+        });
+        rows.sort_by_key(|&(row, _)| row);
+
+        let mut image = Image::new(self.width, self.height);
+        for (row, pixels) in rows {
+            image.pixels[row * self.width..(row + 1) * self.width].copy_from_slice(&pixels);
+        }
+        image
+    }
+}
+
+/// A thin disk in the equatorial plane
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Disk {
+    pub inner_radius: f64,
+    pub outer_radius: f64,
+}
+
+/// What the camera looks at: a black hole, optionally surrounded by a disk, and a celestial
+/// sphere far away
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scene {
+    /// The radius of the (outer) horizon
+    pub horizon_radius: f64,
+    /// The radius below which rays are considered captured. Traced back, rays only approach the
+    /// horizon asymptotically, so it has to lie above it, but also below the innermost photon
+    /// orbit for the image to stay the same. The default 1% above the horizon only satisfies that
+    /// up to a spin of about 0.995 for Kerr.
+    pub capture_radius: f64,
+    /// The radius at which rays are considered to have escaped to the celestial sphere
+    pub escape_radius: f64,
+    pub disk: Option<Disk>,
+    /// The limit of integration steps for a single ray
+    pub max_steps: usize,
+    /// The error allowed in a single integration step
+    pub tolerance: f64,
+}
+
+impl Scene {
+    pub fn new(horizon_radius: f64, escape_radius: f64) -> Self {
+        Scene {
+            horizon_radius,
+            capture_radius: horizon_radius * (1.0 + CAPTURE_MARGIN),
+            escape_radius,
+            disk: None,
+            max_steps: 10000,
+            tolerance: 1e-8,
+        }
+    }
+
+    pub fn with_disk(self, inner_radius: f64, outer_radius: f64) -> Self {
+        Scene {
+            disk: Some(Disk {
+                inner_radius,
+                outer_radius,
+            }),
+            ..self
+        }
+    }
+
+    pub fn with_capture_radius(self, capture_radius: f64) -> Self {
+        assert!(
+            capture_radius > self.horizon_radius,
+            "the capture radius must lie above the horizon"
+        );
+        Scene {
+            capture_radius,
+            ..self
+        }
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Scene { max_steps, ..self }
+    }
+
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Scene { tolerance, ..self }
+    }
+
+    /// Follows a ray (see `Camera::ray`) until it is captured, escapes or hits the disk
+    pub fn trace<A, S>(&self, spacetime: &S, ray: Particle<A::Main>) -> RayOutcome
+    where
+        A: Atlas,
+        A::Main: Symmetries,
+        A::Pole0: Symmetries,
+        A::PolePi: Symmetries,
+        S: Spacetime<A::Main> + Spacetime<A::Pole0> + Spacetime<A::PolePi>,
+    {
+        const HORIZON: usize = 0;
+        const ESCAPE: usize = 1;
+
+        let mut photon = AtlasParticle::<A>::new(ray);
+        let max_step = 0.05 * self.escape_radius;
+        let integrator = DenseDPIntegrator::new(0.1, 1e-6, max_step, self.tolerance);
+        let mut driver = Driver::new(integrator)
+            .with_event(Event::horizon(self.capture_radius))
+            .with_event(
+                Event::radius(self.escape_radius)
+                    .with_direction(Crossing::Rising)
+                    .with_terminal(true),
+            );
+        if self.disk.is_some() {
+            driver.add_event(Event::equator());
+        }
+
+        let diff_eq = |p: &AtlasParticle<A>| p.derivative_in(spacetime);
+        for _ in 0..self.max_steps {
+            for hit in driver.step(&mut photon, diff_eq) {
+                let pos = hit.state.get_pos();
+                match hit.index {
+                    HORIZON => return RayOutcome::Captured,
+                    ESCAPE => {
+                        return RayOutcome::Escaped {
+                            theta: pos[2],
+                            phi: normalize_angle(pos[3]),
+                        }
+                    }
+                    _ => {
+                        let r = hit.state.radius();
+                        if let Some(disk) = self.disk {
+                            if r >= disk.inner_radius && r <= disk.outer_radius {
+                                return RayOutcome::Disk {
+                                    r,
+                                    phi: normalize_angle(pos[3]),
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+            if photon.update_chart() {
+                driver.reset();
+            }
+        }
+        RayOutcome::Unfinished
+    }
+}
+
+// brings an angle into [0, 2pi)
+fn normalize_angle(phi: f64) -> f64 {
+    phi.rem_euclid(2.0 * PI)
+}
+
+/// The fate of a ray traced back from the camera
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RayOutcome {
+    /// The ray came out of the black hole - the pixel belongs to the shadow
+    Captured,
+    /// The ray came from the celestial sphere, at the given angles
+    Escaped { theta: f64, phi: f64 },
+    /// The ray came from the disk, at the given radius and angle
+    Disk { r: f64, phi: f64 },
+    /// The ray was still being traced after the maximal number of steps
+    Unfinished,
+}
+
+impl RayOutcome {
+    /// A simple coloring: a black shadow, a checkered celestial sphere and a checkered disk
+    /// getting dimmer away from the black hole
+    pub fn default_color(&self) -> [u8; 3] {
+        match *self {
+            RayOutcome::Captured => [0, 0, 0],
+            RayOutcome::Escaped { theta, phi } => {
+                let checker = ((theta / PI * 9.0) as i64 + (phi / PI * 9.0) as i64) % 2 == 0;
+                if checker {
+                    [40, 60, 150]
+                } else {
+                    [200, 200, 230]
+                }
+            }
+            RayOutcome::Disk { r, phi } => {
+                let brightness = (6.0 / r).min(1.0).powf(0.75);
+                let checker = if (phi / PI * 8.0) as i64 % 2 == 0 {
+                    1.0
+                } else {
+                    0.7
+                };
+                let scale = 255.0 * brightness * checker;
+                [scale as u8, (0.6 * scale) as u8, (0.2 * scale) as u8]
+            }
+            RayOutcome::Unfinished => [255, 0, 255],
+        }
+    }
+}
+
+/// An RGB image
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    /// Creates a black image
+    pub fn new(width: usize, height: usize) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![[0; 3]; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, column: usize, row: usize) -> [u8; 3] {
+        self.pixels[row * self.width + column]
+    }
+
+    pub fn set(&mut self, column: usize, row: usize, color: [u8; 3]) {
+        self.pixels[row * self.width + column] = color;
+    }
+
+    /// Writes the image in the binary PPM format
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for pixel in &self.pixels {
+            out.write_all(pixel)?;
+        }
+        out.flush()
+    }
+}
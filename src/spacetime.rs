@@ -0,0 +1,210 @@
+use crate::conserved::{ConservedQuantities, Symmetries};
+use crate::typenum::consts::U4;
+use diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use diffgeom::metric::MetricSystem;
+use diffgeom::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, InvTwoForm, Matrix, Tensor, TwoForm, Vector,
+};
+
+/// A metric in the coordinate system `C` whose parameters are only known at runtime.
+///
+/// This is the runtime counterpart of `MetricSystem`: a single type can describe a whole family
+/// of spacetimes (for example Kerr black holes with any mass and spin), with the parameters
+/// stored in `self`.
+pub trait Spacetime<C: CoordinateSystem<Dimension = U4>> {
+    /// Returns the metric tensor at a given point.
+    fn g(&self, x: &Point<C>) -> TwoForm<C>;
+
+    /// Returns the inverse metric tensor at a given point.
+    fn inv_g(&self, x: &Point<C>) -> InvTwoForm<C>;
+
+    /// Returns the Christoffel symbols at a given point.
+    ///
+    /// The default implementation differentiates the metric numerically.
+    fn christoffel(
+        &self,
+        x: &Point<C>,
+    ) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let h = C::small(x);
+        // dg[i][j][k] = dg_ij/dx^k
+        let mut dg = [[[0.0; 4]; 4]; 4];
+        for k in 0..4 {
+            let mut x1 = *x;
+            x1[k] -= h;
+            let g1 = self.g(&x1);
+            let mut x2 = *x;
+            x2[k] += h;
+            let g2 = self.g(&x2);
+            for i in 0..4 {
+                for j in 0..4 {
+                    dg[i][j][k] = (g2[i * 4 + j] - g1[i * 4 + j]) / (2.0 * h);
+                }
+            }
+        }
+
+        christoffel_from_dg(x, &self.inv_g(x), &dg)
+    }
+
+    /// Returns the Carter constant of a geodesic with the momentum `p`, given its other conserved
+    /// quantities.
+    ///
+    /// The default implementation leaves it to the chart, which knows the parameters of the
+    /// static spacetimes; the runtime spacetimes of rotating black holes override it.
+    fn carter_constant(&self, p: &Covector<C>, conserved: &ConservedQuantities) -> f64
+    where
+        C: Symmetries,
+    {
+        C::carter_constant(p, conserved)
+    }
+}
+
+/// A conversion between two coordinate systems that depends on the runtime parameters of a
+/// spacetime; the runtime counterpart of `ConversionTo`.
+pub trait SpacetimeConversion<C1, C2>
+where
+    C1: CoordinateSystem<Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4>,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2>;
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2>;
+}
+
+/// The `Spacetime` of any `MetricSystem`, which already knows its parameters statically.
+///
+/// It lets code written against `Spacetime` also run with the zero-cost static metrics.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StaticSpacetime;
+
+impl<C: MetricSystem<Dimension = U4>> Spacetime<C> for StaticSpacetime {
+    fn g(&self, x: &Point<C>) -> TwoForm<C> {
+        C::g(x)
+    }
+
+    fn inv_g(&self, x: &Point<C>) -> InvTwoForm<C> {
+        C::inv_g(x)
+    }
+
+    fn christoffel(
+        &self,
+        x: &Point<C>,
+    ) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        C::christoffel(x)
+    }
+}
+
+impl<C1, C2> SpacetimeConversion<C1, C2> for StaticSpacetime
+where
+    C1: ConversionTo<C2, Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    fn convert_point(&self, p: &Point<C1>) -> Point<C2> {
+        C1::convert_point(p)
+    }
+
+    fn jacobian(&self, p: &Point<C1>) -> Matrix<C2> {
+        C1::jacobian(p)
+    }
+}
+
+// transforms a vector with a jacobian obtained from a `SpacetimeConversion`
+pub(crate) fn convert_vector<C1, C2>(jacobian: &Matrix<C2>, v: &Vector<C1>) -> Vector<C2>
+where
+    C1: CoordinateSystem<Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = Vector::zero(*jacobian.get_point());
+    for i in 0..4 {
+        for j in 0..4 {
+            result[i] += jacobian[i * 4 + j] * v[j];
+        }
+    }
+    result
+}
+
+// the scalar product g(a, b)
+pub(crate) fn dot<C>(g: &TwoForm<C>, a: &Vector<C>, b: &Vector<C>) -> f64
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = 0.0;
+    for i in 0..4 {
+        for j in 0..4 {
+            result += g[i * 4 + j] * a[i] * b[j];
+        }
+    }
+    result
+}
+
+// lowers the index of a vector, g(v, .)
+pub(crate) fn lower<C>(g: &TwoForm<C>, v: &Vector<C>) -> Covector<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = Covector::zero(*v.get_point());
+    for i in 0..4 {
+        for j in 0..4 {
+            result[i] += g[i * 4 + j] * v[j];
+        }
+    }
+    result
+}
+
+// raises the index of a covector with the inverse metric
+pub(crate) fn raise<C>(inv_g: &InvTwoForm<C>, p: &Covector<C>) -> Vector<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = Vector::zero(*p.get_point());
+    for i in 0..4 {
+        for j in 0..4 {
+            result[i] += inv_g[i * 4 + j] * p[j];
+        }
+    }
+    result
+}
+
+// the value p(v) of a covector on a vector
+pub(crate) fn contract<C>(p: &Covector<C>, v: &Vector<C>) -> f64
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    (0..4).map(|i| p[i] * v[i]).sum()
+}
+
+// returns ka * a + kb * b
+pub(crate) fn combine<C>(ka: f64, a: &Vector<C>, kb: f64, b: &Vector<C>) -> Vector<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = *a;
+    for i in 0..4 {
+        result[i] = ka * a[i] + kb * b[i];
+    }
+    result
+}
+
+// calculates the Christoffel symbols from the inverse metric and the derivatives of the metric,
+// given as dg[i][j][k] = dg_ij/dx^k
+pub(crate) fn christoffel_from_dg<C>(
+    x: &Point<C>,
+    inv_g: &InvTwoForm<C>,
+    dg: &[[[f64; 4]; 4]; 4],
+) -> Tensor<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+where
+    C: CoordinateSystem<Dimension = U4>,
+{
+    let mut result = Tensor::<C, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::zero(*x);
+    for i in 0..4 {
+        for j in 0..4 {
+            for k in 0..4 {
+                let mut sum = 0.0;
+                for l in 0..4 {
+                    sum += inv_g[i * 4 + l] * (dg[l][j][k] + dg[l][k][j] - dg[j][k][l]);
+                }
+                result[i * 16 + j * 4 + k] = 0.5 * sum;
+            }
+        }
+    }
+    result
+}
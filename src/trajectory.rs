@@ -0,0 +1,276 @@
+use crate::atlas::Chart;
+use crate::events::Driver;
+use crate::numeric::hermite;
+use numeric_algs::State;
+use std::io::{self, Write};
+
+/// An object whose state can be stored in a `Trajectory`
+pub trait Recordable {
+    /// The chart the coordinates are expressed in; objects that don't switch charts are always in
+    /// the main one
+    fn chart(&self) -> Chart {
+        Chart::Main
+    }
+
+    fn position(&self) -> [f64; 4];
+
+    fn velocity(&self) -> [f64; 4];
+
+    /// The spatial axes of the local frame (forward, right, up), if the object has one
+    fn tetrad(&self) -> Option<[[f64; 4]; 3]> {
+        None
+    }
+}
+
+/// A single recorded state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+    /// The parameter along the trajectory: proper time for massive bodies, affine parameter for
+    /// light
+    pub param: f64,
+    pub chart: Chart,
+    pub position: [f64; 4],
+    pub velocity: [f64; 4],
+    pub tetrad: Option<[[f64; 4]; 3]>,
+}
+
+impl Sample {
+    pub fn new<R: Recordable>(param: f64, state: &R) -> Self {
+        Sample {
+            param,
+            chart: state.chart(),
+            position: state.position(),
+            velocity: state.velocity(),
+            tetrad: state.tetrad(),
+        }
+    }
+}
+
+/// The history of states along a trajectory, ordered by growing parameter.
+///
+/// It can be exported as CSV, with the columns `param`, `chart`, `x0`..`x3` (the coordinates),
+/// `v0`..`v3` (the 4-velocity) and, if any sample has a tetrad, `forward0`..`forward3`,
+/// `right0`..`right3` and `up0`..`up3`; or as JSON Lines, one object per sample with the fields
+/// `param`, `chart`, `x`, `v` and optionally `tetrad` (an array of the 3 axes). The chart is one
+/// of `main`, `pole0` and `pole_pi`. Infinite and NaN values are written as empty fields in CSV
+/// and as `null` in JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory {
+    samples: Vec<Sample>,
+}
+
+impl Trajectory {
+    pub fn new() -> Self {
+        Trajectory {
+            samples: Vec::new(),
+        }
+    }
+
+    /// Stores the state at parameter `param`, which shouldn't be smaller than the one of the last
+    /// sample
+    pub fn record<R: Recordable>(&mut self, param: f64, state: &R) {
+        self.push(Sample::new(param, state));
+    }
+
+    /// Records the last step made by `driver`, sampling the dense output of the integrator at
+    /// `samples` evenly spaced points within it, the last of which is its end. Does nothing if
+    /// the driver hasn't made any steps.
+    pub fn record_step<S: State + Recordable>(&mut self, driver: &Driver<S>, samples: usize) {
+        if let Some(step) = driver.last_step() {
+            let param0 = driver.param() - step.step_size();
+            for i in 1..=samples {
+                let theta = i as f64 / samples as f64;
+                self.record(param0 + theta * step.step_size(), &step.at(theta));
+            }
+        }
+    }
+
+    // `Option::is_none_or` would need Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn push(&mut self, sample: Sample) {
+        debug_assert!(
+            self.samples
+                .last()
+                .map_or(true, |s| s.param <= sample.param),
+            "samples must be recorded in order"
+        );
+        self.samples.push(sample);
+    }
+
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Reconstructs the state at parameter `param`, using cubic Hermite interpolation of the
+    /// position and linear interpolation of the tetrad between the samples. This is less accurate
+    /// than the dense output of the integrator; recording several samples per step with
+    /// `record_step` keeps the interpolation close to it.
+    ///
+    /// Returns `None` outside the recorded range and between samples in different charts; to
+    /// interpolate across a chart switch, record the state both before and after switching.
+    pub fn interpolate(&self, param: f64) -> Option<Sample> {
+        let first = self.samples.first()?;
+        let last = self.samples.last()?;
+        if param < first.param || param > last.param {
+            return None;
+        }
+        let index = self.samples.partition_point(|s| s.param <= param);
+        if index == self.samples.len() {
+            return Some(*last);
+        }
+        let (s0, s1) = (&self.samples[index - 1], &self.samples[index]);
+        if param == s0.param {
+            return Some(*s0);
+        }
+        if s0.chart != s1.chart {
+            return None;
+        }
+
+        let mut position = [0.0; 4];
+        let mut velocity = [0.0; 4];
+        for i in 0..4 {
+            (position[i], velocity[i]) = hermite(
+                param,
+                (s0.param, s0.position[i], s0.velocity[i]),
+                (s1.param, s1.position[i], s1.velocity[i]),
+            );
+        }
+
+        let s = (param - s0.param) / (s1.param - s0.param);
+        let tetrad = match (s0.tetrad, s1.tetrad) {
+            (Some(t0), Some(t1)) => {
+                let mut tetrad = [[0.0; 4]; 3];
+                for (axis, (a0, a1)) in tetrad.iter_mut().zip(t0.iter().zip(t1.iter())) {
+                    for i in 0..4 {
+                        axis[i] = a0[i] + s * (a1[i] - a0[i]);
+                    }
+                }
+                Some(tetrad)
+            }
+            _ => None,
+        };
+
+        Some(Sample {
+            param,
+            chart: s0.chart,
+            position,
+            velocity,
+            tetrad,
+        })
+    }
+
+    /// Writes the samples as CSV with a header row
+    pub fn write_csv<W: Write>(&self, mut out: W) -> io::Result<()> {
+        let with_tetrad = self.samples.iter().any(|s| s.tetrad.is_some());
+        write!(out, "param,chart,x0,x1,x2,x3,v0,v1,v2,v3")?;
+        if with_tetrad {
+            for axis in &["forward", "right", "up"] {
+                for i in 0..4 {
+                    write!(out, ",{}{}", axis, i)?;
+                }
+            }
+        }
+        writeln!(out)?;
+
+        for sample in &self.samples {
+            write!(
+                out,
+                "{},{}",
+                csv_number(sample.param),
+                chart_name(sample.chart)
+            )?;
+            for value in sample.position.iter().chain(sample.velocity.iter()) {
+                write!(out, ",{}", csv_number(*value))?;
+            }
+            if with_tetrad {
+                match sample.tetrad {
+                    Some(tetrad) => {
+                        for value in tetrad.iter().flatten() {
+                            write!(out, ",{}", csv_number(*value))?;
+                        }
+                    }
+                    None => write!(out, "{}", ",".repeat(12))?,
+                }
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the samples as JSON Lines, one object per line
+    pub fn write_json_lines<W: Write>(&self, mut out: W) -> io::Result<()> {
+        for sample in &self.samples {
+            write!(
+                out,
+                "{{\"param\":{},\"chart\":\"{}\",\"x\":",
+                json_number(sample.param),
+                chart_name(sample.chart)
+            )?;
+            write_json_array(&mut out, &sample.position)?;
+            write!(out, ",\"v\":")?;
+            write_json_array(&mut out, &sample.velocity)?;
+            if let Some(tetrad) = sample.tetrad {
+                write!(out, ",\"tetrad\":[")?;
+                for (i, axis) in tetrad.iter().enumerate() {
+                    if i > 0 {
+                        write!(out, ",")?;
+                    }
+                    write_json_array(&mut out, axis)?;
+                }
+                write!(out, "]")?;
+            }
+            writeln!(out, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+fn chart_name(chart: Chart) -> &'static str {
+    match chart {
+        Chart::Main => "main",
+        Chart::Pole0 => "pole0",
+        Chart::PolePi => "pole_pi",
+    }
+}
+
+// infinities and NaNs are left empty, like the missing tetrads, so that they read as missing
+// values
+fn csv_number(x: f64) -> String {
+    if x.is_finite() {
+        format!("{:?}", x)
+    } else {
+        String::new()
+    }
+}
+
+// JSON has no representation for infinities and NaNs
+fn json_number(x: f64) -> String {
+    if x.is_finite() {
+        format!("{:?}", x)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn write_json_array<W: Write>(out: &mut W, values: &[f64]) -> io::Result<()> {
+    write!(out, "[")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, "{}", json_number(*value))?;
+    }
+    write!(out, "]")
+}
@@ -0,0 +1,127 @@
+//! Propagates particles and entities through the Alcubierre spacetime: at the centre of an
+//! accelerating bubble, which carries them along, and off its axis, where it overtakes them. Also
+//! checks the Christoffel symbols of the chart.
+
+mod common;
+
+use common::check_chart_in_box;
+use gr_engine::coord_systems::alcubierre::{Alcubierre, Motion, WarpDrive};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Entity, Particle, Spacetime};
+
+type Coords = Alcubierre<Runtime>;
+
+// a bubble starting at rest and accelerating to twice the speed of light, v = 2 tanh(t / 5)
+fn drive() -> WarpDrive<impl Motion> {
+    WarpDrive::new(2.0, 0.5, |t: f64| {
+        let s = t / 5.0;
+        (
+            10.0 * s.cosh().ln(),
+            2.0 * s.tanh(),
+            0.4 / (s.cosh() * s.cosh()),
+        )
+    })
+}
+
+fn fly_particle<S: Spacetime<Coords>>(
+    spacetime: &S,
+    x: Point<Coords>,
+    tau: f64,
+) -> (Particle<Coords>, f64) {
+    // the bubble is at rest at t = 0, so the particle starts at rest in the flat spacetime
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; 1.0, 0.0, 0.0, 0.0]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-4, 1.0, 1e-10));
+    while driver.param() < tau {
+        driver.step(&mut particle, |p: &Particle<Coords>| {
+            p.derivative_in(spacetime)
+        });
+    }
+    (particle, driver.param())
+}
+
+fn fly_entity<S: Spacetime<Coords>>(
+    spacetime: &S,
+    x: Point<Coords>,
+    tau: f64,
+) -> (Entity<Coords>, f64) {
+    let axis = |i: usize| {
+        let mut v = Vector::new(x, arr![f64; 0.0, 0.0, 0.0, 0.0]);
+        v[i] = 1.0;
+        v
+    };
+    let mut entity = Entity::new(x, axis(0), axis(1), axis(2), axis(3));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-4, 1.0, 1e-10));
+    while driver.param() < tau {
+        driver.step(&mut entity, |e: &Entity<Coords>| e.derivative_in(spacetime));
+    }
+    (entity, driver.param())
+}
+
+fn dot<S: Spacetime<Coords>>(spacetime: &S, a: &Vector<Coords>, b: &Vector<Coords>) -> f64 {
+    let g = spacetime.g(a.get_point());
+    (0..16).map(|i| g[i] * a[i / 4] * b[i % 4]).sum()
+}
+
+#[test]
+fn riding_the_bubble() {
+    let drive = drive();
+    let start = Point::new(arr![f64; 0.0, 0.0, 0.0, 0.0]);
+
+    // the proper time at the centre of the bubble is the coordinate time
+    let (particle, tau) = fly_particle(&drive, start, 30.0);
+    let (xs, v, _) = drive.motion.motion(tau);
+    let (x, u) = (particle.get_pos(), particle.get_vel());
+    assert!((x[0] - tau).abs() < 1e-6, "t = {}", x[0]);
+    assert!((x[1] - xs).abs() < 1e-6, "x = {}, expected {}", x[1], xs);
+    assert!(x[2].abs() < 1e-9 && x[3].abs() < 1e-9);
+    assert!((u[1] - v).abs() < 1e-6);
+
+    // the entity keeps its orientation, with the axes orthonormal
+    let (entity, tau) = fly_entity(&drive, start, 30.0);
+    let (xs, _, _) = drive.motion.motion(tau);
+    assert!((entity.get_pos()[1] - xs).abs() < 1e-6);
+    let axes = *entity.frame().axes();
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = match (i, j) {
+                (0, 0) => 1.0,
+                _ if i == j => -1.0,
+                _ => 0.0,
+            };
+            assert!((dot(&drive, &axes[i], &axes[j]) - expected).abs() < 1e-6);
+        }
+    }
+    assert!((axes[2][2] - 1.0).abs() < 1e-6 && (axes[3][3] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn overtaken_by_the_bubble() {
+    let drive = drive();
+    // ahead of the bubble and off its axis, outside of the wall
+    let start = Point::new(arr![f64; 0.0, 8.0, 3.5, 0.0]);
+
+    // the particle is dragged a bit as the bubble passes, and left at rest behind it
+    let (particle, tau) = fly_particle(&drive, start, 30.0);
+    let (xs, _, _) = drive.motion.motion(tau);
+    let (x, u) = (particle.get_pos(), particle.get_vel());
+    assert!(x[1] > 8.0 && x[1] < 9.0, "x = {}", x[1]);
+    assert!(xs - x[1] > 10.0);
+    assert!(u[1].abs() < 1e-6 && (u[0] - 1.0).abs() < 1e-6);
+    assert!((x[2] - 3.5).abs() < 0.5 && x[3].abs() < 1e-9);
+
+    // the entity is left at the same place, wherever the integration stops
+    let (entity, _) = fly_entity(&drive, start, 30.0);
+    assert!((entity.get_pos()[1] - x[1]).abs() < 1e-6);
+    assert!((entity.get_pos()[2] - x[2]).abs() < 1e-6);
+}
+
+#[test]
+fn alcubierre_chart() {
+    let min = [-5.0, -6.0, -4.0, -4.0];
+    let max = [5.0, 6.0, 4.0, 4.0];
+    check_chart_in_box::<Coords, _>(&drive(), 51, min, max);
+}
@@ -0,0 +1,238 @@
+//! Sends particles and entities on polar orbits around a Schwarzschild black hole, over both
+//! poles, and compares them with the same orbits in the equatorial plane of the main chart.
+
+use gr_engine::coord_systems::schwarzschild::{
+    BlackHole, NearPole0Schw, NearPolePiSchw, Schwarzschild, SchwarzschildAtlas,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{
+    AtlasEntity, AtlasParticle, Chart, Driver, Entity, Particle, Spacetime, Trajectory,
+    DEFAULT_ENTER_POLE, DEFAULT_LEAVE_POLE,
+};
+use std::f64::consts::PI;
+
+type Main = Schwarzschild<Runtime>;
+type Atlas = SchwarzschildAtlas<Runtime>;
+
+const R0: f64 = 10.0;
+const UR0: f64 = -0.1;
+// the angular velocity, making an eccentric bound orbit
+const W0: f64 = 0.04;
+const DURATION: f64 = 400.0;
+
+// the initial 4-velocity of an orbit starting at r = R0 on the equator, moving inwards and with
+// the angular velocity W0 along the direction `angular` (normalized to the unit sphere)
+fn initial_velocity(x: &Point<Main>, angular: [f64; 2]) -> Vector<Main> {
+    let f = 1.0 - 2.0 / R0;
+    let ut = ((1.0 + UR0 * UR0 / f + R0 * R0 * W0 * W0) / f).sqrt();
+    Vector::new(*x, arr![f64; ut, UR0, W0 * angular[0], W0 * angular[1]])
+}
+
+// the direction of the point on the unit sphere
+fn unit(theta: f64, phi: f64) -> [f64; 3] {
+    [
+        theta.sin() * phi.cos(),
+        theta.sin() * phi.sin(),
+        theta.cos(),
+    ]
+}
+
+// the orbit in the equatorial plane of the main chart, which never gets close to the poles
+fn reference(bh: &BlackHole) -> Trajectory {
+    let x = Point::new(arr![f64; 0.0, R0, PI / 2.0, 0.0]);
+    let mut particle = Particle::new(x, initial_velocity(&x, [0.0, 1.0]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 0.5, 1e-12));
+    let mut trajectory = Trajectory::new();
+    trajectory.record(0.0, &particle);
+    while driver.param() < DURATION + 1.0 {
+        driver.step(&mut particle, |p: &Particle<Main>| p.derivative_in(bh));
+        trajectory.record(driver.param(), &particle);
+    }
+    trajectory
+}
+
+// checks that the point `x` of the main chart lies on the reference orbit rotated into the polar
+// plane phi = 0, which moves from the equator towards theta = 0
+fn assert_on_reference(reference: &Trajectory, param: f64, x: &Point<Main>) {
+    let expected = reference.interpolate(param).unwrap().position;
+    // the angle travelled along the orbit
+    let psi = expected[3];
+    let n = unit(x[2], x[3]);
+    let expected_n = [psi.cos(), 0.0, psi.sin()];
+    for i in 0..3 {
+        assert!(
+            (n[i] - expected_n[i]).abs() < 1e-6,
+            "tau = {}: direction {:?}, expected {:?}",
+            param,
+            n,
+            expected_n
+        );
+    }
+    assert!((x[0] - expected[0]).abs() < 1e-6 && (x[1] - expected[1]).abs() < 1e-6);
+}
+
+// records a chart switch, checking that it happened beyond the thresholds
+fn check_switch(switches: &mut Vec<Chart>, from: Chart, to: Chart, theta: f64) {
+    match (from, to) {
+        (Chart::Main, Chart::Pole0) => assert!(theta < DEFAULT_ENTER_POLE),
+        (Chart::Main, Chart::PolePi) => assert!(theta > PI - DEFAULT_ENTER_POLE),
+        (Chart::Pole0, Chart::Main) => assert!(theta > DEFAULT_LEAVE_POLE),
+        (Chart::PolePi, Chart::Main) => assert!(theta < PI - DEFAULT_LEAVE_POLE),
+        _ => panic!("switched from {:?} to {:?}", from, to),
+    }
+    switches.push(to);
+}
+
+// checks that the charts were visited in the order the orbit passes the poles, beginning with the
+// pole theta = 0
+fn assert_alternating(switches: &[Chart]) {
+    assert!(switches.len() >= 6, "switches: {:?}", switches);
+    for (i, chart) in switches.iter().enumerate() {
+        let expected = match i % 4 {
+            0 => Chart::Pole0,
+            2 => Chart::PolePi,
+            _ => Chart::Main,
+        };
+        assert_eq!(*chart, expected, "switches: {:?}", switches);
+    }
+}
+
+#[test]
+fn particle_crosses_both_poles() {
+    let bh = BlackHole::new(1.0);
+    let reference = reference(&bh);
+
+    let x = Point::new(arr![f64; 0.0, R0, PI / 2.0, 0.0]);
+    let mut particle =
+        AtlasParticle::<Atlas>::new(Particle::new(x, initial_velocity(&x, [-1.0, 0.0])));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 0.5, 1e-12));
+    let mut switches = Vec::new();
+    while driver.param() < DURATION {
+        driver.step(&mut particle, |p: &AtlasParticle<Atlas>| {
+            p.derivative_in(&bh)
+        });
+        let (chart, theta) = (particle.chart(), particle.theta());
+        if particle.update_chart() {
+            driver.reset();
+            check_switch(&mut switches, chart, particle.chart(), theta);
+        }
+        // between the thresholds the chart only depends on where the particle came from
+        if theta < DEFAULT_ENTER_POLE {
+            assert_eq!(particle.chart(), Chart::Pole0);
+        } else if theta > PI - DEFAULT_ENTER_POLE {
+            assert_eq!(particle.chart(), Chart::PolePi);
+        } else if theta > DEFAULT_LEAVE_POLE && theta < PI - DEFAULT_LEAVE_POLE {
+            assert_eq!(particle.chart(), Chart::Main);
+        }
+        assert_on_reference(&reference, driver.param(), &particle.get_pos());
+    }
+    assert_alternating(&switches);
+}
+
+#[test]
+fn entity_crosses_both_poles() {
+    let bh = BlackHole::new(1.0);
+    let reference = reference(&bh);
+
+    let x = Point::new(arr![f64; 0.0, R0, PI / 2.0, 0.0]);
+    let entity = Entity::new(
+        x,
+        initial_velocity(&x, [-1.0, 0.0]),
+        Vector::new(x, arr![f64; 0.0, 1.0, 0.0, 0.0]),
+        Vector::new(x, arr![f64; 0.0, 0.0, 1.0 / R0, 0.0]),
+        Vector::new(x, arr![f64; 0.0, 0.0, 0.0, 1.0 / R0]),
+    );
+    let mut entity = AtlasEntity::<Atlas>::new(entity);
+    entity.orthonormalize_in(&bh);
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 0.5, 1e-12));
+    let mut switches = Vec::new();
+    while driver.param() < DURATION {
+        driver.step(&mut entity, |e: &AtlasEntity<Atlas>| e.derivative_in(&bh));
+        let (chart, theta) = (entity.chart(), entity.theta());
+        if entity.update_chart() {
+            driver.reset();
+            check_switch(&mut switches, chart, entity.chart(), theta);
+        }
+        assert_on_reference(&reference, driver.param(), &entity.get_pos());
+    }
+    assert_alternating(&switches);
+
+    // the frame carried through the pole patches is still orthonormal in the main chart
+    let main = entity.to_main();
+    let g = bh.g(main.get_pos());
+    let axes = *main.frame().axes();
+    for i in 0..4 {
+        for j in 0..4 {
+            let dot: f64 = (0..16)
+                .map(|k| g[k] * axes[i][k / 4] * axes[j][k % 4])
+                .sum();
+            let expected = match (i, j) {
+                (0, 0) => 1.0,
+                _ if i == j => -1.0,
+                _ => 0.0,
+            };
+            assert!((dot - expected).abs() < 1e-6, "g(e{}, e{}) = {}", i, j, dot);
+        }
+    }
+}
+
+// compares the jacobian of a conversion with the finite differences of the converted points
+fn check_jacobian<C1, C2>(x: Point<C1>, v: [f64; 4])
+where
+    C1: CoordinateSystem<Dimension = U4> + ConversionTo<C2>,
+    C2: CoordinateSystem<Dimension = U4> + 'static,
+{
+    let h = 1e-6;
+    let (mut x1, mut x2) = (x, x);
+    for i in 0..4 {
+        x1[i] -= h * v[i];
+        x2[i] += h * v[i];
+    }
+    let (y1, y2) = (C1::convert_point(&x1), C1::convert_point(&x2));
+    let jacobian = C1::jacobian(&x);
+    for i in 0..4 {
+        let expected = (y2[i] - y1[i]) / (2.0 * h);
+        let converted: f64 = (0..4).map(|j| jacobian[i * 4 + j] * v[j]).sum();
+        assert!(
+            (converted - expected).abs() < 1e-6,
+            "component {}: {}, expected {}",
+            i,
+            converted,
+            expected
+        );
+    }
+}
+
+#[test]
+fn pole_conversions_have_consistent_jacobians() {
+    let v = [1.2, -0.3, 0.7, 0.4];
+    for &(theta, phi) in &[(0.3, 1.0), (0.05, -2.0), (2.9, 1.0), (3.1, 3.0)] {
+        let x = Point::<Main>::new(arr![f64; 1.0, 7.0, theta, phi]);
+        if theta < PI / 2.0 {
+            check_jacobian::<Main, NearPole0Schw<Runtime>>(x, v);
+            let y = <Main as ConversionTo<NearPole0Schw<Runtime>>>::convert_point(&x);
+            check_jacobian::<NearPole0Schw<Runtime>, Main>(y, v);
+        } else {
+            check_jacobian::<Main, NearPolePiSchw<Runtime>>(x, v);
+            let y = <Main as ConversionTo<NearPolePiSchw<Runtime>>>::convert_point(&x);
+            check_jacobian::<NearPolePiSchw<Runtime>, Main>(y, v);
+        }
+
+        // a round trip through the pole patch gives back the same particle
+        let particle = Particle::new(x, Vector::new(x, arr![f64; v[0], v[1], v[2], v[3]]));
+        let back: Particle<Main> = if theta < PI / 2.0 {
+            particle.convert::<NearPole0Schw<Runtime>>().convert()
+        } else {
+            particle.convert::<NearPolePiSchw<Runtime>>().convert()
+        };
+        for i in 0..4 {
+            assert!((back.get_pos()[i] - x[i]).abs() < 1e-12);
+            assert!((back.get_vel()[i] - v[i]).abs() < 1e-12);
+        }
+    }
+}
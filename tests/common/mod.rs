@@ -0,0 +1,263 @@
+//! Checks shared by the tests of the charts.
+
+#![allow(dead_code)]
+
+use gr_engine::diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use gr_engine::diffgeom::metric::MetricSystem;
+use gr_engine::diffgeom::tensors::Matrix;
+use gr_engine::generic_array::arr;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{Spacetime, SpacetimeConversion};
+
+const H: f64 = 1e-5;
+const TOLERANCE: f64 = 1e-6;
+
+// a simple deterministic generator of pseudo-random numbers in [0, 1)
+pub struct Lcg(pub u64);
+
+impl Lcg {
+    pub fn next(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next()
+    }
+}
+
+pub fn numeric_christoffel<C, S>(spacetime: &S, x: &Point<C>) -> [f64; 64]
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    // dg[i][j][k] = dg_ij/dx^k
+    let mut dg = [[[0.0; 4]; 4]; 4];
+    for k in 0..4 {
+        let mut x1 = *x;
+        x1[k] -= H;
+        let g1 = spacetime.g(&x1);
+        let mut x2 = *x;
+        x2[k] += H;
+        let g2 = spacetime.g(&x2);
+        for i in 0..4 {
+            for j in 0..4 {
+                dg[i][j][k] = (g2[i * 4 + j] - g1[i * 4 + j]) / (2.0 * H);
+            }
+        }
+    }
+
+    let inv_g = spacetime.inv_g(x);
+    let mut result = [0.0; 64];
+    for i in 0..4 {
+        for j in 0..4 {
+            for k in 0..4 {
+                for l in 0..4 {
+                    result[i * 16 + j * 4 + k] +=
+                        0.5 * inv_g[i * 4 + l] * (dg[l][j][k] + dg[l][k][j] - dg[j][k][l]);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Checks the inverse metric and the Christoffel symbols of a chart at points with r between 2.5
+/// and 20 and the other coordinates around zero
+pub fn check_chart<C, S>(spacetime: &S, seed: u64)
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    check_chart_in_range(spacetime, seed, 2.5, 20.0);
+}
+
+/// Like `check_chart`, with r between `r_min` and `r_max`
+pub fn check_chart_in_range<C, S>(spacetime: &S, seed: u64, r_min: f64, r_max: f64)
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    check_chart_in_box(
+        spacetime,
+        seed,
+        [-10.0, r_min, -0.8, -0.8],
+        [10.0, r_max, 0.8, 0.8],
+    );
+}
+
+/// Like `check_chart`, at points with the coordinates between `min` and `max`
+pub fn check_chart_in_box<C, S>(spacetime: &S, seed: u64, min: [f64; 4], max: [f64; 4])
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    let mut rng = Lcg(seed);
+    for _ in 0..100 {
+        let x = Point::<C>::new(arr![f64;
+            rng.range(min[0], max[0]),
+            rng.range(min[1], max[1]),
+            rng.range(min[2], max[2]),
+            rng.range(min[3], max[3])
+        ]);
+        let g = spacetime.g(&x);
+        let inv_g = spacetime.inv_g(&x);
+        for i in 0..4 {
+            for j in 0..4 {
+                let product: f64 = (0..4).map(|k| g[i * 4 + k] * inv_g[k * 4 + j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(
+                    (product - expected).abs() < TOLERANCE,
+                    "g * inv_g at {:?}",
+                    x
+                );
+            }
+        }
+
+        let analytic = spacetime.christoffel(&x);
+        let numeric = numeric_christoffel(spacetime, &x);
+        for i in 0..64 {
+            let diff = (analytic[i] - numeric[i]).abs();
+            assert!(
+                diff < TOLERANCE * (1.0 + numeric[i].abs()),
+                "component {} at {:?}: analytic {}, numeric {}",
+                i,
+                x,
+                analytic[i],
+                numeric[i]
+            );
+        }
+    }
+}
+
+fn assert_components(what: &str, x: [f64; 4], expected: &[f64], actual: &[f64]) {
+    for (i, (e, a)) in expected.iter().zip(actual.iter()).enumerate() {
+        assert!(
+            (e - a).abs() <= 1e-12 * (1.0 + e.abs()),
+            "{} component {} at {:?}: static {}, runtime {}",
+            what,
+            i,
+            x,
+            e,
+            a
+        );
+    }
+}
+
+/// Checks that the metric of the static chart `S` matches the one of the runtime chart `R`
+/// provided by `spacetime` at the point with the coordinates `x`
+pub fn assert_same_metric<S, R, T>(spacetime: &T, x: [f64; 4])
+where
+    S: MetricSystem<Dimension = U4>,
+    R: CoordinateSystem<Dimension = U4>,
+    T: Spacetime<R>,
+{
+    let xs = Point::<S>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let xr = Point::<R>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+
+    let (g, inv_g) = (S::g(&xs), S::inv_g(&xs));
+    let (g_r, inv_g_r) = (spacetime.g(&xr), spacetime.inv_g(&xr));
+    let expected: Vec<f64> = (0..16).map(|i| g[i]).collect();
+    let actual: Vec<f64> = (0..16).map(|i| g_r[i]).collect();
+    assert_components("g", x, &expected, &actual);
+    let expected: Vec<f64> = (0..16).map(|i| inv_g[i]).collect();
+    let actual: Vec<f64> = (0..16).map(|i| inv_g_r[i]).collect();
+    assert_components("inv_g", x, &expected, &actual);
+
+    let (gamma, gamma_r) = (S::christoffel(&xs), spacetime.christoffel(&xr));
+    let expected: Vec<f64> = (0..64).map(|i| gamma[i]).collect();
+    let actual: Vec<f64> = (0..64).map(|i| gamma_r[i]).collect();
+    assert_components("christoffel", x, &expected, &actual);
+}
+
+/// Checks that the static conversion from `S1` to `S2` matches the runtime conversion from `R1`
+/// to `R2` provided by `spacetime` at the point with the coordinates `x`
+pub fn assert_same_conversion<S1, S2, R1, R2, T>(spacetime: &T, x: [f64; 4])
+where
+    S1: ConversionTo<S2, Dimension = U4>,
+    S2: CoordinateSystem<Dimension = U4> + 'static,
+    R1: CoordinateSystem<Dimension = U4>,
+    R2: CoordinateSystem<Dimension = U4>,
+    T: SpacetimeConversion<R1, R2>,
+{
+    let xs = Point::<S1>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let xr = Point::<R1>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+
+    let (y, y_r) = (S1::convert_point(&xs), spacetime.convert_point(&xr));
+    let expected: Vec<f64> = (0..4).map(|i| y[i]).collect();
+    let actual: Vec<f64> = (0..4).map(|i| y_r[i]).collect();
+    assert_components("point", x, &expected, &actual);
+
+    let (jacobian, jacobian_r) = (S1::jacobian(&xs), spacetime.jacobian(&xr));
+    let expected: Vec<f64> = (0..16).map(|i| jacobian[i]).collect();
+    let actual: Vec<f64> = (0..16).map(|i| jacobian_r[i]).collect();
+    assert_components("jacobian", x, &expected, &actual);
+}
+
+/// Checks a pair of conversions between the charts `C1` and `C2` at points of `C1` with the
+/// coordinates between `min` and `max`: the jacobian against the finite differences of the
+/// converted points, and the conversion back to `C1` against the identity
+pub fn check_conversion_in_box<C1, C2, S>(spacetime: &S, seed: u64, min: [f64; 4], max: [f64; 4])
+where
+    C1: CoordinateSystem<Dimension = U4>,
+    C2: CoordinateSystem<Dimension = U4>,
+    S: SpacetimeConversion<C1, C2> + SpacetimeConversion<C2, C1>,
+{
+    let mut rng = Lcg(seed);
+    for _ in 0..100 {
+        let x = Point::<C1>::new(arr![f64;
+            rng.range(min[0], max[0]),
+            rng.range(min[1], max[1]),
+            rng.range(min[2], max[2]),
+            rng.range(min[3], max[3])
+        ]);
+        let y: Point<C2> = spacetime.convert_point(&x);
+        let back: Point<C1> = spacetime.convert_point(&y);
+        for i in 0..4 {
+            assert!(
+                (back[i] - x[i]).abs() < TOLERANCE * (1.0 + x[i].abs()),
+                "{:?} converted back to {:?}",
+                x,
+                back
+            );
+        }
+
+        let jacobian: Matrix<C2> = spacetime.jacobian(&x);
+        let inverse: Matrix<C1> = spacetime.jacobian(&y);
+        for k in 0..4 {
+            let (mut x1, mut x2) = (x, x);
+            x1[k] -= H;
+            x2[k] += H;
+            let (y1, y2): (Point<C2>, Point<C2>) =
+                (spacetime.convert_point(&x1), spacetime.convert_point(&x2));
+            for i in 0..4 {
+                let numeric = (y2[i] - y1[i]) / (2.0 * H);
+                assert!(
+                    (jacobian[i * 4 + k] - numeric).abs() < TOLERANCE * (1.0 + numeric.abs()),
+                    "jacobian component ({}, {}) at {:?}: analytic {}, numeric {}",
+                    i,
+                    k,
+                    x,
+                    jacobian[i * 4 + k],
+                    numeric
+                );
+            }
+        }
+        for i in 0..4 {
+            for j in 0..4 {
+                let product: f64 = (0..4)
+                    .map(|k| inverse[i * 4 + k] * jacobian[k * 4 + j])
+                    .sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(
+                    (product - expected).abs() < TOLERANCE,
+                    "the jacobians aren't inverse at {:?}",
+                    x
+                );
+            }
+        }
+    }
+}
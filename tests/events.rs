@@ -0,0 +1,167 @@
+//! Locates events along straight lines in flat spacetime and along the radial fall into a
+//! Schwarzschild black hole of mass 1, where the exact parameters are known.
+
+use gr_engine::coord_systems::minkowski::{Cartesian, Minkowski};
+use gr_engine::coord_systems::schwarzschild::{BlackHole, EddingtonFinkelstein, Schwarzschild};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::{CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{Body, Crossing, Driver, Event, Particle};
+use std::f64::consts::PI;
+
+type Flat = Cartesian<Runtime>;
+
+// a particle in flat spacetime starting at `x` with the velocity `v` (per unit of t)
+fn straight_line(x: [f64; 3], v: [f64; 3]) -> Particle<Flat> {
+    let gamma = 1.0 / (1.0 - v.iter().map(|v| v * v).sum::<f64>()).sqrt();
+    let x = Point::new(arr![f64; 0.0, x[0], x[1], x[2]]);
+    let u = arr![f64; gamma, gamma * v[0], gamma * v[1], gamma * v[2]];
+    Particle::new(x, Vector::new(x, u))
+}
+
+// a driver making steps of fixed length, so that the events have to be located within them
+fn fixed_step_driver<C: CoordinateSystem<Dimension = U4>>(step: f64) -> Driver<Particle<C>> {
+    Driver::new(DenseDPIntegrator::new(step, step, step, 1e-12)).with_tolerance(1e-12)
+}
+
+// the proper time of the fall from rest at r0 to r, from the cycloid r = r0 (1 + cos(eta)) / 2
+fn fall_time(r0: f64, r: f64) -> f64 {
+    let eta = (2.0 * r / r0 - 1.0).acos();
+    (r0 * r0 * r0 / 8.0).sqrt() * (eta + eta.sin())
+}
+
+#[test]
+fn events_are_located_within_a_step() {
+    let flat = Minkowski::new(0.0, 0.0);
+    // passing the origin at the distance 1 at t = 5; the radius is a nonlinear function along
+    // the line, so locating it takes a few iterations
+    let mut particle = straight_line([-3.0, 1.0, 0.0], [0.6, 0.0, 0.0]);
+    // the steps end at t = 5 and t = 10, so that each contains one crossing
+    let mut driver = fixed_step_driver(4.0).with_event(Event::radius(1.5));
+    let mut hits = driver.step(&mut particle, |p: &Particle<Flat>| p.derivative_in(&flat));
+    hits.extend(driver.step(&mut particle, |p: &Particle<Flat>| p.derivative_in(&flat)));
+
+    // r = 1.5 where x = -sqrt(1.25) and x = sqrt(1.25), at the proper times t / gamma
+    assert_eq!(hits.len(), 2);
+    let gamma = 1.25;
+    for (hit, (x, crossing)) in hits.iter().zip(&[
+        (-(1.25f64).sqrt(), Crossing::Falling),
+        ((1.25f64).sqrt(), Crossing::Rising),
+    ]) {
+        let expected = (x + 3.0) / 0.6 / gamma;
+        assert!(
+            (hit.param - expected).abs() < 1e-10,
+            "{} at {}, expected {}",
+            hit.name,
+            hit.param,
+            expected
+        );
+        assert_eq!(hit.crossing, *crossing);
+        assert!((hit.state.radius() - 1.5).abs() < 1e-10);
+        assert!(!hit.terminal);
+    }
+    // without a terminal event the steps are complete
+    assert_eq!(driver.param(), 8.0);
+    assert!((particle.get_pos()[0] - 8.0 * gamma).abs() < 1e-10);
+}
+
+#[test]
+fn multiple_events_in_a_step_are_ordered() {
+    let flat = Minkowski::new(0.0, 0.0);
+    let mut particle = straight_line([1.0, 0.0, 4.0], [0.0, 0.0, -0.8]);
+    let gamma = 1.0 / 0.6;
+    let mut driver = fixed_step_driver(10.0)
+        .with_event(Event::time_coordinate(9.0))
+        .with_event(Event::equator())
+        .with_event(Event::time_coordinate(2.0));
+    let hits = driver.step(&mut particle, |p: &Particle<Flat>| p.derivative_in(&flat));
+
+    // the equator is crossed at t = 5, from the north to the south
+    let indices: Vec<usize> = hits.iter().map(|hit| hit.index).collect();
+    assert_eq!(indices, vec![2, 1, 0]);
+    for (hit, t) in hits.iter().zip(&[2.0, 5.0, 9.0]) {
+        assert!((hit.param - t / gamma).abs() < 1e-10);
+        assert!((hit.state.time_coordinate() - t).abs() < 1e-10);
+    }
+    assert_eq!(hits[1].name, "equator");
+    assert_eq!(hits[1].crossing, Crossing::Rising);
+    assert!(hits[1].state.cos_theta().abs() < 1e-10);
+}
+
+#[test]
+fn terminal_events_stop_the_step() {
+    let flat = Minkowski::new(0.0, 0.0);
+    let mut particle = straight_line([1.0, 0.0, 4.0], [0.0, 0.0, -0.8]);
+    let gamma = 1.0 / 0.6;
+    let mut driver = fixed_step_driver(10.0)
+        .with_event(Event::time_coordinate(2.0))
+        .with_event(Event::time_coordinate(4.0).with_terminal(true))
+        .with_event(Event::equator());
+    let hits = driver.step(&mut particle, |p: &Particle<Flat>| p.derivative_in(&flat));
+
+    // the events after the terminal one are dropped, and the state stops at it
+    assert_eq!(hits.len(), 2);
+    assert!(hits[1].terminal);
+    assert!((driver.param() - 4.0 / gamma).abs() < 1e-10);
+    assert!((particle.get_pos()[0] - 4.0).abs() < 1e-10);
+    assert!((particle.get_pos()[3] - 0.8).abs() < 1e-10);
+
+    // the integration continues from there
+    let hits = driver.step(&mut particle, |p: &Particle<Flat>| p.derivative_in(&flat));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].index, 2);
+    assert!((hits[0].param - 5.0 / gamma).abs() < 1e-10);
+}
+
+#[test]
+fn radial_fall_is_stopped_at_the_horizon() {
+    let bh = BlackHole::new(1.0);
+    let r0 = 10.0;
+    let x = Point::<EddingtonFinkelstein<Runtime>>::new(arr![f64; 0.0, r0, PI / 2.0, 0.0]);
+    // at rest at r0: in the EF coordinates v = t + r*, dv/dtau = dt/dtau
+    let ut = 1.0 / (1.0 - 2.0 / r0).sqrt();
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; ut, 0.0, 0.0, 0.0]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 5.0, 1e-12))
+        .with_tolerance(1e-12)
+        .with_event(Event::radius(5.0))
+        .with_event(Event::horizon(bh.horizon_radius()));
+    let hits = driver.run(
+        &mut particle,
+        |p: &Particle<EddingtonFinkelstein<Runtime>>| p.derivative_in(&bh),
+        10_000,
+    );
+
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].name, "radius");
+    assert!((hits[0].param - fall_time(r0, 5.0)).abs() < 1e-8);
+    assert_eq!(hits[1].name, "horizon");
+    assert!(hits[1].terminal && hits[1].crossing == Crossing::Falling);
+    assert!((hits[1].param - fall_time(r0, 2.0)).abs() < 1e-8);
+    assert!((particle.radius() - 2.0).abs() < 1e-10);
+    assert_eq!(driver.param(), hits[1].param);
+}
+
+#[test]
+fn horizon_ignores_outgoing_particles() {
+    let bh = BlackHole::new(1.0);
+    let x = Point::<Schwarzschild<Runtime>>::new(arr![f64; 0.0, 3.0, PI / 2.0, 0.0]);
+    // moving outwards at dr/dtau = 0.5 from just outside of the horizon event's radius
+    let f: f64 = 1.0 - 2.0 / 3.0;
+    let ut = ((1.0 + 0.25 / f) / f).sqrt();
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; ut, 0.5, 0.0, 0.0]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 5.0, 1e-12))
+        .with_event(Event::horizon(4.0))
+        .with_event(Event::radius(4.0));
+    let hits = driver.run(
+        &mut particle,
+        |p: &Particle<Schwarzschild<Runtime>>| p.derivative_in(&bh),
+        100,
+    );
+    // only the radius event, which crosses in either direction, happens
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].index, 1);
+    assert_eq!(hits[0].crossing, Crossing::Rising);
+}
@@ -0,0 +1,129 @@
+//! Compares the tabulated ΛCDM expansion history with the analytic solutions, checks the
+//! conversions between the cosmic and conformal time charts and the motion of particles in the
+//! charts with a static scale factor, and checks the Christoffel symbols of the charts.
+
+mod common;
+
+use common::check_chart_in_box;
+use gr_engine::coord_systems::flrw::{
+    ConformalTime, CosmicTime, Flrw, LambdaCdm, Properties, ScaleFactor,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::{Particle, SpacetimeConversion};
+
+const TOLERANCE: f64 = 1e-8;
+
+fn cosmic_scale_factor(model: &LambdaCdm, t: f64) -> (f64, f64) {
+    ScaleFactor::<CosmicTime<Runtime>>::scale_factor(model, t)
+}
+
+#[test]
+fn flat_matter_and_lambda() {
+    let (h0, om, ol) = (0.7, 0.3, 0.7);
+    let model = LambdaCdm::new(h0, om, ol);
+    // a(t) = (Om/OL)^(1/3) sinh(3/2 sqrt(OL) H0 t)^(2/3)
+    let analytic =
+        |t: f64| (om / ol).powf(1.0 / 3.0) * (1.5 * ol.sqrt() * h0 * t).sinh().powf(2.0 / 3.0);
+    let age = 2.0 / (3.0 * ol.sqrt() * h0) * (ol / om).sqrt().asinh();
+    assert!((model.age() - age).abs() < TOLERANCE * age);
+    for &t in &[1e-20, 1e-12, 1e-3, 0.5, 1.0, 3.0, 10.0, 100.0] {
+        let (a, da) = cosmic_scale_factor(&model, t);
+        assert!(
+            (a - analytic(t)).abs() < 1e-6 * analytic(t),
+            "a({}) = {}, expected {}",
+            t,
+            a,
+            analytic(t)
+        );
+        assert!((da / a - model.hubble_rate(a)).abs() < 1e-6 * model.hubble_rate(a));
+    }
+}
+
+#[test]
+fn open_and_closed_dust() {
+    // without the cosmological constant, H0 t0 = 1/(1-Om) - Om/(2 (1-Om)^(3/2)) acosh(2/Om - 1)
+    // for the open universe and the analogous expression with acos for the closed one
+    let om: f64 = 0.3;
+    let open = LambdaCdm::new(1.0, om, 0.0);
+    let age = 1.0 / (1.0 - om) - om / (2.0 * (1.0 - om).powf(1.5)) * (2.0 / om - 1.0).acosh();
+    assert!((open.age() - age).abs() < TOLERANCE);
+    assert!(open.curvature() < 0.0);
+
+    let om: f64 = 2.0;
+    let closed = LambdaCdm::new(1.0, om, 0.0);
+    let age = om / (2.0 * (om - 1.0).powf(1.5)) * (1.0 - 2.0 / om).acos() - 1.0 / (om - 1.0);
+    assert!((closed.age() - age).abs() < TOLERANCE);
+    assert!(closed.curvature() > 0.0);
+}
+
+#[test]
+fn time_conversions() {
+    let universe = Flrw::lambda_cdm(LambdaCdm::new(1.0, 0.3, 0.7).with_radiation(1e-4));
+    for &t in &[1e-15, 1e-6, 0.1, 1.0, 5.0, 20.0] {
+        let x = Point::<CosmicTime<Runtime>>::new(arr![f64; t, 0.5, 1.0, 2.0]);
+        let y: Point<ConformalTime<Runtime>> = universe.convert_point(&x);
+        let back: Point<CosmicTime<Runtime>> = universe.convert_point(&y);
+        assert!((back[0] - t).abs() < TOLERANCE * t, "{} -> {}", t, back[0]);
+        // the scale factor is the same event in both charts
+        let (a, _) = cosmic_scale_factor(&universe.scale_factor, t);
+        let (b, _) =
+            ScaleFactor::<ConformalTime<Runtime>>::scale_factor(&universe.scale_factor, y[0]);
+        assert!((a - b).abs() < 1e-6 * a);
+    }
+}
+
+// the flat universe filled with dust, a = t^(2/3)
+struct EinsteinDeSitter;
+
+impl Properties for EinsteinDeSitter {
+    fn curvature() -> f64 {
+        0.0
+    }
+
+    fn scale_factor(t: f64) -> (f64, f64) {
+        (t.powf(2.0 / 3.0), 2.0 / 3.0 / t.powf(1.0 / 3.0))
+    }
+}
+
+#[test]
+fn peculiar_velocity_decays() {
+    // a comoving particle stays at rest, a moving one loses its momentum a^2 dchi/dtau as 1/a
+    let x = Point::<CosmicTime<EinsteinDeSitter>>::new(arr![f64; 2.0, 1.0, 1.0, 0.5]);
+    let (a, da) = EinsteinDeSitter::scale_factor(2.0);
+    let comoving = Particle::new(x, Vector::new(x, arr![f64; 1.0, 0.0, 0.0, 0.0]));
+    let derivative = comoving.derivative();
+    for i in 1..8 {
+        assert!(derivative.0[i].abs() < TOLERANCE, "{:?}", derivative.0);
+    }
+
+    let u = 0.1 / a;
+    let ut = (1.0 + a * a * u * u).sqrt();
+    let moving = Particle::new(x, Vector::new(x, arr![f64; ut, u, 0.0, 0.0]));
+    let derivative = moving.derivative();
+    assert!((derivative.0[4] + a * da * u * u).abs() < TOLERANCE);
+    assert!((derivative.0[5] + 2.0 * da / a * ut * u).abs() < TOLERANCE);
+}
+
+#[test]
+fn flrw_charts() {
+    // dust-dominated expansion, a ~ t^(2/3) ~ eta^2
+    let cosmic = |t: f64| (t.powf(2.0 / 3.0), 2.0 / 3.0 / t.powf(1.0 / 3.0));
+    let conformal = |eta: f64| (eta * eta, 2.0 * eta);
+    // chi stays below pi for the closed universe
+    let min = [0.5, 0.1, 0.3, -3.0];
+    let max = [3.0, 3.0, 2.8, 3.0];
+    for (i, k) in [-1.0, 0.0, 1.0].iter().enumerate() {
+        let seed = 37 + 2 * i as u64;
+        let universe = Flrw::new(*k, cosmic);
+        check_chart_in_box::<CosmicTime<Runtime>, _>(&universe, seed, min, max);
+        let universe = Flrw::new(*k, conformal);
+        check_chart_in_box::<ConformalTime<Runtime>, _>(&universe, seed + 1, min, max);
+    }
+
+    let universe = Flrw::lambda_cdm(LambdaCdm::new(1.0, 0.3, 0.6));
+    check_chart_in_box::<CosmicTime<Runtime>, _>(&universe, 43, min, max);
+    check_chart_in_box::<ConformalTime<Runtime>, _>(&universe, 44, min, max);
+}
@@ -0,0 +1,132 @@
+//! Checks the frames of the local observers around a Kerr black hole of mass 1 and spin 0.8, and
+//! the particles they launch.
+
+use gr_engine::coord_systems::kerr::{BlackHole, BoyerLindquist, NearPole0EF};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::{CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{Frame, Particle, Spacetime, Speed, Symmetries};
+
+type BL = BoyerLindquist<Runtime>;
+
+fn dot<C, S>(spacetime: &S, a: &Vector<C>, b: &Vector<C>) -> f64
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    let g = spacetime.g(a.get_point());
+    (0..16).map(|i| g[i] * a[i / 4] * b[i % 4]).sum()
+}
+
+fn assert_orthonormal<C, S>(spacetime: &S, frame: &Frame<C>)
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    let axes = frame.axes();
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = match (i, j) {
+                (0, 0) => 1.0,
+                _ if i == j => -1.0,
+                _ => 0.0,
+            };
+            let product = dot(spacetime, &axes[i], &axes[j]);
+            assert!(
+                (product - expected).abs() < 1e-12,
+                "g(e{}, e{}) = {} at {:?}",
+                i,
+                j,
+                product,
+                frame.get_pos()
+            );
+        }
+    }
+}
+
+// checks that the time leg of the frame, taken to the chart, is the observer's 4-velocity, with
+// the angular momentum `ang_momentum` if given
+fn assert_observer<C, S>(spacetime: &S, frame: &Frame<C>, ang_momentum: Option<f64>)
+where
+    C: Symmetries,
+    S: Spacetime<C>,
+{
+    let u = frame.to_global([1.0, 0.0, 0.0, 0.0]);
+    for i in 0..4 {
+        assert_eq!(u[i], frame.axes()[0][i]);
+    }
+    let particle = Particle::new(*frame.get_pos(), u);
+    let conserved = particle.conserved_in(spacetime);
+    assert!((conserved.norm - 1.0).abs() < 1e-12);
+    if let Some(l) = ang_momentum {
+        assert!((conserved.ang_momentum - l).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn observers_have_orthonormal_frames() {
+    let bh = BlackHole::new(1.0, 0.8);
+    for &(r, theta) in &[(3.0, 1.2), (10.0, 0.4), (30.0, 2.5)] {
+        let x = Point::<BL>::new(arr![f64; 0.0, r, theta, 0.3]);
+        let frame = Frame::static_observer_in(&bh, &x).unwrap();
+        assert_orthonormal(&bh, &frame);
+        assert_observer(&bh, &frame, None);
+        // the static observer moves along the time Killing vector
+        let u = frame.axes()[0];
+        assert!(u[1] == 0.0 && u[2] == 0.0 && u[3] == 0.0 && u[0] > 0.0);
+
+        let frame = Frame::zamo_in(&bh, &x).unwrap();
+        assert_orthonormal(&bh, &frame);
+        assert_observer(&bh, &frame, Some(0.0));
+    }
+
+    // the frames are also built near the pole, in the stereographic coordinates
+    let x = Point::<NearPole0EF<Runtime>>::new(arr![f64; 0.0, 5.0, 0.02, -0.01]);
+    let frame = Frame::zamo_in(&bh, &x).unwrap();
+    assert_orthonormal(&bh, &frame);
+    assert_observer(&bh, &frame, Some(0.0));
+}
+
+#[test]
+fn observers_exist_only_where_they_can() {
+    let bh = BlackHole::new(1.0, 0.8);
+    // inside the ergosphere, r < 1 + sqrt(1 - a^2 cos^2(theta)), only the ZAMO exists
+    let x = Point::<BL>::new(arr![f64; 0.0, 1.8, 1.5, 0.0]);
+    assert!(Frame::static_observer_in(&bh, &x).is_none());
+    let frame = Frame::zamo_in(&bh, &x).unwrap();
+    assert_orthonormal(&bh, &frame);
+    // between the horizons, neither of them does
+    let x = Point::<BL>::new(arr![f64; 0.0, 1.0, 1.5, 0.0]);
+    assert!(Frame::static_observer_in(&bh, &x).is_none());
+    assert!(Frame::zamo_in(&bh, &x).is_none());
+}
+
+#[test]
+fn launched_particles_have_the_right_speed() {
+    let bh = BlackHole::new(1.0, 0.8);
+    let x = Point::<BL>::new(arr![f64; 0.0, 6.0, 1.0, 0.0]);
+    let frame = Frame::zamo_in(&bh, &x).unwrap();
+    let u = frame.axes()[0];
+
+    let particle = frame.particle([1.0, 2.0, -2.0], Speed::Massive(0.6));
+    let v = particle.get_vel();
+    assert!((dot(&bh, v, v) - 1.0).abs() < 1e-12);
+    // the Lorentz factor measured by the observer, and the direction along the axes
+    assert!((dot(&bh, v, &u) - 1.25).abs() < 1e-12);
+    for (i, &d) in [1.0, 2.0, -2.0].iter().enumerate() {
+        let component = -dot(&bh, v, &frame.axes()[i + 1]);
+        assert!((component - 0.75 * d / 3.0).abs() < 1e-12);
+    }
+
+    let photon = frame.particle([0.0, 0.0, 1.0], Speed::Null);
+    let v = photon.get_vel();
+    assert!(dot(&bh, v, v).abs() < 1e-12);
+    assert!((dot(&bh, v, &u) - 1.0).abs() < 1e-12);
+
+    let at_rest = frame.particle([0.0; 3], Speed::Massive(0.0));
+    for i in 0..4 {
+        assert!((at_rest.get_vel()[i] - u[i]).abs() < 1e-12);
+    }
+}
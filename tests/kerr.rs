@@ -0,0 +1,256 @@
+//! Checks the charts of the Kerr and Kerr-Newman spacetimes.
+
+mod common;
+
+use common::{
+    assert_same_conversion, assert_same_metric, check_chart, check_chart_in_box,
+    check_chart_in_range, check_conversion_in_box,
+};
+use gr_engine::coord_systems::kerr::{
+    BlackHole, BoyerLindquist, Doran, EddingtonFinkelstein, KerrSchild, NearPole0EF, NearPolePiEF,
+    Properties,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{DriftReport, Driver, Particle, Spacetime};
+
+type EF<P> = EddingtonFinkelstein<P>;
+type BL<P> = BoyerLindquist<P>;
+
+struct Spinning;
+
+impl Properties for Spinning {
+    fn mass() -> f64 {
+        1.3
+    }
+
+    fn ang_momentum() -> f64 {
+        0.6
+    }
+}
+
+struct Charged;
+
+impl Properties for Charged {
+    fn mass() -> f64 {
+        1.3
+    }
+
+    fn ang_momentum() -> f64 {
+        0.6
+    }
+
+    fn charge() -> f64 {
+        0.4
+    }
+}
+
+fn check_metrics<P: Properties>(bh: &BlackHole) {
+    // outside of the horizons, between them and inside of the inner one
+    for &r in &[0.1, 1.0, 5.0, 30.0] {
+        let x = [0.7, r, 1.1, 0.4];
+        assert_same_metric::<EF<P>, EF<Runtime>, _>(bh, x);
+        assert_same_metric::<BL<P>, BL<Runtime>, _>(bh, x);
+        assert_same_metric::<Doran<P>, Doran<Runtime>, _>(bh, x);
+        let x = [0.7, r, 0.3, -0.2];
+        assert_same_metric::<NearPole0EF<P>, NearPole0EF<Runtime>, _>(bh, x);
+        assert_same_metric::<NearPolePiEF<P>, NearPolePiEF<Runtime>, _>(bh, x);
+    }
+    for &x in &[
+        [0.7, 3.0, -2.0, 1.5],
+        [0.7, 0.2, 0.3, -0.4],
+        [0.7, -9.0, 4.0, 20.0],
+    ] {
+        assert_same_metric::<KerrSchild<P>, KerrSchild<Runtime>, _>(bh, x);
+    }
+}
+
+fn check_conversions<P: Properties + 'static>(bh: &BlackHole) {
+    for &r in &[1.0, 5.0, 30.0] {
+        let x = [0.7, r, 1.1, 0.4];
+        assert_same_conversion::<EF<P>, BL<P>, EF<Runtime>, BL<Runtime>, _>(bh, x);
+        assert_same_conversion::<BL<P>, EF<P>, BL<Runtime>, EF<Runtime>, _>(bh, x);
+        assert_same_conversion::<EF<P>, Doran<P>, EF<Runtime>, Doran<Runtime>, _>(bh, x);
+        assert_same_conversion::<Doran<P>, EF<P>, Doran<Runtime>, EF<Runtime>, _>(bh, x);
+        assert_same_conversion::<EF<P>, KerrSchild<P>, EF<Runtime>, KerrSchild<Runtime>, _>(bh, x);
+        assert_same_conversion::<EF<P>, NearPole0EF<P>, EF<Runtime>, NearPole0EF<Runtime>, _>(
+            bh, x,
+        );
+        let x = [0.7, r, 0.3, -0.2];
+        assert_same_conversion::<NearPolePiEF<P>, EF<P>, NearPolePiEF<Runtime>, EF<Runtime>, _>(
+            bh, x,
+        );
+    }
+    let x = [0.7, 3.0, -2.0, 1.5];
+    assert_same_conversion::<KerrSchild<P>, EF<P>, KerrSchild<Runtime>, EF<Runtime>, _>(bh, x);
+}
+
+#[test]
+fn runtime_charts_match_static_ones() {
+    let kerr = BlackHole::new(1.3, 0.6);
+    check_metrics::<Spinning>(&kerr);
+    check_metrics::<Charged>(&kerr.with_charge(0.4));
+}
+
+#[test]
+fn runtime_conversions_match_static_ones() {
+    let kerr = BlackHole::new(1.3, 0.6);
+    check_conversions::<Spinning>(&kerr);
+    check_conversions::<Charged>(&kerr.with_charge(0.4));
+}
+
+#[test]
+fn boyer_lindquist_chart() {
+    for (i, &(a, q)) in [(0.0, 0.0), (0.5, 0.0), (0.99, 0.0), (0.6, 0.5)]
+        .iter()
+        .enumerate()
+    {
+        let bh = BlackHole::new(1.0, a).with_charge(q);
+        let seed = 60 + 3 * i as u64;
+        // outside of the horizons and inside of the inner one; the chart uses the angles, so
+        // theta is kept away from the poles
+        let (min, max) = ([-10.0, 2.5, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+        check_chart_in_box::<BL<Runtime>, _>(&bh, seed, min, max);
+        check_conversion_in_box::<BL<Runtime>, EF<Runtime>, _>(&bh, seed + 1, min, max);
+        let r_inner = 1.0 - (1.0 - a * a - q * q).sqrt();
+        if r_inner > 0.5 {
+            let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 0.9 * r_inner, 2.8, 3.0]);
+            check_chart_in_box::<BL<Runtime>, _>(&bh, seed + 2, min, max);
+            check_conversion_in_box::<BL<Runtime>, EF<Runtime>, _>(&bh, seed + 2, min, max);
+        }
+    }
+}
+
+#[test]
+fn eddington_finkelstein_chart() {
+    for (i, &(a, q)) in [(0.0, 0.0), (0.5, 0.0), (0.99, 0.0), (0.6, 0.5)]
+        .iter()
+        .enumerate()
+    {
+        let bh = BlackHole::new(1.0, a).with_charge(q);
+        // the chart is regular across both horizons, so this also covers the charge terms of
+        // the Christoffel symbols between and inside of them
+        let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+        check_chart_in_box::<EF<Runtime>, _>(&bh, 125 + i as u64, min, max);
+    }
+}
+
+#[test]
+fn conserved_quantities_stay_constant() {
+    let bh = BlackHole::new(1.3, 0.6);
+    // a bound orbit inclined to the equator, so that the Carter constant has a part from the
+    // spin; u^t is solved for from g(u, u) = 1
+    let x = Point::<BL<Runtime>>::new(arr![f64; 0.0, 15.0, 1.2, 0.0]);
+    let (ur, uth, uph) = (-0.01, 0.011, 0.0215);
+    let g = bh.g(&x);
+    let b = g[3] * uph;
+    let c = g[5] * ur * ur + g[10] * uth * uth + g[15] * uph * uph - 1.0;
+    let ut = (-b + (b * b - g[0] * c).sqrt()) / g[0];
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; ut, ur, uth, uph]));
+
+    // the static chart calculates the same quantities with the parameters it knows
+    let initial = particle.conserved_in(&bh);
+    let xs = Point::<BL<Spinning>>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let static_particle = Particle::new(xs, Vector::new(xs, arr![f64; ut, ur, uth, uph]));
+    assert_eq!(static_particle.conserved(), initial);
+    assert!((initial.norm - 1.0).abs() < 1e-12);
+    assert!(initial.carter > 1.0, "Q = {}", initial.carter);
+
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 1.0, 1e-12));
+    let mut report = DriftReport::new(initial);
+    while driver.param() < 1000.0 {
+        driver.step(&mut particle, |p: &Particle<BL<Runtime>>| {
+            p.derivative_in(&bh)
+        });
+        report.add(particle.conserved_in(&bh));
+    }
+    let drift = report.max_relative_drift();
+    assert!(
+        drift.energy < 1e-8
+            && drift.ang_momentum < 1e-8
+            && drift.norm < 1e-8
+            && drift.carter < 1e-8,
+        "{}",
+        report
+    );
+}
+
+#[test]
+fn kerr_newman_polar_charts() {
+    for &(a, q) in &[(0.5, 0.5), (0.9, 0.3), (0.0, 0.9)] {
+        let bh = BlackHole::new(1.0, a).with_charge(q);
+        check_chart::<NearPole0EF<Runtime>, _>(&bh, 13);
+        check_chart::<NearPolePiEF<Runtime>, _>(&bh, 14);
+        // the Eddington-Finkelstein charts are regular across both horizons
+        check_chart_in_range::<NearPole0EF<Runtime>, _>(&bh, 15, 0.3, 2.5);
+        check_chart_in_range::<NearPolePiEF<Runtime>, _>(&bh, 16, 0.3, 2.5);
+    }
+}
+
+#[test]
+fn doran_chart() {
+    for (i, &(a, q)) in [(0.0, 0.0), (0.5, 0.0), (0.99, 0.0), (0.6, 0.5)]
+        .iter()
+        .enumerate()
+    {
+        let bh = BlackHole::new(1.0, a).with_charge(q);
+        let seed = 78 + 2 * i as u64;
+        // the chart is regular across both horizons, down to where 2mr = q^2
+        let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+        check_chart_in_box::<Doran<Runtime>, _>(&bh, seed, min, max);
+        check_conversion_in_box::<Doran<Runtime>, EF<Runtime>, _>(&bh, seed + 1, min, max);
+    }
+}
+
+#[test]
+fn kerr_schild_chart() {
+    for (i, &(a, q)) in [(0.0, 0.0), (0.5, 0.0), (0.99, 0.0), (0.6, 0.5)]
+        .iter()
+        .enumerate()
+    {
+        let bh = BlackHole::new(1.0, a).with_charge(q);
+        let seed = 86 + 4 * i as u64;
+        // above and below the disk spanned by the ring singularity, across both horizons
+        for (j, &(z_min, z_max)) in [(0.5, 10.0), (-10.0, -0.5)].iter().enumerate() {
+            let (min, max) = ([-10.0, -10.0, -10.0, z_min], [10.0, 10.0, 10.0, z_max]);
+            let seed = seed + 2 * j as u64;
+            check_chart_in_box::<KerrSchild<Runtime>, _>(&bh, seed, min, max);
+            check_conversion_in_box::<KerrSchild<Runtime>, EF<Runtime>, _>(&bh, seed + 1, min, max);
+        }
+    }
+}
+
+#[test]
+fn kerr_schild_conserved_quantities() {
+    let bh = BlackHole::new(1.3, 0.6);
+    let x = Point::<KerrSchild<Runtime>>::new(arr![f64; 0.4, 6.0, -3.0, 4.0]);
+    let u = [1.2, 0.1, 0.25, -0.15];
+    let particle = Particle::new(x, Vector::new(x, arr![f64; u[0], u[1], u[2], u[3]]));
+
+    // the same geodesic in the Eddington-Finkelstein coordinates, where the Carter constant takes
+    // its usual form
+    let conserved = particle.conserved_in(&bh);
+    let expected = particle.convert_in::<EF<Runtime>, _>(&bh).conserved_in(&bh);
+    assert!(expected.carter.abs() > 1.0, "Q = {}", expected.carter);
+    for &(value, expected) in &[
+        (conserved.energy, expected.energy),
+        (conserved.ang_momentum, expected.ang_momentum),
+        (conserved.norm, expected.norm),
+        (conserved.carter, expected.carter),
+    ] {
+        assert!(
+            (value - expected).abs() < 1e-10 * expected.abs().max(1.0),
+            "{} != {}",
+            value,
+            expected
+        );
+    }
+
+    // the static chart calculates the same quantities with the parameters it knows
+    let xs = Point::<KerrSchild<Spinning>>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let static_particle = Particle::new(xs, Vector::new(xs, arr![f64; u[0], u[1], u[2], u[3]]));
+    assert_eq!(static_particle.conserved(), conserved);
+}
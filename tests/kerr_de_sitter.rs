@@ -0,0 +1,143 @@
+//! Checks the charts of the Kerr-de Sitter spacetime and the Carter constant of geodesics in it.
+
+mod common;
+
+use common::{check_chart_in_box, check_chart_in_range, check_conversion_in_box};
+use gr_engine::coord_systems::kerr_de_sitter::{
+    BlackHole, BoyerLindquist, EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF,
+    NearPolePiEF, NearPolePiOutgoingEF, OutgoingEF, Properties,
+};
+use gr_engine::coord_systems::{kerr, Runtime};
+use gr_engine::diffgeom::coordinates::{CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{DriftReport, Driver, Particle, Spacetime};
+
+type BL<P> = BoyerLindquist<P>;
+type EF = EddingtonFinkelstein<Runtime>;
+type Out = OutgoingEF<Runtime>;
+
+struct Expanding;
+
+impl Properties for Expanding {
+    fn mass() -> f64 {
+        1.3
+    }
+
+    fn ang_momentum() -> f64 {
+        0.6
+    }
+
+    fn cosmological_constant() -> f64 {
+        1e-4
+    }
+}
+
+// a particle at x with the spatial components (ur, uth, uph) of the 4-velocity; u^t is solved
+// for from g(u, u) = 1
+fn timelike<C, S>(spacetime: &S, x: [f64; 4], (ur, uth, uph): (f64, f64, f64)) -> Particle<C>
+where
+    C: CoordinateSystem<Dimension = U4>,
+    S: Spacetime<C>,
+{
+    let x = Point::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let g = spacetime.g(&x);
+    let b = g[3] * uph;
+    let c = g[5] * ur * ur + g[10] * uth * uth + g[15] * uph * uph - 1.0;
+    let ut = (-b + (b * b - g[0] * c).sqrt()) / g[0];
+    Particle::new(x, Vector::new(x, arr![f64; ut, ur, uth, uph]))
+}
+
+#[test]
+fn kerr_de_sitter_polar_charts() {
+    for &(a, l) in &[(0.0, 0.01), (0.5, 0.01), (0.9, 0.03)] {
+        let bh = BlackHole::new(1.0, a, l);
+        check_chart_in_range::<NearPole0EF<Runtime>, _>(&bh, 23, 0.3, 40.0);
+        check_chart_in_range::<NearPolePiEF<Runtime>, _>(&bh, 24, 0.3, 40.0);
+        check_chart_in_range::<NearPole0OutgoingEF<Runtime>, _>(&bh, 25, 0.3, 40.0);
+        check_chart_in_range::<NearPolePiOutgoingEF<Runtime>, _>(&bh, 26, 0.3, 40.0);
+    }
+}
+
+#[test]
+fn kerr_de_sitter_charts() {
+    for (i, &(a, l)) in [(0.0, 0.01), (0.5, 0.01), (0.9, 0.03)].iter().enumerate() {
+        let bh = BlackHole::new(1.0, a, l);
+        let (r_in, r_h) = (bh.inner_horizon_radius(), bh.horizon_radius());
+        let r_c = bh.cosmological_horizon_radius();
+        let seed = 158 + 13 * i as u64;
+        // Boyer-Lindquist is singular on the horizons, so it is checked between them, inside
+        // of the inner one where that leaves some room and beyond the cosmological one
+        let mut ranges = vec![
+            (1.1 * r_h, 0.9 * r_c),
+            (1.1 * r_c, 1.5 * r_c),
+            (r_in + 0.1 * (r_h - r_in), r_h - 0.1 * (r_h - r_in)),
+        ];
+        if r_in > 0.5 {
+            ranges.push((0.3, 0.8 * r_in));
+        }
+        for (j, &(r_min, r_max)) in ranges.iter().enumerate() {
+            let (min, max) = ([-10.0, r_min, 0.3, -3.0], [10.0, r_max, 2.8, 3.0]);
+            let seed = seed + 3 * j as u64;
+            check_chart_in_box::<BL<Runtime>, _>(&bh, seed, min, max);
+            check_conversion_in_box::<BL<Runtime>, EF, _>(&bh, seed + 1, min, max);
+            check_conversion_in_box::<BL<Runtime>, Out, _>(&bh, seed + 2, min, max);
+        }
+        // the Eddington-Finkelstein charts are regular across all of the horizons
+        let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 1.5 * r_c, 2.8, 3.0]);
+        check_chart_in_box::<EF, _>(&bh, seed + 12, min, max);
+        check_chart_in_box::<Out, _>(&bh, seed + 12, min, max);
+    }
+}
+
+#[test]
+fn carter_constant_reduces_to_kerr() {
+    let (x, u) = ([0.0, 6.0, 0.7, 0.0], (0.05, -0.05, 0.03));
+    let bh = BlackHole::new(1.3, 0.6, 0.0);
+    let particle: Particle<BL<Runtime>> = timelike(&bh, x, u);
+    let conserved = particle.conserved_in(&bh);
+
+    let kerr = kerr::BlackHole::new(1.3, 0.6);
+    let kerr_particle: Particle<kerr::BoyerLindquist<Runtime>> = timelike(&kerr, x, u);
+    let expected = kerr_particle.conserved_in(&kerr);
+    assert!(conserved.carter.abs() > 1.0);
+    assert!((conserved.carter - expected.carter).abs() < 1e-12 * expected.carter.abs());
+}
+
+#[test]
+fn conserved_quantities_stay_constant() {
+    let bh = BlackHole::new(1.3, 0.6, 1e-4);
+    // a bound orbit inclined to the equator, well inside of the cosmological horizon; the Carter
+    // constant of Kerr isn't conserved along it
+    let mut particle: Particle<BL<Runtime>> =
+        timelike(&bh, [0.0, 12.0, 1.2, 0.0], (-0.005, 0.015, 0.029));
+
+    // the static chart calculates the same quantities with the parameters it knows
+    let initial = particle.conserved_in(&bh);
+    let (x, u) = (particle.get_pos(), particle.get_vel());
+    let xs = Point::<BL<Expanding>>::new(arr![f64; x[0], x[1], x[2], x[3]]);
+    let static_particle = Particle::new(xs, Vector::new(xs, arr![f64; u[0], u[1], u[2], u[3]]));
+    assert_eq!(static_particle.conserved(), initial);
+    assert!((initial.norm - 1.0).abs() < 1e-12);
+    assert!(initial.carter > 1.0, "Q = {}", initial.carter);
+
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 1.0, 1e-12));
+    let mut report = DriftReport::new(initial);
+    while driver.param() < 1000.0 {
+        driver.step(&mut particle, |p: &Particle<BL<Runtime>>| {
+            p.derivative_in(&bh)
+        });
+        report.add(particle.conserved_in(&bh));
+    }
+    let drift = report.max_relative_drift();
+    assert!(
+        drift.energy < 1e-8
+            && drift.ang_momentum < 1e-8
+            && drift.norm < 1e-8
+            && drift.carter < 1e-8,
+        "{}",
+        report
+    );
+}
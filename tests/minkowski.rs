@@ -0,0 +1,74 @@
+//! Checks the charts of the Minkowski spacetime and the conversions between them, and follows a
+//! uniformly accelerating observer along its hyperbola.
+
+mod common;
+
+use common::{check_chart, check_chart_in_box, check_chart_in_range, check_conversion_in_box};
+use gr_engine::coord_systems::minkowski::{
+    Cartesian, Minkowski, NearPole0Spherical, NearPolePiSpherical, Rindler, Rotating, Spherical,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Entity};
+
+type Cart = Cartesian<Runtime>;
+type Sph = Spherical<Runtime>;
+
+#[test]
+fn minkowski_charts() {
+    let spacetime = Minkowski::new(0.7, 0.1);
+    check_chart::<NearPole0Spherical<Runtime>, _>(&spacetime, 27);
+    check_chart::<NearPolePiSpherical<Runtime>, _>(&spacetime, 28);
+    // the Rindler chart needs xi > 0, the rotating one a speed of the frame below 1
+    check_chart_in_range::<Rindler<Runtime>, _>(&spacetime, 29, 0.1, 20.0);
+    check_chart_in_range::<Rotating<Runtime>, _>(&spacetime, 30, -5.0, 5.0);
+    check_chart_in_box::<Cart, _>(&spacetime, 200, [-10.0; 4], [10.0; 4]);
+    check_chart_in_box::<Sph, _>(
+        &spacetime,
+        201,
+        [-10.0, 0.5, 0.3, -3.0],
+        [10.0, 20.0, 2.8, 3.0],
+    );
+}
+
+#[test]
+fn minkowski_conversions() {
+    let spacetime = Minkowski::new(0.7, 0.1);
+    // x > 0 keeps the points off the axis and the branch cut of phi
+    let (min, max) = ([-10.0, 0.5, -10.0, -10.0], [10.0, 10.0, 10.0, 10.0]);
+    check_conversion_in_box::<Cart, Sph, _>(&spacetime, 202, min, max);
+    // the Rindler wedge x > |t|
+    let (min, max) = ([-3.0, 4.0, -10.0, -10.0], [3.0, 20.0, 10.0, 10.0]);
+    check_conversion_in_box::<Cart, Rindler<Runtime>, _>(&spacetime, 203, min, max);
+    check_conversion_in_box::<Cart, Rotating<Runtime>, _>(&spacetime, 204, [-10.0; 4], [10.0; 4]);
+    let (min, max) = ([-10.0, 0.5, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_conversion_in_box::<Sph, NearPole0Spherical<Runtime>, _>(&spacetime, 205, min, max);
+    check_conversion_in_box::<Sph, NearPolePiSpherical<Runtime>, _>(&spacetime, 206, min, max);
+}
+
+#[test]
+fn uniform_acceleration_follows_a_hyperbola() {
+    let a = 0.7;
+    let spacetime = Minkowski::new(a, 0.0);
+    // at rest at x = 1/a, pushed forward along the x axis with the proper acceleration a
+    let x = Point::<Cart>::new(arr![f64; 0.0, 1.0 / a, 0.0, 0.0]);
+    let axis = |i: usize| {
+        let mut v = Vector::new(x, arr![f64; 0.0, 0.0, 0.0, 0.0]);
+        v[i] = 1.0;
+        v
+    };
+    let mut entity = Entity::new(x, axis(0), axis(1), axis(2), axis(3));
+    entity.add_force(a, 0.0, 0.0);
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-4, 1.0, 1e-12));
+    while driver.param() < 5.0 {
+        driver.step(&mut entity, |e: &Entity<Cart>| e.derivative_in(&spacetime));
+        let (tau, x) = (driver.param(), entity.get_pos());
+        // x^2 - t^2 = 1/a^2, and the proper time is the Rindler time of the observer
+        assert!((x[1] * x[1] - x[0] * x[0] - 1.0 / (a * a)).abs() < 1e-8 * x[1] * x[1]);
+        assert!((x[0] - (a * tau).sinh() / a).abs() < 1e-8 * (1.0 + x[0].abs()));
+        assert!(x[2].abs() < 1e-12 && x[3].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,218 @@
+//! Propagates dust, light and an entity through the Oppenheimer-Snyder collapse of a star of
+//! mass 1 starting from the radius 5: the dust inside follows the closed FLRW cycloid, and light
+//! and entities cross the surface of the star in both directions. Also checks the Christoffel
+//! symbols of the chart and compares it with the static one.
+
+mod common;
+
+use common::{assert_same_metric, check_chart_in_box};
+use gr_engine::coord_systems::oppenheimer_snyder::{CollapsingStar, OppenheimerSnyder, Properties};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Entity, Particle, Spacetime};
+use std::f64::consts::PI;
+
+type Coords = OppenheimerSnyder<Runtime>;
+
+const MASS: f64 = 1.0;
+const RADIUS: f64 = 5.0;
+
+struct Collapsing;
+
+impl Properties for Collapsing {
+    fn mass() -> f64 {
+        MASS
+    }
+
+    fn initial_radius() -> f64 {
+        RADIUS
+    }
+}
+
+struct Compact;
+
+impl Properties for Compact {
+    fn mass() -> f64 {
+        1.5
+    }
+
+    fn initial_radius() -> f64 {
+        4.0
+    }
+}
+
+fn dot(star: &CollapsingStar, a: &Vector<Coords>, b: &Vector<Coords>) -> f64 {
+    let g = star.g(a.get_point());
+    (0..16).map(|i| g[i] * a[i / 4] * b[i % 4]).sum()
+}
+
+// +1 outside of the star, -1 inside
+fn side(star: &CollapsingStar, x: &Point<Coords>) -> f64 {
+    (x[1] - star.surface_radius(x[0])).signum()
+}
+
+#[test]
+fn dust_follows_the_cycloid() {
+    let star = CollapsingStar::new(MASS, RADIUS);
+    // the interior is a closed FLRW universe with the scale factor a = a_max cos^2(eta / 2), in
+    // which the surface lies at chi0
+    let sin0 = (2.0 * MASS / RADIUS).sqrt();
+    let (chi0, cos0) = (sin0.asin(), (1.0 - sin0 * sin0).sqrt());
+    let a_max = RADIUS / sin0;
+    let scale = |eta: f64| a_max * (0.5 * eta).cos().powi(2);
+    let time = |eta: f64| 0.5 * a_max * (eta + eta.sin());
+
+    // the ingoing light ray v = 0 leaves the surface at eta = 0 and reaches chi at eta = chi0 - chi
+    for &chi in &[chi0, 0.5 * chi0, 0.1 * chi0] {
+        let eta0 = chi0 - chi;
+        let x = Point::new(arr![f64; 0.0, scale(eta0) * chi.sin(), PI / 2.0, 0.0]);
+        // comoving with the dust, d/dt = (1/a) d/deta at constant chi
+        let (a, da) = (scale(eta0), -0.5 * a_max * eta0.sin());
+        let u = Vector::new(x, arr![f64; a_max / cos0 / a, da * chi.sin() / a, 0.0, 0.0]);
+        assert!((dot(&star, &u, &u) - 1.0).abs() < 1e-12);
+
+        let mut particle = Particle::new(x, u);
+        let mut driver = Driver::new(DenseDPIntegrator::new(0.01, 1e-5, 0.5, 1e-12));
+        let end = time(2.8) - time(eta0);
+        while driver.param() < end {
+            driver.step(&mut particle, |p: &Particle<Coords>| p.derivative_in(&star));
+            // the conformal time after the proper time tau
+            let tau = driver.param() + time(eta0);
+            let (mut lo, mut hi) = (0.0, PI);
+            for _ in 0..60 {
+                let mid = 0.5 * (lo + hi);
+                if time(mid) < tau {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            let expected = scale(lo) * chi.sin();
+            let r = particle.get_pos()[1];
+            assert!(
+                (r - expected).abs() < 1e-6,
+                "chi = {}: r = {}, expected {}",
+                chi,
+                r,
+                expected
+            );
+        }
+    }
+}
+
+// propagates an outgoing radial light ray from the radius `r` at the advanced time `v` until it
+// escapes or falls into the singularity; returns its last position and the number of times it
+// crossed the surface
+fn outgoing_ray(star: &CollapsingStar, v: f64, r: f64) -> (Point<Coords>, usize) {
+    let x = Point::new(arr![f64; v, r, PI / 2.0, 0.0]);
+    let g = star.g(&x);
+    let u = Vector::new(x, arr![f64; 1.0, -0.5 * g[0] / g[1], 0.0, 0.0]);
+    assert!(dot(star, &u, &u).abs() < 1e-12);
+    let mut ray = Particle::new(x, u);
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.01, 1e-6, 0.5, 1e-10));
+    let mut crossings = 0;
+    let mut last = side(star, &x);
+    while ray.get_pos()[1] > 0.1 && ray.get_pos()[1] < 50.0 && ray.get_pos()[0] < 200.0 {
+        driver.step(&mut ray, |p: &Particle<Coords>| p.derivative_in(star));
+        let now = side(star, ray.get_pos());
+        if now != last {
+            crossings += 1;
+            last = now;
+        }
+    }
+    (*ray.get_pos(), crossings)
+}
+
+#[test]
+fn light_leaves_the_star_before_the_horizon_forms() {
+    let star = CollapsingStar::new(MASS, RADIUS);
+
+    // emitted near the centre early enough, the light escapes to infinity
+    let (x, crossings) = outgoing_ray(&star, -2.0, 0.5);
+    assert_eq!(crossings, 1);
+    assert!(x[1] >= 50.0, "r = {} at v = {}", x[1], x[0]);
+
+    // the event horizon has already formed inside the star, while its surface is still outside
+    // of r = 2m
+    assert!(star.surface_radius(9.0) > 2.0 * MASS);
+    let (x, crossings) = outgoing_ray(&star, 9.0, 0.5);
+    assert!(crossings <= 1);
+    assert!(x[1] <= 0.1, "r = {} at v = {}", x[1], x[0]);
+}
+
+#[test]
+fn entity_falls_into_the_star() {
+    let star = CollapsingStar::new(MASS, RADIUS);
+    let r = 12.0;
+    let x = Point::new(arr![f64; 0.0, r, PI / 2.0, 0.0]);
+    // inwards at dr/dtau = -0.8, solving (1 - 2m/r) u^2 - 2 u dr/dtau = 1 for u = dv/dtau
+    let (f, dr) = (1.0 - 2.0 * MASS / r, -0.8);
+    let dv = (dr + (dr * dr + f).sqrt()) / f;
+    let mut entity = Entity::new(
+        x,
+        Vector::new(x, arr![f64; dv, dr, 0.0, 0.0]),
+        Vector::new(x, arr![f64; 0.0, -1.0, 0.0, 0.0]),
+        Vector::new(x, arr![f64; 0.0, 0.0, 1.0 / r, 0.0]),
+        Vector::new(x, arr![f64; 0.0, 0.0, 0.0, 1.0 / r]),
+    );
+    entity.orthonormalize_in(&star);
+
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.01, 1e-5, 0.5, 1e-10));
+    let mut crossings = 0;
+    let mut last = side(&star, &x);
+    while entity.get_pos()[1] > 0.5 && driver.param() < 50.0 {
+        driver.step(&mut entity, |e: &Entity<Coords>| e.derivative_in(&star));
+        let now = side(&star, entity.get_pos());
+        if now != last {
+            crossings += 1;
+            last = now;
+        }
+    }
+
+    // it ends up inside the star, before the surface reaches the singularity, with its frame
+    // still orthonormal
+    assert_eq!(crossings, 1);
+    assert!(last < 0.0);
+    let x = *entity.get_pos();
+    assert!(x[1] <= 0.5 && star.surface_radius(x[0]) > 1.0, "{:?}", x);
+    let axes = *entity.frame().axes();
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = match (i, j) {
+                (0, 0) => 1.0,
+                _ if i == j => -1.0,
+                _ => 0.0,
+            };
+            assert!((dot(&star, &axes[i], &axes[j]) - expected).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn oppenheimer_snyder_chart() {
+    // the star collapses into the singularity around v = 11.6; most of the points with r < 5 lie
+    // inside of it before that
+    let star = CollapsingStar::new(MASS, RADIUS);
+    let min = [-4.0, 0.3, 0.3, -3.0];
+    let max = [11.0, 8.0, 2.8, 3.0];
+    check_chart_in_box::<Coords, _>(&star, 53, min, max);
+}
+
+#[test]
+fn runtime_chart_matches_static_ones() {
+    let (star, compact) = (
+        CollapsingStar::new(MASS, RADIUS),
+        CollapsingStar::new(1.5, 4.0),
+    );
+    // alternating between the stars, inside and outside of them, before and after the collapse
+    for &v in &[-3.0, 0.0, 4.0, 20.0] {
+        for &r in &[0.5, 2.5, 6.0] {
+            let x = [v, r, 1.1, 0.4];
+            assert_same_metric::<OppenheimerSnyder<Collapsing>, Coords, _>(&star, x);
+            assert_same_metric::<OppenheimerSnyder<Compact>, Coords, _>(&compact, x);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Compares the analytic Christoffel symbols of the polar charts of the Schwarzschild and Kerr
+//! spacetimes with a finite-difference evaluation of their metrics, and checks their inverse
+//! metrics. The charts of the other spacetimes are checked in their own tests.
+
+mod common;
+
+use common::{check_chart, check_chart_in_range};
+use gr_engine::coord_systems::{kerr, schwarzschild, Runtime};
+
+#[test]
+fn schwarzschild_polar_charts() {
+    let bh = schwarzschild::BlackHole::new(1.0);
+    check_chart::<schwarzschild::NearPole0Schw<Runtime>, _>(&bh, 1);
+    check_chart::<schwarzschild::NearPolePiSchw<Runtime>, _>(&bh, 2);
+    check_chart::<schwarzschild::NearPole0EF<Runtime>, _>(&bh, 3);
+    check_chart::<schwarzschild::NearPolePiEF<Runtime>, _>(&bh, 4);
+    check_chart::<schwarzschild::NearPole0OutgoingEF<Runtime>, _>(&bh, 31);
+    check_chart::<schwarzschild::NearPolePiOutgoingEF<Runtime>, _>(&bh, 32);
+    // the outgoing chart is regular across the past horizon
+    check_chart_in_range::<schwarzschild::NearPole0OutgoingEF<Runtime>, _>(&bh, 33, 0.3, 2.5);
+    check_chart_in_range::<schwarzschild::NearPolePiOutgoingEF<Runtime>, _>(&bh, 34, 0.3, 2.5);
+}
+
+#[test]
+fn kerr_polar_charts() {
+    for &a in &[0.0, 0.5, 0.99] {
+        let bh = kerr::BlackHole::new(1.0, a);
+        check_chart::<kerr::NearPole0EF<Runtime>, _>(&bh, 5);
+        check_chart::<kerr::NearPolePiEF<Runtime>, _>(&bh, 6);
+    }
+}
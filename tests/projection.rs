@@ -0,0 +1,132 @@
+//! Projects particles around a Schwarzschild black hole of mass 1 back onto their constraints,
+//! both by hand and after every step of a driver.
+
+use gr_engine::coord_systems::schwarzschild::{BlackHole, Schwarzschild};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Causality, Constraints, Driver, Particle};
+use std::f64::consts::PI;
+
+type Coords = Schwarzschild<Runtime>;
+
+const R0: f64 = 10.0;
+
+// a particle on the equator at R0 with the 4-velocity (ut, ur, 0, uphi)
+fn particle(ut: f64, ur: f64, uphi: f64) -> Particle<Coords> {
+    let x = Point::new(arr![f64; 0.0, R0, PI / 2.0, 0.0]);
+    Particle::new(x, Vector::new(x, arr![f64; ut, ur, 0.0, uphi]))
+}
+
+// propagates the particle with a loose tolerance, so that it noticeably drifts off its
+// constraints unless projected; returns the largest deviation of its norm from the initial one
+fn max_norm_drift(driver: &mut Driver<Particle<Coords>>, particle: &mut Particle<Coords>) -> f64 {
+    let bh = BlackHole::new(1.0);
+    let norm = particle.conserved_in(&bh).norm;
+    let mut max_drift: f64 = 0.0;
+    while driver.param() < 200.0 && particle.get_pos()[1] > 3.0 && particle.get_pos()[1] < 50.0 {
+        driver.step(particle, |p: &Particle<Coords>| p.derivative_in(&bh));
+        max_drift = max_drift.max((particle.conserved_in(&bh).norm - norm).abs());
+    }
+    max_drift
+}
+
+fn loose_driver() -> Driver<Particle<Coords>> {
+    Driver::new(DenseDPIntegrator::new(0.5, 1e-3, 5.0, 1e-5))
+}
+
+#[test]
+fn projection_keeps_the_norm_of_massive_particles() {
+    let bh = BlackHole::new(1.0);
+    let f = 1.0 - 2.0 / R0;
+    let uphi = 0.03;
+    let ut = ((1.0 + R0 * R0 * uphi * uphi) / f).sqrt();
+
+    let mut free = particle(ut, 0.0, uphi);
+    let free_drift = max_norm_drift(&mut loose_driver(), &mut free);
+
+    let constraints = Constraints::new(Causality::Timelike);
+    let mut driver = loose_driver()
+        .with_projection(move |p: &mut Particle<Coords>| p.project_in(&bh, &constraints));
+    let mut projected = particle(ut, 0.0, uphi);
+    let projected_drift = max_norm_drift(&mut driver, &mut projected);
+
+    assert!(free_drift > 1e-8, "drift without projection {}", free_drift);
+    assert!(
+        projected_drift < 1e-12,
+        "drift with projection {}",
+        projected_drift
+    );
+    assert_eq!(driver.projection_failures(), 0);
+}
+
+#[test]
+fn projection_keeps_light_on_the_null_cone() {
+    let bh = BlackHole::new(1.0);
+    let f = 1.0 - 2.0 / R0;
+    // a photon moving outwards at an angle
+    let (ur, uphi) = (0.6, 0.08);
+    let ut = ((ur * ur / f + R0 * R0 * uphi * uphi) / f).sqrt();
+
+    let constraints = Constraints::new(Causality::Null);
+    let mut driver = loose_driver()
+        .with_projection(move |p: &mut Particle<Coords>| p.project_in(&bh, &constraints));
+    let mut photon = particle(ut, ur, uphi);
+    assert!(photon.conserved_in(&bh).norm.abs() < 1e-12);
+    let drift = max_norm_drift(&mut driver, &mut photon);
+    assert!(drift < 1e-12, "drift with projection {}", drift);
+    assert!(photon.get_pos()[1] >= 50.0);
+}
+
+#[test]
+fn projection_fixes_the_energy_and_angular_momentum() {
+    let bh = BlackHole::new(1.0);
+    let (energy, l) = (0.97, 4.0);
+    let constraints = Constraints::new(Causality::Timelike)
+        .with_energy(energy)
+        .with_ang_momentum(l);
+
+    // a 4-velocity only roughly matching the constraints
+    let mut p = particle(1.1, -0.1, 0.035);
+    assert!(p.project_in(&bh, &constraints));
+    let conserved = p.conserved_in(&bh);
+    assert!((conserved.energy - energy).abs() < 1e-12);
+    assert!((conserved.ang_momentum - l).abs() < 1e-12);
+    assert!((conserved.norm - 1.0).abs() < 1e-12);
+    // only the radial component was left free; it keeps its direction
+    assert!(p.get_vel()[1] < 0.0);
+}
+
+#[test]
+fn projection_fails_for_unreachable_constraints() {
+    let bh = BlackHole::new(1.0);
+    // below the energy of a particle at rest at R0, sqrt(1 - 2/R0), no timelike 4-velocity
+    // has this energy
+    let constraints = Constraints::new(Causality::Timelike).with_energy(0.5);
+    let f = 1.0 - 2.0 / R0;
+    let ut = (1.0 / f).sqrt();
+    assert!(!particle(ut, 0.0, 0.0).project_in(&bh, &constraints));
+    // neither has any with this angular momentum and no energy at all
+    let constraints = Constraints::new(Causality::Timelike)
+        .with_energy(0.0)
+        .with_ang_momentum(1.0);
+    assert!(!particle(ut, 0.0, 0.0).project_in(&bh, &constraints));
+
+    // a driver counts the failures and leaves the state as it was integrated
+    let constraints = Constraints::new(Causality::Timelike).with_energy(0.5);
+    let mut driver = loose_driver()
+        .with_projection(move |p: &mut Particle<Coords>| p.project_in(&bh, &constraints));
+    let mut plain = loose_driver();
+    let (mut projected, mut free) = (particle(ut, 0.0, 0.02), particle(ut, 0.0, 0.02));
+    for _ in 0..10 {
+        driver.step(&mut projected, |p: &Particle<Coords>| p.derivative_in(&bh));
+        plain.step(&mut free, |p: &Particle<Coords>| p.derivative_in(&bh));
+    }
+    assert_eq!(driver.projection_failures(), 10);
+    for i in 0..4 {
+        assert_eq!(projected.get_pos()[i], free.get_pos()[i]);
+        assert_eq!(projected.get_vel()[i], free.get_vel()[i]);
+    }
+}
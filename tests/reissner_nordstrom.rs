@@ -0,0 +1,107 @@
+//! Checks the charts of the Reissner-Nordström spacetime and follows a particle falling through
+//! both of its horizons.
+
+mod common;
+
+use common::{check_chart, check_chart_in_box, check_chart_in_range, check_conversion_in_box};
+use gr_engine::coord_systems::reissner_nordstrom::{
+    BlackHole, EddingtonFinkelstein, NearPole0EF, NearPole0RN, NearPolePiEF, NearPolePiRN,
+    ReissnerNordstrom,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Crossing, Driver, Event, Particle};
+use std::f64::consts::PI;
+
+type EF = EddingtonFinkelstein<Runtime>;
+type RN = ReissnerNordstrom<Runtime>;
+
+#[test]
+fn reissner_nordstrom_polar_charts() {
+    for &q in &[0.0, 0.5, 0.9] {
+        let bh = BlackHole::new(1.0, q);
+        check_chart::<NearPole0RN<Runtime>, _>(&bh, 7);
+        check_chart::<NearPolePiRN<Runtime>, _>(&bh, 8);
+        check_chart::<NearPole0EF<Runtime>, _>(&bh, 9);
+        check_chart::<NearPolePiEF<Runtime>, _>(&bh, 10);
+        // the Eddington-Finkelstein charts are regular across both horizons
+        check_chart_in_range::<NearPole0EF<Runtime>, _>(&bh, 11, 0.3, 2.5);
+        check_chart_in_range::<NearPolePiEF<Runtime>, _>(&bh, 12, 0.3, 2.5);
+    }
+}
+
+#[test]
+fn reissner_nordstrom_charts() {
+    for (i, &q) in [0.0, 0.5, 0.9].iter().enumerate() {
+        let bh = BlackHole::new(1.0, q);
+        let (r_outer, r_inner) = (bh.horizon_radius(), bh.inner_horizon_radius());
+        let seed = 102 + 7 * i as u64;
+        // outside of the outer horizon, between the horizons and inside of the inner one; the
+        // static chart and the conversions are singular on the horizons
+        let mut ranges = vec![(r_outer + 0.2, 20.0)];
+        if q > 0.0 {
+            ranges.push((r_inner + 0.1, r_outer - 0.1));
+            ranges.push((0.5 * r_inner, 0.8 * r_inner));
+        }
+        for (j, &(r_min, r_max)) in ranges.iter().enumerate() {
+            let (min, max) = ([-10.0, r_min, 0.3, -3.0], [10.0, r_max, 2.8, 3.0]);
+            let seed = seed + 2 * j as u64;
+            check_chart_in_box::<RN, _>(&bh, seed, min, max);
+            check_conversion_in_box::<RN, EF, _>(&bh, seed + 1, min, max);
+        }
+        // the Eddington-Finkelstein chart is regular across both horizons
+        let (min, max) = (
+            [-10.0, 0.5 * r_inner.max(0.2), 0.3, -3.0],
+            [10.0, 20.0, 2.8, 3.0],
+        );
+        check_chart_in_box::<EF, _>(&bh, seed + 6, min, max);
+    }
+}
+
+#[test]
+fn radial_fall_crosses_both_horizons() {
+    let bh = BlackHole::new(1.0, 0.5);
+    let (r_outer, r_inner) = (bh.horizon_radius(), bh.inner_horizon_radius());
+    let r0 = 10.0;
+    let f = |r: f64| 1.0 - 2.0 / r + 0.25 / (r * r);
+    // at rest at r0: in the EF coordinates v = t + r*, dv/dtau = dt/dtau
+    let energy = f(r0).sqrt();
+    let x = Point::<EF>::new(arr![f64; 0.0, r0, PI / 2.0, 0.0]);
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; 1.0 / energy, 0.0, 0.0, 0.0]));
+
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.1, 1e-5, 1.0, 1e-12))
+        .with_tolerance(1e-12)
+        .with_event(Event::radius(r_outer).with_direction(Crossing::Falling))
+        .with_event(Event::radius(r_inner).with_direction(Crossing::Falling))
+        // the charge repels the particle inside of the inner horizon
+        .with_event(
+            Event::new("turning point", |p: &Particle<EF>| p.get_vel()[1])
+                .with_direction(Crossing::Rising)
+                .with_terminal(true),
+        );
+    let hits = driver.run(
+        &mut particle,
+        |p: &Particle<EF>| p.derivative_in(&bh),
+        10_000,
+    );
+
+    let indices: Vec<usize> = hits.iter().map(|hit| hit.index).collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    // the radial velocity follows from the energy, (dr/dtau)^2 = E^2 - f(r), so that it equals
+    // -E on both horizons
+    for hit in &hits[..2] {
+        let ur = hit.state.get_vel()[1];
+        assert!((ur + energy).abs() < 1e-8, "u^r = {} at {}", ur, hit.name);
+    }
+    // the particle turns around where f(r) = E^2
+    let (m, q2, e2) = (1.0, 0.25, energy * energy);
+    let r_min = (m - (m * m - (1.0 - e2) * q2).sqrt()) / (1.0 - e2);
+    assert!(r_min < r_inner);
+    assert!((particle.get_pos()[1] - r_min).abs() < 1e-8);
+    let conserved = particle.conserved_in(&bh);
+    assert!((conserved.energy - energy).abs() < 1e-8);
+    assert!((conserved.norm - 1.0).abs() < 1e-8);
+}
@@ -0,0 +1,78 @@
+//! Renders a small image of a Schwarzschild black hole of mass 1 seen by a static observer, and
+//! compares its shadow with the capture radius of light, sqrt(27).
+
+use gr_engine::coord_systems::schwarzschild::{BlackHole, EFAtlas};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::generic_array::arr;
+use gr_engine::{Camera, Entity, Frame, RayOutcome, Scene};
+use std::f64::consts::PI;
+
+type Atlas = EFAtlas<Runtime>;
+
+const R_OBSERVER: f64 = 30.0;
+const SIZE: usize = 31;
+const FOV: f64 = 0.5;
+
+// the impact parameter of the ray arriving at the center of the pixel; the ray makes the angle
+// alpha with the direction to the black hole, and b = r sin(alpha) / sqrt(1 - 2/r)
+fn impact_parameter(column: usize, row: usize) -> f64 {
+    let scale = (0.5 * FOV).tan();
+    let x = (2.0 * (column as f64 + 0.5) / SIZE as f64 - 1.0) * scale;
+    let y = (1.0 - 2.0 * (row as f64 + 0.5) / SIZE as f64) * scale;
+    let sin_alpha = ((x * x + y * y) / (1.0 + x * x + y * y)).sqrt();
+    R_OBSERVER * sin_alpha / (1.0 - 2.0 / R_OBSERVER).sqrt()
+}
+
+#[test]
+fn shadow_has_the_capture_radius() {
+    let bh = BlackHole::new(1.0);
+    let position = Point::new(arr![f64; 0.0, R_OBSERVER, PI / 2.0, 0.0]);
+    let frame = Frame::static_observer_in(&bh, &position).unwrap();
+    // looking at the black hole, with the north pole up
+    let mut observer = Entity::new(
+        position,
+        frame.to_global([1.0, 0.0, 0.0, 0.0]),
+        frame.to_global([0.0, -1.0, 0.0, 0.0]),
+        frame.to_global([0.0, 0.0, 0.0, 1.0]),
+        frame.to_global([0.0, 0.0, -1.0, 0.0]),
+    );
+    observer.orthonormalize_in(&bh);
+    let camera = Camera::<Atlas>::new(&observer, SIZE, SIZE, FOV);
+    let scene = Scene::new(bh.horizon_radius(), 60.0);
+    let image = camera.render(&scene, &bh, RayOutcome::default_color);
+
+    // the largest impact parameter of the captured rays, rendered black, and the smallest of the
+    // escaped ones
+    let (mut captured, mut escaped) = (0.0f64, f64::INFINITY);
+    for row in 0..SIZE {
+        for column in 0..SIZE {
+            let b = impact_parameter(column, row);
+            let color = image.get(column, row);
+            assert_ne!(color, RayOutcome::Unfinished.default_color(), "b = {}", b);
+            if color == RayOutcome::Captured.default_color() {
+                captured = captured.max(b);
+            } else {
+                escaped = escaped.min(b);
+            }
+        }
+    }
+    let critical = 27.0f64.sqrt();
+    assert!(
+        captured < critical && escaped > critical,
+        "captured up to {}, escaped from {}",
+        captured,
+        escaped
+    );
+    // the rows and columns are about 0.5 apart in b, with the diagonals falling in between
+    assert!(critical - captured < 0.5 && escaped - critical < 0.5);
+
+    // the rays far from the black hole stay in the equatorial plane and escape behind it
+    match scene.trace::<Atlas, _>(&bh, camera.ray(0, SIZE / 2)) {
+        RayOutcome::Escaped { theta, phi } => {
+            assert!((theta - PI / 2.0).abs() < 1e-8);
+            assert!(phi > PI / 2.0 && phi < 1.5 * PI, "phi = {}", phi);
+        }
+        outcome => panic!("{:?}", outcome),
+    }
+}
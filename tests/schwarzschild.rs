@@ -0,0 +1,268 @@
+//! Checks the charts of the Schwarzschild spacetime and follows a fall through the horizon in
+//! the charts covering it.
+
+mod common;
+
+use common::{
+    assert_same_conversion, assert_same_metric, check_chart_in_box, check_conversion_in_box,
+};
+use gr_engine::coord_systems::schwarzschild::{
+    BlackHole, EddingtonFinkelstein, Harmonic, Isotropic, KruskalSzekeres, Mass, NearPole0EF,
+    NearPole0OutgoingEF, NearPole0Schw, NearPolePiEF, NearPolePiOutgoingEF, NearPolePiSchw,
+    OutgoingEF, PainleveGullstrand, Penrose, Schwarzschild,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::{ConversionTo, CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::typenum::consts::U4;
+use gr_engine::{Driver, Particle, Spacetime, SpacetimeConversion};
+use std::f64::consts::PI;
+
+const MASS: f64 = 1.3;
+
+struct Heavy;
+
+impl Mass for Heavy {
+    fn mass() -> f64 {
+        MASS
+    }
+}
+
+type EF = EddingtonFinkelstein<Heavy>;
+type RunEF = EddingtonFinkelstein<Runtime>;
+type Pole0Out = NearPole0OutgoingEF<Heavy>;
+type RunPole0Out = NearPole0OutgoingEF<Runtime>;
+type PolePiOut = NearPolePiOutgoingEF<Heavy>;
+type RunPolePiOut = NearPolePiOutgoingEF<Runtime>;
+
+#[test]
+fn runtime_charts_match_static_ones() {
+    let bh = BlackHole::new(MASS);
+    for &r in &[1.2, 5.0, 30.0] {
+        let x = [0.7, r, 1.1, 0.4];
+        assert_same_metric::<EF, RunEF, _>(&bh, x);
+        assert_same_metric::<OutgoingEF<Heavy>, OutgoingEF<Runtime>, _>(&bh, x);
+        assert_same_metric::<PainleveGullstrand<Heavy>, PainleveGullstrand<Runtime>, _>(&bh, x);
+        assert_same_metric::<Isotropic<Heavy>, Isotropic<Runtime>, _>(&bh, x);
+        // the polar charts use stereographic coordinates in place of the angles
+        let x = [0.7, r, 0.3, -0.2];
+        assert_same_metric::<NearPole0EF<Heavy>, NearPole0EF<Runtime>, _>(&bh, x);
+        assert_same_metric::<NearPolePiEF<Heavy>, NearPolePiEF<Runtime>, _>(&bh, x);
+        assert_same_metric::<Pole0Out, RunPole0Out, _>(&bh, x);
+        assert_same_metric::<PolePiOut, RunPolePiOut, _>(&bh, x);
+    }
+    for &r in &[5.0, 30.0] {
+        let x = [0.7, r, 1.1, 0.4];
+        assert_same_metric::<Schwarzschild<Heavy>, Schwarzschild<Runtime>, _>(&bh, x);
+        assert_same_metric::<Harmonic<Heavy>, Harmonic<Runtime>, _>(&bh, x);
+        let x = [0.7, r, 0.3, -0.2];
+        assert_same_metric::<NearPole0Schw<Heavy>, NearPole0Schw<Runtime>, _>(&bh, x);
+        assert_same_metric::<NearPolePiSchw<Heavy>, NearPolePiSchw<Runtime>, _>(&bh, x);
+    }
+    // (T, X) in both exteriors and inside the black hole
+    for &(t, x) in &[(0.3, 1.2), (-0.4, -2.0), (0.8, 0.1)] {
+        assert_same_metric::<KruskalSzekeres<Heavy>, KruskalSzekeres<Runtime>, _>(
+            &bh,
+            [t, x, 1.1, 0.4],
+        );
+    }
+    for &(tau, chi) in &[(0.1, 0.4), (-0.2, -0.9), (0.5, 0.05)] {
+        assert_same_metric::<Penrose<Heavy>, Penrose<Runtime>, _>(&bh, [tau, chi, 1.1, 0.4]);
+    }
+}
+
+#[test]
+fn eddington_finkelstein_inverse_metric() {
+    let bh = BlackHole::new(1.3);
+    // outside of the horizon, on it and inside of it
+    for &r in &[0.4, 2.6, 5.0, 30.0] {
+        let x = Point::<EddingtonFinkelstein<Runtime>>::new(arr![f64; 0.7, r, 1.1, 0.4]);
+        let (g, inv_g) = (bh.g(&x), bh.inv_g(&x));
+        for i in 0..4 {
+            for j in 0..4 {
+                let product: f64 = (0..4).map(|k| g[i * 4 + k] * inv_g[k * 4 + j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(
+                    (product - expected).abs() < 1e-12,
+                    "r = {}: (g g^-1)[{}][{}] = {}",
+                    r,
+                    i,
+                    j,
+                    product
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn runtime_conversions_match_static_ones() {
+    type Schw = Schwarzschild<Heavy>;
+    type RunSchw = Schwarzschild<Runtime>;
+    type Out = OutgoingEF<Heavy>;
+    type RunOut = OutgoingEF<Runtime>;
+    type KS = KruskalSzekeres<Heavy>;
+    type RunKS = KruskalSzekeres<Runtime>;
+    type PG = PainleveGullstrand<Heavy>;
+    type RunPG = PainleveGullstrand<Runtime>;
+    type Iso = Isotropic<Heavy>;
+    type RunIso = Isotropic<Runtime>;
+    type Harm = Harmonic<Heavy>;
+    type RunHarm = Harmonic<Runtime>;
+
+    let bh = BlackHole::new(MASS);
+    let x = [0.7, 5.0, 1.1, 0.4];
+    assert_same_conversion::<Schw, EF, RunSchw, RunEF, _>(&bh, x);
+    assert_same_conversion::<EF, Schw, RunEF, RunSchw, _>(&bh, x);
+    assert_same_conversion::<Schw, KS, RunSchw, RunKS, _>(&bh, x);
+    assert_same_conversion::<EF, KS, RunEF, RunKS, _>(&bh, x);
+    assert_same_conversion::<Schw, Out, RunSchw, RunOut, _>(&bh, x);
+    assert_same_conversion::<Out, Schw, RunOut, RunSchw, _>(&bh, x);
+    assert_same_conversion::<EF, Out, RunEF, RunOut, _>(&bh, x);
+    assert_same_conversion::<Out, EF, RunOut, RunEF, _>(&bh, x);
+    assert_same_conversion::<Out, KS, RunOut, RunKS, _>(&bh, x);
+    assert_same_conversion::<EF, PG, RunEF, RunPG, _>(&bh, x);
+    assert_same_conversion::<PG, EF, RunPG, RunEF, _>(&bh, x);
+    assert_same_conversion::<Schw, Iso, RunSchw, RunIso, _>(&bh, x);
+    assert_same_conversion::<Iso, Schw, RunIso, RunSchw, _>(&bh, x);
+    assert_same_conversion::<Schw, Harm, RunSchw, RunHarm, _>(&bh, x);
+    assert_same_conversion::<Harm, Schw, RunHarm, RunSchw, _>(&bh, x);
+
+    // the conversions that don't depend on the mass, provided by the blanket implementation
+    assert_same_conversion::<Schw, NearPole0Schw<Heavy>, RunSchw, NearPole0Schw<Runtime>, _>(
+        &bh, x,
+    );
+    assert_same_conversion::<EF, NearPolePiEF<Heavy>, RunEF, NearPolePiEF<Runtime>, _>(&bh, x);
+    let x = [0.7, 5.0, 0.3, -0.2];
+    assert_same_conversion::<NearPolePiSchw<Heavy>, Schw, NearPolePiSchw<Runtime>, RunSchw, _>(
+        &bh, x,
+    );
+    assert_same_conversion::<Pole0Out, Out, RunPole0Out, RunOut, _>(&bh, x);
+
+    let x = [0.3, 1.2, 1.1, 0.4];
+    assert_same_conversion::<KS, Schw, RunKS, RunSchw, _>(&bh, x);
+    assert_same_conversion::<KS, EF, RunKS, RunEF, _>(&bh, x);
+    assert_same_conversion::<KS, Out, RunKS, RunOut, _>(&bh, x);
+    assert_same_conversion::<KS, Penrose<Heavy>, RunKS, Penrose<Runtime>, _>(&bh, x);
+    let x = [0.1, 0.4, 1.1, 0.4];
+    assert_same_conversion::<Penrose<Heavy>, KS, Penrose<Runtime>, RunKS, _>(&bh, x);
+}
+
+#[test]
+fn isotropic_and_harmonic_charts() {
+    let bh = BlackHole::new(1.0);
+    // these charts use the angles, so theta is kept away from the poles; the isotropic chart
+    // covers both sheets around the throat at rho = m/2
+    let (min, max) = ([-10.0, 0.1, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_chart_in_box::<Isotropic<Runtime>, _>(&bh, 35, min, max);
+    let (min, max) = ([-10.0, 1.5, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_chart_in_box::<Harmonic<Runtime>, _>(&bh, 36, min, max);
+}
+
+#[test]
+fn kruskal_and_penrose_charts() {
+    let bh = BlackHole::new(1.0);
+    // all four regions, away from the singularities at T^2 - X^2 = 1 and tau = ±pi/4
+    let (min, max) = ([-0.6, -2.0, 0.3, -3.0], [0.6, 2.0, 2.8, 3.0]);
+    check_chart_in_box::<KruskalSzekeres<Runtime>, _>(&bh, 70, min, max);
+    let (min, max) = ([-0.5, -0.9, 0.3, -3.0], [0.5, 0.9, 2.8, 3.0]);
+    check_chart_in_box::<Penrose<Runtime>, _>(&bh, 71, min, max);
+    check_conversion_in_box::<Penrose<Runtime>, KruskalSzekeres<Runtime>, _>(&bh, 72, min, max);
+}
+
+#[test]
+fn kruskal_conversions() {
+    type KS = KruskalSzekeres<Runtime>;
+    let bh = BlackHole::new(1.0);
+    // the exterior, X > |T|, is the region covered by the Schwarzschild chart
+    let (min, max) = ([-0.5, 0.7, 0.3, -3.0], [0.5, 2.0, 2.8, 3.0]);
+    check_conversion_in_box::<KS, Schwarzschild<Runtime>, _>(&bh, 73, min, max);
+    // the ingoing chart also covers the black hole, T > |X|, the outgoing one the white hole
+    let (min, max) = ([0.0, 0.1, 0.3, -3.0], [0.8, 1.0, 2.8, 3.0]);
+    check_conversion_in_box::<KS, EddingtonFinkelstein<Runtime>, _>(&bh, 74, min, max);
+    let (min, max) = ([-0.8, 0.1, 0.3, -3.0], [0.0, 1.0, 2.8, 3.0]);
+    check_conversion_in_box::<KS, OutgoingEF<Runtime>, _>(&bh, 75, min, max);
+}
+
+#[test]
+fn outgoing_eddington_finkelstein_chart() {
+    type Out = OutgoingEF<Runtime>;
+    let bh = BlackHole::new(1.0);
+    // the chart is regular across the horizon of the white hole
+    let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_chart_in_box::<Out, _>(&bh, 210, min, max);
+    // the ingoing chart only shares the exterior with it, the Schwarzschild chart covers either
+    // side of the horizon
+    let (min, max) = ([-10.0, 2.3, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_conversion_in_box::<RunEF, Out, _>(&bh, 211, min, max);
+    for (i, &(r_min, r_max)) in [(0.3, 1.7), (2.3, 20.0)].iter().enumerate() {
+        let (min, max) = ([-10.0, r_min, 0.3, -3.0], [10.0, r_max, 2.8, 3.0]);
+        check_conversion_in_box::<Schwarzschild<Runtime>, Out, _>(&bh, 212 + i as u64, min, max);
+    }
+}
+
+// propagates the particle with fixed steps, returning its position after each of them
+fn fixed_steps<C>(bh: &BlackHole, mut particle: Particle<C>, steps: usize) -> Vec<Point<C>>
+where
+    C: CoordinateSystem<Dimension = U4>,
+    BlackHole: Spacetime<C>,
+{
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.05, 0.05, 0.05, 1e-12));
+    (0..steps)
+        .map(|_| {
+            driver.step(&mut particle, |p: &Particle<C>| p.derivative_in(bh));
+            *particle.get_pos()
+        })
+        .collect()
+}
+
+fn assert_same_point<C: CoordinateSystem<Dimension = U4>>(x: &Point<C>, y: &Point<C>) {
+    for i in 0..4 {
+        assert!((x[i] - y[i]).abs() < 1e-7, "{:?} and {:?}", x, y);
+    }
+}
+
+#[test]
+fn radial_fall_through_the_kruskal_and_penrose_charts() {
+    type KS = KruskalSzekeres<Runtime>;
+    let bh = BlackHole::new(1.0);
+    // at rest at r = 6: in the EF coordinates v = t + r*, dv/dtau = dt/dtau
+    let r0 = 6.0;
+    let x = Point::<EddingtonFinkelstein<Runtime>>::new(arr![f64; 0.0, r0, PI / 2.0, 0.0]);
+    let ut = 1.0 / (1.0 - 2.0 / r0).sqrt();
+    let ef = Particle::new(x, Vector::new(x, arr![f64; ut, 0.0, 0.0, 0.0]));
+    let ks: Particle<KS> = ef.convert_in(&bh);
+    let penrose: Particle<Penrose<Runtime>> = ks.convert();
+    let start = ks.get_pos();
+    assert!(start[1] > start[0].abs());
+
+    // the fall from rest at 6 reaches r = 0 after the proper time pi sqrt(27) = 16.3; the charts
+    // are compared until shortly before
+    let steps = 320;
+    let ef = fixed_steps(&bh, ef, steps);
+    let ks = fixed_steps(&bh, ks, steps);
+    let penrose = fixed_steps(&bh, penrose, steps);
+    for ((ef, ks), penrose) in ef.iter().zip(&ks).zip(&penrose) {
+        let expected: Point<KS> = bh.convert_point(ef);
+        assert_same_point(ks, &expected);
+        assert_same_point(ks, &Penrose::convert_point(penrose));
+    }
+    let r = ef.last().unwrap()[1];
+    assert!(r > 0.5 && r < 1.0, "r = {}", r);
+    // inside of the black hole, T > |X|
+    let end = ks.last().unwrap();
+    assert!(end[0] > end[1].abs());
+}
+
+#[test]
+fn painleve_gullstrand_chart() {
+    let bh = BlackHole::new(1.0);
+    // the chart is regular across the horizon, down to the singularity
+    let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 20.0, 2.8, 3.0]);
+    check_chart_in_box::<PainleveGullstrand<Runtime>, _>(&bh, 76, min, max);
+    check_conversion_in_box::<PainleveGullstrand<Runtime>, EddingtonFinkelstein<Runtime>, _>(
+        &bh, 77, min, max,
+    );
+}
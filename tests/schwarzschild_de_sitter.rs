@@ -0,0 +1,56 @@
+//! Checks the charts of the Schwarzschild-de Sitter spacetime.
+
+mod common;
+
+use common::{check_chart_in_box, check_chart_in_range, check_conversion_in_box};
+use gr_engine::coord_systems::schwarzschild_de_sitter::{
+    BlackHole, EddingtonFinkelstein, NearPole0EF, NearPole0OutgoingEF, NearPole0SdS, NearPolePiEF,
+    NearPolePiOutgoingEF, NearPolePiSdS, OutgoingEF, SchwarzschildDeSitter,
+};
+use gr_engine::coord_systems::Runtime;
+
+type SdS = SchwarzschildDeSitter<Runtime>;
+type EF = EddingtonFinkelstein<Runtime>;
+type Out = OutgoingEF<Runtime>;
+
+#[test]
+fn schwarzschild_de_sitter_polar_charts() {
+    for &l in &[0.001, 0.01, 0.03] {
+        let bh = BlackHole::new(1.0, l);
+        // the static charts only cover the region between the horizons
+        check_chart_in_range::<NearPole0SdS<Runtime>, _>(&bh, 17, 2.5, 8.0);
+        check_chart_in_range::<NearPolePiSdS<Runtime>, _>(&bh, 18, 2.5, 8.0);
+        check_chart_in_range::<NearPole0EF<Runtime>, _>(&bh, 19, 0.3, 40.0);
+        check_chart_in_range::<NearPolePiEF<Runtime>, _>(&bh, 20, 0.3, 40.0);
+        check_chart_in_range::<NearPole0OutgoingEF<Runtime>, _>(&bh, 21, 0.3, 40.0);
+        check_chart_in_range::<NearPolePiOutgoingEF<Runtime>, _>(&bh, 22, 0.3, 40.0);
+    }
+}
+
+#[test]
+fn schwarzschild_de_sitter_charts() {
+    for (i, &l) in [0.001, 0.01, 0.03].iter().enumerate() {
+        let bh = BlackHole::new(1.0, l);
+        let (r_h, r_c) = (bh.horizon_radius(), bh.cosmological_horizon_radius());
+        let seed = 128 + 10 * i as u64;
+        // the static chart is singular on the horizons, so it is checked on either side of them
+        for (j, &(r_min, r_max)) in [
+            (0.3, 0.8 * r_h),
+            (1.1 * r_h, 0.9 * r_c),
+            (1.1 * r_c, 1.5 * r_c),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let (min, max) = ([-10.0, r_min, 0.3, -3.0], [10.0, r_max, 2.8, 3.0]);
+            let seed = seed + 3 * j as u64;
+            check_chart_in_box::<SdS, _>(&bh, seed, min, max);
+            check_conversion_in_box::<SdS, EF, _>(&bh, seed + 1, min, max);
+            check_conversion_in_box::<SdS, Out, _>(&bh, seed + 2, min, max);
+        }
+        // the Eddington-Finkelstein charts are regular across both horizons
+        let (min, max) = ([-10.0, 0.3, 0.3, -3.0], [10.0, 1.5 * r_c, 2.8, 3.0]);
+        check_chart_in_box::<EF, _>(&bh, seed + 9, min, max);
+        check_chart_in_box::<Out, _>(&bh, seed + 9, min, max);
+    }
+}
@@ -0,0 +1,177 @@
+//! Solves the Tolman-Oppenheimer-Volkoff equations for stars of constant density and for
+//! neutron stars, sends light through their interiors and checks the Christoffel symbols of the
+//! chart.
+
+mod common;
+
+use common::check_chart_in_box;
+use gr_engine::coord_systems::static_star::{
+    EquationOfState, Incompressible, Polytrope, Profile, Properties, Star, StaticStar, Tabulated,
+    Tov, UniformDensity,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::{CoordinateSystem, Point};
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Particle, Spacetime, StaticSpacetime};
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+// the neutron star of the mass 1.4 with the polytrope K = 100, Gamma = 2 common in numerical
+// relativity, in units of the mass of the Sun
+fn neutron_star() -> &'static Tov<Polytrope> {
+    static STAR: OnceLock<Tov<Polytrope>> = OnceLock::new();
+    STAR.get_or_init(|| {
+        let eos = Polytrope::new(100.0, 2.0);
+        Tov::solve(eos, eos.pressure(1.28e-3))
+    })
+}
+
+struct NeutronStar;
+
+impl Properties for NeutronStar {
+    fn mass(r: f64) -> (f64, f64) {
+        neutron_star().mass(r)
+    }
+
+    fn redshift(r: f64) -> (f64, f64) {
+        neutron_star().redshift(r)
+    }
+}
+
+#[test]
+fn constant_density_is_interior_schwarzschild() {
+    let exact = UniformDensity::new(1.0, 6.0);
+    let eos = Incompressible::new(exact.energy_density());
+    let solved = Tov::solve(eos, exact.central_pressure());
+    assert!((solved.total_mass() - 1.0).abs() < 1e-8);
+    assert!((solved.radius() - 6.0).abs() < 1e-7);
+    for &r in &[0.1, 1.0, 3.0, 5.5, 5.99, 8.0, 50.0] {
+        let (m, dm) = solved.mass(r);
+        let (m_exact, dm_exact) = exact.mass(r);
+        assert!((m - m_exact).abs() < 1e-8 && (dm - dm_exact).abs() < 1e-8);
+        let (phi, dphi) = solved.redshift(r);
+        let (phi_exact, dphi_exact) = exact.redshift(r);
+        assert!(
+            (phi - phi_exact).abs() < 1e-8 && (dphi - dphi_exact).abs() < 1e-8,
+            "r = {}: Phi = {}, expected {}",
+            r,
+            phi,
+            phi_exact
+        );
+        assert!((solved.pressure(r) - exact.pressure(r).max(0.0)).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn polytropic_neutron_star() {
+    let star = neutron_star();
+    assert!(
+        (star.total_mass() - 1.400).abs() < 1e-3,
+        "M = {}",
+        star.total_mass()
+    );
+    assert!(
+        (star.radius() - 9.586).abs() < 1e-3,
+        "R = {}",
+        star.radius()
+    );
+    assert_eq!(star.pressure(star.radius()), 0.0);
+
+    // the same matter given as a table of the equation of state
+    let eos = Polytrope::new(100.0, 2.0);
+    let entries = (0..=200)
+        .map(|i| {
+            let p = eos.pressure(1e-3) * 10f64.powf(-12.0 + 0.07 * i as f64);
+            (p, eos.energy_density(p))
+        })
+        .collect();
+    let tabulated = Tov::solve(Tabulated::new(entries), eos.pressure(1.28e-3));
+    assert!((tabulated.total_mass() - star.total_mass()).abs() < 1e-4);
+    assert!((tabulated.radius() - star.radius()).abs() < 1e-3);
+}
+
+// sends light from r = 50 in the equatorial plane with the impact parameter `b` until it gets
+// back to r = 50; returns the photon and the smallest radius it reached
+fn through_star<C, S>(spacetime: &S, b: f64) -> (Particle<C>, f64)
+where
+    C: CoordinateSystem<Dimension = gr_engine::typenum::consts::U4>,
+    S: Spacetime<C>,
+{
+    let r = 50.0;
+    let x = Point::new(arr![f64; 0.0, r, PI / 2.0, 0.0]);
+    let g = spacetime.g(&x);
+    // E = 1, L = b
+    let (ut, uphi) = (1.0 / g[0], b / (r * r));
+    let ur = (-(g[0] * ut * ut + g[15] * uphi * uphi) / g[5]).sqrt();
+    let mut photon = Particle::new(x, Vector::new(x, arr![f64; ut, -ur, 0.0, uphi]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.01, 1e-5, 0.1, 1e-12));
+    let mut r_min = r;
+    let mut steps = 0;
+    while driver.param() < 1.0 || photon.get_pos()[1] < r {
+        assert!(steps < 100_000, "the light didn't get back to r = {}", r);
+        steps += 1;
+        driver.step(&mut photon, |p: &Particle<C>| p.derivative_in(spacetime));
+        r_min = r_min.min(photon.get_pos()[1]);
+    }
+    (photon, r_min)
+}
+
+// the turning point of light with the impact parameter `b`, where r e^(-Phi) = b
+fn turning_point<P: Profile>(profile: &P, b: f64) -> f64 {
+    let (mut lo, mut hi) = (1e-6, b);
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        if mid * (-profile.redshift(mid).0).exp() < b {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+#[test]
+fn light_crosses_a_constant_density_star() {
+    let star = Star::uniform(1.0, 6.0);
+    let b = 4.0;
+    let (photon, r_min): (Particle<StaticStar<Runtime>>, _) = through_star(&star, b);
+    let expected = turning_point(&star.profile, b);
+    assert!(expected < 6.0);
+    assert!((r_min - expected).abs() < 1e-3, "r_min = {}", r_min);
+    let conserved = photon.conserved_in(&star);
+    assert!((conserved.energy - 1.0).abs() < 1e-8 && (conserved.ang_momentum - b).abs() < 1e-8);
+}
+
+#[test]
+fn light_crosses_a_neutron_star() {
+    let b = 5.0;
+    // the chart as a `MetricSystem`, with the solution behind `Properties`
+    let (photon, r_min): (Particle<StaticStar<NeutronStar>>, _) = through_star(&StaticSpacetime, b);
+    let expected = turning_point(neutron_star(), b);
+    assert!(expected < neutron_star().radius());
+    assert!((r_min - expected).abs() < 1e-3, "r_min = {}", r_min);
+    let conserved = photon.conserved_in(&StaticSpacetime);
+    assert!((conserved.energy - 1.0).abs() < 1e-8 && (conserved.ang_momentum - b).abs() < 1e-8);
+
+    // the runtime star gives the same trajectory
+    let star = Star::new(neutron_star().clone());
+    let (twin, _): (Particle<StaticStar<Runtime>>, _) = through_star(&star, b);
+    for i in 0..4 {
+        assert!((twin.get_pos()[i] - photon.get_pos()[i]).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn static_star_charts() {
+    // r covers the interior and the exterior of the stars
+    let min = [-10.0, 0.3, 0.3, -3.0];
+    let max = [10.0, 20.0, 2.8, 3.0];
+    let uniform = Star::uniform(1.0, 6.0);
+    check_chart_in_box::<StaticStar<Runtime>, _>(&uniform, 54, min, max);
+
+    let polytrope = Polytrope::new(100.0, 2.0);
+    let neutron_star = Star::solve(polytrope, polytrope.pressure(1.28e-3));
+    check_chart_in_box::<StaticStar<Runtime>, _>(&neutron_star, 55, min, max);
+}
@@ -0,0 +1,245 @@
+//! Records orbits around a Schwarzschild black hole of mass 1, compares the interpolated states
+//! with the exact circular orbit or a finely sampled eccentric one, and reads the exported samples
+//! back.
+
+use gr_engine::coord_systems::schwarzschild::{BlackHole, Schwarzschild};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Chart, Driver, Event, Particle, Sample, Trajectory};
+use std::f64::consts::PI;
+
+type Coords = Schwarzschild<Runtime>;
+
+const R: f64 = 8.0;
+
+// the 4-velocity of the circular orbit at R
+fn circular_velocity() -> [f64; 4] {
+    let ut = 1.0 / (1.0 - 3.0 / R).sqrt();
+    [ut, 0.0, 0.0, ut / (R * R * R).sqrt()]
+}
+
+// the exact position on the circular orbit after the proper time `tau`
+fn circular_position(tau: f64) -> [f64; 4] {
+    let u = circular_velocity();
+    [u[0] * tau, R, PI / 2.0, u[3] * tau]
+}
+
+// the 4-velocity of a bound eccentric orbit starting at R, moving faster than the circular one
+fn eccentric_velocity() -> [f64; 4] {
+    let uphi = 1.1 * circular_velocity()[3];
+    let f = 1.0 - 2.0 / R;
+    [((1.0 + R * R * uphi * uphi) / f).sqrt(), 0.0, 0.0, uphi]
+}
+
+// records the orbit starting at R with the 4-velocity `u`, making steps of the length `step`
+// and recording `samples` samples per step
+fn record_orbit(u: [f64; 4], step: f64, samples: usize) -> Trajectory {
+    let bh = BlackHole::new(1.0);
+    let x = Point::<Coords>::new(arr![f64; 0.0, R, PI / 2.0, 0.0]);
+    let mut particle = Particle::new(x, Vector::new(x, arr![f64; u[0], u[1], u[2], u[3]]));
+    let mut driver = Driver::new(DenseDPIntegrator::new(step, step, step, 1e-12))
+        .with_event(Event::time_coordinate(200.0).with_terminal(true));
+    let mut trajectory = Trajectory::new();
+    trajectory.record(0.0, &particle);
+    loop {
+        let hits = driver.step(&mut particle, |p: &Particle<Coords>| p.derivative_in(&bh));
+        trajectory.record_step(&driver, samples);
+        if !hits.is_empty() {
+            break;
+        }
+    }
+    trajectory
+}
+
+// the largest difference of the interpolated positions from the reference within the recorded
+// range
+fn max_interpolation_error(trajectory: &Trajectory, reference: &Trajectory) -> f64 {
+    let end = trajectory.samples().last().unwrap().param;
+    let mut max_error: f64 = 0.0;
+    for i in 0..1000 {
+        let tau = end * i as f64 / 1000.0;
+        let sample = trajectory.interpolate(tau).unwrap();
+        let expected = reference.interpolate(tau).unwrap().position;
+        for (x, x0) in sample.position.iter().zip(expected.iter()) {
+            max_error = max_error.max((x - x0).abs());
+        }
+    }
+    max_error
+}
+
+#[test]
+fn steps_are_recorded_up_to_the_terminal_event() {
+    let trajectory = record_orbit(circular_velocity(), 5.0, 4);
+    let samples = trajectory.samples();
+    // the first sample, and 4 per step
+    assert_eq!((samples.len() - 1) % 4, 0);
+    let last = samples.last().unwrap();
+    assert!((last.position[0] - 200.0).abs() < 1e-9);
+    for pair in samples.windows(2) {
+        assert!(pair[0].param < pair[1].param);
+    }
+    // each recorded state lies on the orbit
+    for sample in samples {
+        let expected = circular_position(sample.param);
+        for (x, x0) in sample.position.iter().zip(expected.iter()) {
+            assert!((x - x0).abs() < 1e-8);
+        }
+    }
+}
+
+#[test]
+fn dense_samples_improve_the_interpolation() {
+    // the reference makes the same steps, sampled so densely that the interpolation between the
+    // samples is exact, so that only the errors of the interpolation are compared
+    let u = eccentric_velocity();
+    let reference = record_orbit(u, 5.0, 64);
+    let coarse = max_interpolation_error(&record_orbit(u, 5.0, 1), &reference);
+    let dense = max_interpolation_error(&record_orbit(u, 5.0, 8), &reference);
+    assert!(dense < 1e-6, "error with dense samples {}", dense);
+    assert!(dense < 0.01 * coarse, "errors {} and {}", coarse, dense);
+
+    // the interpolation reproduces the samples and fails outside of them
+    let trajectory = record_orbit(u, 5.0, 8);
+    for sample in trajectory.samples() {
+        assert_eq!(trajectory.interpolate(sample.param), Some(*sample));
+    }
+    let end = trajectory.samples().last().unwrap().param;
+    assert!(trajectory.interpolate(-0.1).is_none());
+    assert!(trajectory.interpolate(end + 0.1).is_none());
+}
+
+#[test]
+fn interpolation_stops_at_chart_switches() {
+    let mut trajectory = Trajectory::new();
+    let sample = |param: f64, chart: Chart| Sample {
+        param,
+        chart,
+        position: [param, 5.0, 0.1, 0.0],
+        velocity: [1.0, 0.0, 0.0, 0.0],
+        tetrad: None,
+    };
+    trajectory.push(sample(0.0, Chart::Main));
+    trajectory.push(sample(1.0, Chart::Main));
+    trajectory.push(sample(1.0, Chart::Pole0));
+    trajectory.push(sample(2.0, Chart::Pole0));
+    assert_eq!(trajectory.interpolate(0.5).unwrap().chart, Chart::Main);
+    assert_eq!(trajectory.interpolate(1.5).unwrap().chart, Chart::Pole0);
+    assert!((trajectory.interpolate(1.5).unwrap().position[0] - 1.5).abs() < 1e-12);
+}
+
+// a trajectory with a tetrad in one of the samples, and values CSV and JSON can't represent
+fn exported() -> Trajectory {
+    let mut trajectory = Trajectory::new();
+    trajectory.push(Sample {
+        param: 0.0,
+        chart: Chart::Main,
+        position: [0.1, 7.25, 1.0 / 3.0, -2.5e-17],
+        velocity: [1.5, -0.125, 0.0, 3.0e8],
+        tetrad: Some([
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.5, 0.0],
+            [0.0, 0.0, 0.0, -0.25],
+        ]),
+    });
+    trajectory.push(Sample {
+        param: 0.75,
+        chart: Chart::PolePi,
+        position: [f64::NAN, f64::INFINITY, 0.2, f64::NEG_INFINITY],
+        velocity: [1.0, 2.0, 3.0, 4.0],
+        tetrad: None,
+    });
+    trajectory
+}
+
+// reads a number written by the export, with empty fields and nulls standing for NaN
+fn parse(field: &str) -> f64 {
+    match field {
+        "" | "null" => f64::NAN,
+        _ => field.parse().unwrap(),
+    }
+}
+
+fn assert_same(value: f64, expected: f64) {
+    if expected.is_finite() {
+        assert_eq!(value, expected);
+    } else {
+        assert!(value.is_nan(), "{} for {}", value, expected);
+    }
+}
+
+#[test]
+fn csv_export_reads_back() {
+    let trajectory = exported();
+    let mut out = Vec::new();
+    trajectory.write_csv(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+
+    let header: Vec<&str> = lines[0].split(',').collect();
+    assert_eq!(header.len(), 22);
+    assert_eq!(&header[..3], &["param", "chart", "x0"]);
+    assert_eq!(header[21], "up3");
+
+    for (line, sample) in lines[1..].iter().zip(trajectory.samples()) {
+        let fields: Vec<&str> = line.split(',').collect();
+        assert_eq!(fields.len(), 22);
+        assert_same(parse(fields[0]), sample.param);
+        let values = sample.position.iter().chain(sample.velocity.iter());
+        for (field, value) in fields[2..10].iter().zip(values) {
+            assert_same(parse(field), *value);
+        }
+        match sample.tetrad {
+            Some(tetrad) => {
+                for (field, value) in fields[10..].iter().zip(tetrad.iter().flatten()) {
+                    assert_same(parse(field), *value);
+                }
+            }
+            None => assert!(fields[10..].iter().all(|f| f.is_empty())),
+        }
+    }
+    assert_eq!(&lines[1][..9], "0.0,main,");
+    assert!(lines[2].starts_with("0.75,pole_pi,,,0.2,,"), "{}", lines[2]);
+}
+
+#[test]
+fn json_export_reads_back() {
+    let trajectory = exported();
+    let mut out = Vec::new();
+    trajectory.write_json_lines(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+
+    // the arrays of numbers, in the order they are written
+    for (line, sample) in lines.iter().zip(trajectory.samples()) {
+        let param = line
+            .strip_prefix("{\"param\":")
+            .and_then(|rest| rest.split(',').next())
+            .unwrap();
+        assert_same(parse(param), sample.param);
+        let numbers: Vec<f64> = line
+            .split('[')
+            .skip(1)
+            .flat_map(|part| part.split(']').next().unwrap().split(','))
+            .filter(|field| !field.is_empty())
+            .map(parse)
+            .collect();
+        let mut expected: Vec<f64> = sample.position.to_vec();
+        expected.extend_from_slice(&sample.velocity);
+        if let Some(tetrad) = sample.tetrad {
+            expected.extend(tetrad.iter().flatten());
+        }
+        assert_eq!(numbers.len(), expected.len());
+        for (value, expected) in numbers.iter().zip(expected) {
+            assert_same(*value, expected);
+        }
+    }
+    assert!(lines[0].contains("\"chart\":\"main\""));
+    assert!(lines[0].contains("\"tetrad\":[["));
+    assert!(lines[1].contains("\"x\":[null,null,0.2,null]"));
+    assert!(!lines[1].contains("tetrad"));
+}
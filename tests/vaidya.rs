@@ -0,0 +1,99 @@
+//! Follows outgoing light rays near the apparent horizon of a Vaidya black hole: a growing
+//! horizon traps the ones just outside of it, a shrinking one releases the ones just inside. Also
+//! checks the Christoffel symbols of the chart.
+
+mod common;
+
+use common::check_chart_in_box;
+use gr_engine::coord_systems::vaidya::{BlackHole, MassFunction, Vaidya};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::diffgeom::tensors::Vector;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{Driver, Particle};
+use std::f64::consts::PI;
+
+type Coords = Vaidya<Runtime>;
+
+// the mass changing smoothly from m0 to m1 around v = 0
+fn transition(m0: f64, m1: f64) -> impl Fn(f64) -> (f64, f64) {
+    move |v: f64| {
+        let s = (v / 2.0).tanh();
+        (
+            0.5 * (m0 + m1) + 0.5 * (m1 - m0) * s,
+            0.25 * (m1 - m0) * (1.0 - s * s),
+        )
+    }
+}
+
+// propagates an outgoing radial light ray from the radius `r` at v = -10 until v = 60
+fn outgoing_ray<F: MassFunction>(bh: &BlackHole<F>, r: f64) -> Point<Coords> {
+    let x = Point::new(arr![f64; -10.0, r, PI / 2.0, 0.0]);
+    let (m, _) = bh.mass.mass(x[0]);
+    let v = Vector::new(x, arr![f64; 1.0, 0.5 * (1.0 - 2.0 * m / r), 0.0, 0.0]);
+    let mut ray = Particle::new(x, v);
+    let mut driver = Driver::new(DenseDPIntegrator::new(0.01, 1e-6, 0.5, 1e-10));
+    while ray.get_pos()[0] < 60.0 && ray.get_pos()[1] > 0.2 && ray.get_pos()[1] < 1e3 {
+        driver.step(&mut ray, |p: &Particle<Coords>| p.derivative_in(bh));
+    }
+    *ray.get_pos()
+}
+
+#[test]
+fn accretion_traps_light() {
+    let bh = BlackHole::new(transition(1.0, 1.5));
+    assert!((bh.apparent_horizon_radius(-100.0) - 2.0).abs() < 1e-9);
+    assert!((bh.apparent_horizon_radius(100.0) - 3.0).abs() < 1e-9);
+
+    // outside of the initial horizon, but inside the event horizon of the grown black hole
+    let x = outgoing_ray(&bh, 2.05);
+    assert!(x[1] < 2.0, "r = {} at v = {}", x[1], x[0]);
+
+    // far enough to escape
+    let x = outgoing_ray(&bh, 4.0);
+    assert!(x[1] > 10.0, "r = {} at v = {}", x[1], x[0]);
+}
+
+#[test]
+fn evaporation_releases_light() {
+    let bh = BlackHole::new(transition(1.0, 0.5));
+
+    // inside of the initial horizon, but outside of the event horizon of the shrunk black hole
+    let x = outgoing_ray(&bh, 1.95);
+    assert!(x[1] > 10.0, "r = {} at v = {}", x[1], x[0]);
+
+    // too deep to escape
+    let x = outgoing_ray(&bh, 0.8);
+    assert!(x[1] < 0.8, "r = {} at v = {}", x[1], x[0]);
+}
+
+#[test]
+fn constant_mass_is_schwarzschild() {
+    use gr_engine::coord_systems::schwarzschild;
+    use gr_engine::Spacetime;
+
+    let bh = BlackHole::new(|_| (1.3, 0.0));
+    let schw = schwarzschild::BlackHole::new(1.3);
+    let x = Point::<Coords>::new(arr![f64; 0.7, 3.1, 1.1, -0.4]);
+    let y = Point::<schwarzschild::EddingtonFinkelstein<Runtime>>::new(*x.coords_array());
+    let (a, b) = (bh.christoffel(&x), schw.christoffel(&y));
+    for i in 0..64 {
+        assert_eq!(a[i], b[i]);
+    }
+}
+
+#[test]
+fn vaidya_chart() {
+    // accretion doubling the mass around v = 0
+    let mass = |v: f64| {
+        (
+            1.5 + 0.5 * (v / 3.0).tanh(),
+            0.5 / 3.0 / (v / 3.0).cosh().powi(2),
+        )
+    };
+    let bh = BlackHole::new(mass);
+    let min = [-10.0, 0.5, 0.3, -3.0];
+    let max = [10.0, 20.0, 2.8, 3.0];
+    check_chart_in_box::<Coords, _>(&bh, 52, min, max);
+}
@@ -0,0 +1,103 @@
+//! Checks the tabulation of Morris-Thorne wormholes against the Ellis drainhole and the
+//! Christoffel symbols of their charts, and flies an entity through the throat.
+
+mod common;
+
+use common::{check_chart_in_box, check_chart_in_range};
+use gr_engine::coord_systems::wormhole::{
+    Ellis, MorrisThorne, MorrisThorneAtlas, NearPole0MT, NearPolePiMT, Profile, ShapeFunction,
+    Wormhole,
+};
+use gr_engine::coord_systems::Runtime;
+use gr_engine::diffgeom::coordinates::Point;
+use gr_engine::generic_array::arr;
+use gr_engine::numeric::DenseDPIntegrator;
+use gr_engine::{AtlasEntity, Body, Chart, Driver, Entity, Event, Frame};
+
+#[test]
+fn shape_function_reproduces_ellis() {
+    // the Ellis drainhole has the shape function b(r) = b0^2 / r
+    let b0 = 2.0;
+    let ellis = Ellis::new(b0);
+    let shape = ShapeFunction::new(b0, |r: f64| b0 * b0 / r, |_| (0.0, 0.0));
+    for &l in &[
+        -1e5, -300.0, -10.0, -0.5, -1e-3, 0.0, 1e-4, 0.2, 3.0, 70.0, 1e4,
+    ] {
+        let (r, dr) = shape.radius(l);
+        let (expected, expected_dr) = ellis.radius(l);
+        assert!(
+            (r - expected).abs() < 1e-6 * expected,
+            "r({}) = {}, expected {}",
+            l,
+            r,
+            expected
+        );
+        assert!((dr - expected_dr).abs() < 1e-6, "dr/dl({}) = {}", l, dr);
+    }
+}
+
+#[test]
+fn entity_flies_through_throat() {
+    type Coords = MorrisThorne<Runtime>;
+
+    let wormhole = Wormhole::ellis(1.0);
+    let start = Point::<Coords>::new(arr![f64; 0.0, 10.0, 0.4, 0.0]);
+    let frame = Frame::static_observer_in(&wormhole, &start).expect("static spacetime");
+    // inwards at half the speed of light, passing close to the pole theta = 0, with too little
+    // angular momentum to be held at the throat
+    let (v, gamma) = (0.5, 1.0 / 0.75f64.sqrt());
+    let dir = [-1.0 / 1.0025f64.sqrt(), -0.05 / 1.0025f64.sqrt(), 0.0];
+    let local = |time: f64, space: f64| {
+        frame.to_global([time, dir[0] * space, dir[1] * space, dir[2] * space])
+    };
+    let entity = Entity::new(
+        start,
+        local(gamma, gamma * v),
+        local(gamma * v, gamma),
+        frame.to_global([0.0, -dir[1], dir[0], 0.0]),
+        frame.to_global([0.0, 0.0, 0.0, 1.0]),
+    );
+    let mut entity = AtlasEntity::<MorrisThorneAtlas<Runtime>>::new(entity);
+    entity.orthonormalize_in(&wormhole);
+
+    let integrator = DenseDPIntegrator::new(0.1, 1e-4, 1.0, 1e-10);
+    let mut driver = Driver::new(integrator).with_event(Event::radius(0.0));
+    let mut throat_crossings = 0;
+    let mut visited_pole = false;
+    while driver.param() < 40.0 {
+        let hits = driver.step(
+            &mut entity,
+            |e: &AtlasEntity<MorrisThorneAtlas<Runtime>>| e.derivative_in(&wormhole),
+        );
+        throat_crossings += hits.len();
+        if entity.update_chart() {
+            driver.reset();
+        }
+        visited_pole |= entity.chart() == Chart::Pole0;
+    }
+
+    assert_eq!(throat_crossings, 1);
+    assert!(visited_pole);
+    assert!(entity.radius() < -5.0, "l = {}", entity.radius());
+}
+
+#[test]
+fn wormhole_charts() {
+    // l covers both sides of the throat
+    let min = [-10.0, -20.0, 0.3, -3.0];
+    let max = [10.0, 20.0, 2.8, 3.0];
+    let ellis = Wormhole::ellis(1.5);
+    check_chart_in_box::<MorrisThorne<Runtime>, _>(&ellis, 45, min, max);
+    check_chart_in_range::<NearPole0MT<Runtime>, _>(&ellis, 46, -20.0, 20.0);
+    check_chart_in_range::<NearPolePiMT<Runtime>, _>(&ellis, 47, -20.0, 20.0);
+
+    let shape = ShapeFunction::new(
+        1.0,
+        |r: f64| (1.0 / r).sqrt(),
+        |r: f64| (-0.5 / r, 0.5 / (r * r)),
+    );
+    let shaped = Wormhole::new(shape);
+    check_chart_in_box::<MorrisThorne<Runtime>, _>(&shaped, 48, min, max);
+    check_chart_in_range::<NearPole0MT<Runtime>, _>(&shaped, 49, -20.0, 20.0);
+    check_chart_in_range::<NearPolePiMT<Runtime>, _>(&shaped, 50, -20.0, 20.0);
+}